@@ -8,7 +8,8 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-//! `#[given]`, `#[when]` and `#[then]` attribute macros implementation.
+//! `#[given]`, `#[when]`, `#[then]` and `#[any]` attribute macros
+//! implementation.
 
 use std::{iter, mem};
 
@@ -18,34 +19,71 @@ use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 use regex::{self, Regex};
 use syn::{
-    parse::{Parse, ParseStream},
+    parse::{Parse, ParseStream, Parser as _},
     parse_quote,
+    punctuated::Punctuated,
     spanned::Spanned as _,
 };
 
 /// Names of default [`Parameter`]s.
 const DEFAULT_PARAMETERS: [&str; 5] = ["int", "float", "word", "string", ""];
 
-/// Generates code of `#[given]`, `#[when]` and `#[then]` attribute macros
-/// expansion.
+/// Generates code of `#[given]`, `#[when]`, `#[then]` and `#[any]` attribute
+/// macros expansion.
+///
+/// Multiple comma-separated arguments register aliases of the same step,
+/// sharing the function and its location. Additional `wip` argument marks all
+/// of them as work-in-progress ones.
 pub(crate) fn step(
     attr_name: &'static str,
     args: TokenStream,
     input: TokenStream,
 ) -> syn::Result<TokenStream> {
-    Step::parse(attr_name, args, input).and_then(Step::expand)
+    let span = args.span();
+    let (wip, args): (Vec<_>, Vec<_>) =
+        Punctuated::<AttributeArgument, syn::Token![,]>::parse_terminated
+            .parse2(args)?
+            .into_iter()
+            .partition(|arg| matches!(arg, AttributeArgument::Wip));
+    if args.is_empty() {
+        return Err(syn::Error::new(
+            span,
+            "expected string literal, `regex` or `expr` argument",
+        ));
+    }
+
+    let func = syn::parse2::<syn::ItemFn>(input)?;
+    let steps = args
+        .into_iter()
+        .map(|arg| Step::parse(attr_name, arg, !wip.is_empty(), func.clone()))
+        .collect::<syn::Result<Vec<_>>>()?;
+    // `#[step]` attributes are stripped from the arguments while parsing.
+    let stripped_func = &steps[0].func;
+    let submissions = steps
+        .iter()
+        .map(Step::expand)
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(quote! {
+        #stripped_func
+
+        #( #submissions )*
+    })
 }
 
 /// Parsed state (ready for code generation) of the attribute and the function
 /// it's applied to.
 #[derive(Clone, Debug)]
 struct Step {
-    /// Name of the attribute (`given`, `when` or `then`).
+    /// Name of the attribute (`given`, `when`, `then` or `any`).
     attr_name: &'static str,
 
     /// Argument of the attribute.
     attr_arg: AttributeArgument,
 
+    /// Indicator whether this [`Step`] is marked as a work-in-progress one.
+    wip: bool,
+
     /// Function the attribute is applied to.
     func: syn::ItemFn,
 
@@ -57,15 +95,14 @@ struct Step {
 }
 
 impl Step {
-    /// Parses [`Step`] definition from the attribute macro input.
+    /// Parses [`Step`] definition from the attribute macro argument and the
+    /// function it's applied to.
     fn parse(
         attr_name: &'static str,
-        attr: TokenStream,
-        body: TokenStream,
+        attr_arg: AttributeArgument,
+        wip: bool,
+        mut func: syn::ItemFn,
     ) -> syn::Result<Self> {
-        let attr_arg = syn::parse2::<AttributeArgument>(attr)?;
-        let mut func = syn::parse2::<syn::ItemFn>(body)?;
-
         let step_arg_name = {
             let (arg_marked_as_step, _) =
                 remove_all_attrs_if_needed("step", &mut func);
@@ -96,13 +133,16 @@ impl Step {
         Ok(Self {
             attr_name,
             attr_arg,
+            wip,
             func,
             step_arg_name,
         })
     }
 
-    /// Expands generated code of this [`Step`] definition.
-    fn expand(self) -> syn::Result<TokenStream> {
+    /// Expands generated code submitting this [`Step`] definition.
+    ///
+    /// [`Step::func`] itself is not included.
+    fn expand(&self) -> syn::Result<TokenStream> {
         let func = &self.func;
         let func_name = &func.sig.ident;
 
@@ -112,14 +152,13 @@ impl Step {
             self.fn_arguments_and_additional_parsing()?;
 
         let regex = self.gen_regex()?;
+        let wip = self.wip;
 
         let awaiting = func.sig.asyncness.map(|_| quote! { .await });
         let unwrapping = (!self.returns_unit())
             .then(|| quote! { .unwrap_or_else(|e| panic!("{}", e)) });
 
         Ok(quote! {
-            #func
-
             #[automatically_derived]
             ::cucumber::codegen::submit!({
                 // TODO: Remove this, once `#![feature(more_qualified_paths)]`
@@ -149,6 +188,7 @@ impl Step {
                         };
                         ::std::boxed::Box::pin(f)
                     },
+                    wip: #wip,
                 }
             });
         })
@@ -276,7 +316,7 @@ impl Step {
             }
         } else if self.step_arg_name.is_some() {
             Ok((
-                quote! { ::std::borrow::Borrow::borrow(&__cucumber_ctx.step), },
+                quote! { ::std::borrow::Borrow::borrow(&__cucumber_ctx), },
                 None,
             ))
         } else {
@@ -309,7 +349,7 @@ impl Step {
         let decl = if is_ctx_arg {
             quote! {
                 let #ident =
-                    ::std::borrow::Borrow::borrow(&__cucumber_ctx.step);
+                    ::std::borrow::Borrow::borrow(&__cucumber_ctx);
             }
         } else {
             let syn::Type::Path(ty) = ty else {
@@ -389,7 +429,7 @@ impl Step {
             let (ident, _) = parse_fn_arg(arg)?;
             if name == ident {
                 return Ok(quote! {
-                    ::std::borrow::Borrow::borrow(&__cucumber_ctx.step),
+                    ::std::borrow::Borrow::borrow(&__cucumber_ctx),
                 });
             }
         }
@@ -429,6 +469,9 @@ impl Step {
             AttributeArgument::Expression(expr) => {
                 self.gen_expression_regex(expr)
             }
+            AttributeArgument::Wip => {
+                unreachable!("`wip` arguments are filtered out on parsing")
+            }
         }
     }
 
@@ -703,6 +746,9 @@ enum AttributeArgument {
 
     /// `#[step(expr = "cucumber-expression")]` case.
     Expression(syn::LitStr),
+
+    /// `#[step("literal", wip)]` case.
+    Wip,
 }
 
 impl Parse for AttributeArgument {
@@ -730,6 +776,8 @@ impl Parse for AttributeArgument {
                 )),
             },
 
+            syn::Meta::Path(p) if p.is_ident("wip") => Ok(Self::Wip),
+
             syn::Meta::List(_) | syn::Meta::Path(_) => Err(syn::Error::new(
                 arg.span(),
                 "expected string literal, `regex` or `expr` argument",
@@ -740,7 +788,8 @@ impl Parse for AttributeArgument {
 
 /// Removes all `#[attr_arg]` attributes from the given function signature and
 /// returns these attributes along with the corresponding function's arguments
-/// in case there are no more `#[given]`, `#[when]` or `#[then]` attributes.
+/// in case there are no more `#[given]`, `#[when]`, `#[then]` or `#[any]`
+/// attributes.
 fn remove_all_attrs_if_needed<'a>(
     attr_arg: &str,
     func: &'a mut syn::ItemFn,
@@ -751,7 +800,7 @@ fn remove_all_attrs_if_needed<'a>(
             .segments
             .last()
             .map(|segment| {
-                ["given", "when", "then"]
+                ["given", "when", "then", "any"]
                     .iter()
                     .any(|step| segment.ident == step)
             })
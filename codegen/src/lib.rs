@@ -160,8 +160,8 @@ mod actually_used_crates_in_tests {
     use tokio as _;
 }
 
-/// Helper macro for generating public shims for [`macro@given`], [`macro@when`]
-/// and [`macro@then`] attributes.
+/// Helper macro for generating public shims for [`macro@given`], [`macro@when`],
+/// [`macro@then`] and [`macro@any`] attributes.
 macro_rules! step_attribute {
     ($name:ident) => {
         /// Attribute to auto-wire the test to the [`World`] implementer.
@@ -171,6 +171,9 @@ macro_rules! step_attribute {
         /// - [`macro@when`]
         /// - [`macro@then`]
         ///
+        /// And a generic [`macro@any`] one, matching steps of any type, but
+        /// only if no step-specific one matches them.
+        ///
         /// # Example
         ///
         /// ```
@@ -211,6 +214,19 @@ macro_rules! step_attribute {
         ///   Matches the step with an **exact** literal only. Doesn't allow any
         ///   values capturing to use as function arguments.
         ///
+        /// - `#[given("literal", regex = "regex", expr = "cucumber-expression")]`
+        ///
+        ///   Multiple comma-separated arguments register aliases of the same
+        ///   step, sharing the function and its [`step::Location`], so they're
+        ///   treated as a single definition (matching several of them is not
+        ///   an ambiguity).
+        ///
+        /// - `#[given("literal", wip)]`
+        ///
+        ///   Additional `wip` argument marks the step as a work-in-progress
+        ///   one: it's still matched, but never executed and reported as
+        ///   pending instead. Removing the `wip` argument re-enables the step.
+        ///
         /// # Function arguments
         ///
         /// - First argument has to be mutable reference to the [`World`]
@@ -218,7 +234,9 @@ macro_rules! step_attribute {
         /// - Other argument's types have to implement [`FromStr`] or it has to
         ///   be a slice where the element type also implements [`FromStr`].
         /// - To use [`gherkin::Step`], name the argument as `step`,
-        ///   **or** mark the argument with a `#[step]` attribute.
+        ///   **or** mark the argument with a `#[step]` attribute. The same
+        ///   way a whole [`step::Context`] may be used instead (to spawn
+        ///   joined threads, for example).
         ///
         /// ```rust
         /// # use std::convert::Infallible;
@@ -255,6 +273,8 @@ macro_rules! step_attribute {
         /// [`FromStr`]: std::str::FromStr
         /// [`Regex`]: regex::Regex
         /// [`gherkin::Step`]: https://bit.ly/3j42hcd
+        /// [`step::Context`]: https://docs.rs/cucumber/*/cucumber/step/struct.Context.html
+        /// [`step::Location`]: https://docs.rs/cucumber/*/cucumber/step/struct.Location.html
         /// [`World`]: https://bit.ly/3j0aWw7
         /// [1]: cucumber_expressions
         #[proc_macro_attribute]
@@ -266,15 +286,15 @@ macro_rules! step_attribute {
     };
 }
 
-/// Helper macro for generating public shim of [`macro@given`], [`macro@when`]
-/// and [`macro@then`] attributes.
+/// Helper macro for generating public shim of [`macro@given`], [`macro@when`],
+/// [`macro@then`] and [`macro@any`] attributes.
 macro_rules! steps {
     ($($name:ident),*) => {
         $(step_attribute!($name);)*
     }
 }
 
-steps!(given, when, then);
+steps!(given, when, then, any);
 
 /// Derive macro for implementing a [`World`] trait.
 ///
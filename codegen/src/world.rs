@@ -77,12 +77,12 @@ impl TryFrom<syn::DeriveInput> for Definition {
 
 impl Definition {
     /// Possible step names.
-    const STEPS: &'static [&'static str] = &["given", "when", "then"];
+    const STEPS: &'static [&'static str] = &["given", "when", "then", "any"];
 
-    /// Assertion to ensure, that [`Self::STEPS`] has exactly 3 step types.
+    /// Assertion to ensure, that [`Self::STEPS`] has exactly 4 step types.
     #[allow(clippy::manual_assert)] // `assert_eq!` isn't const yet
-    const EXACTLY_3_STEPS: () = if Self::STEPS.len() != 3 {
-        panic!("expected exactly 3 step names");
+    const EXACTLY_4_STEPS: () = if Self::STEPS.len() != 4 {
+        panic!("expected exactly 4 step names");
     };
 
     /// Generates code of implementing a `WorldInventory` trait.
@@ -90,10 +90,10 @@ impl Definition {
         let world = &self.ident;
         let (impl_gens, ty_gens, where_clause) = self.generics.split_for_impl();
 
-        let (given_ty, when_step_ty, then_ty) = self
+        let (given_ty, when_step_ty, then_ty, any_ty) = self
             .step_types()
             .collect_tuple()
-            .unwrap_or_else(|| unreachable!("{:?}", Self::EXACTLY_3_STEPS));
+            .unwrap_or_else(|| unreachable!("{:?}", Self::EXACTLY_4_STEPS));
 
         quote! {
             #[automatically_derived]
@@ -104,6 +104,7 @@ impl Definition {
                 type Given = #given_ty;
                 type When = #when_step_ty;
                 type Then = #then_ty;
+                type Any = #any_ty;
             }
         }
     }
@@ -166,6 +167,9 @@ impl Definition {
 
                         #[doc(hidden)]
                         #world_vis func: ::cucumber::Step<#world>,
+
+                        #[doc(hidden)]
+                        #world_vis wip: bool,
                     }
 
                     #[automatically_derived]
@@ -180,6 +184,10 @@ impl Definition {
                         ) {
                             (self.loc, self.regex, self.func)
                         }
+
+                        fn is_wip(&self) -> bool {
+                            self.wip
+                        }
                     }
 
                     #[automatically_derived]
@@ -216,6 +224,7 @@ mod spec {
                 type Given = CucumberGivenWorld;
                 type When = CucumberWhenWorld;
                 type Then = CucumberThenWorld;
+                type Any = CucumberAnyWorld;
             }
 
             #[automatically_derived]
@@ -251,6 +260,9 @@ mod spec {
 
                  #[doc(hidden)]
                  pub func: ::cucumber::Step<World>,
+
+                 #[doc(hidden)]
+                 pub wip: bool,
             }
 
             #[automatically_derived]
@@ -264,6 +276,10 @@ mod spec {
                 ) {
                     (self.loc, self.regex, self.func)
                 }
+
+                fn is_wip(&self) -> bool {
+                    self.wip
+                }
             }
 
             #[automatically_derived]
@@ -280,6 +296,9 @@ mod spec {
 
                  #[doc(hidden)]
                  pub func: ::cucumber::Step<World>,
+
+                 #[doc(hidden)]
+                 pub wip: bool,
             }
 
             #[automatically_derived]
@@ -293,6 +312,10 @@ mod spec {
                 ) {
                     (self.loc, self.regex, self.func)
                 }
+
+                fn is_wip(&self) -> bool {
+                    self.wip
+                }
             }
 
             #[automatically_derived]
@@ -309,6 +332,9 @@ mod spec {
 
                  #[doc(hidden)]
                  pub func: ::cucumber::Step<World>,
+
+                 #[doc(hidden)]
+                 pub wip: bool,
             }
 
             #[automatically_derived]
@@ -322,10 +348,50 @@ mod spec {
                 ) {
                     (self.loc, self.regex, self.func)
                 }
+
+                fn is_wip(&self) -> bool {
+                    self.wip
+                }
             }
 
             #[automatically_derived]
             ::cucumber::codegen::collect!(CucumberThenWorld);
+
+            #[automatically_derived]
+            #[doc(hidden)]
+            pub struct CucumberAnyWorld {
+                 #[doc(hidden)]
+                 pub loc: ::cucumber::step::Location,
+
+                 #[doc(hidden)]
+                 pub regex: ::cucumber::codegen::LazyRegex,
+
+                 #[doc(hidden)]
+                 pub func: ::cucumber::Step<World>,
+
+                 #[doc(hidden)]
+                 pub wip: bool,
+            }
+
+            #[automatically_derived]
+            impl ::cucumber::codegen::StepConstructor<World> for
+                CucumberAnyWorld
+            {
+                fn inner(&self) -> (
+                    ::cucumber::step::Location,
+                    ::cucumber::codegen::LazyRegex,
+                    ::cucumber::Step<World>,
+                ) {
+                    (self.loc, self.regex, self.func)
+                }
+
+                fn is_wip(&self) -> bool {
+                    self.wip
+                }
+            }
+
+            #[automatically_derived]
+            ::cucumber::codegen::collect!(CucumberAnyWorld);
         };
 
         assert_eq!(
@@ -346,6 +412,7 @@ mod spec {
                 type Given = CucumberGivenWorld;
                 type When = CucumberWhenWorld;
                 type Then = CucumberThenWorld;
+                type Any = CucumberAnyWorld;
             }
 
             #[automatically_derived]
@@ -381,6 +448,9 @@ mod spec {
 
                  #[doc(hidden)]
                  pub func: ::cucumber::Step<World>,
+
+                 #[doc(hidden)]
+                 pub wip: bool,
             }
 
             #[automatically_derived]
@@ -394,6 +464,10 @@ mod spec {
                 ) {
                     (self.loc, self.regex, self.func)
                 }
+
+                fn is_wip(&self) -> bool {
+                    self.wip
+                }
             }
 
             #[automatically_derived]
@@ -410,6 +484,9 @@ mod spec {
 
                  #[doc(hidden)]
                  pub func: ::cucumber::Step<World>,
+
+                 #[doc(hidden)]
+                 pub wip: bool,
             }
 
             #[automatically_derived]
@@ -423,6 +500,10 @@ mod spec {
                 ) {
                     (self.loc, self.regex, self.func)
                 }
+
+                fn is_wip(&self) -> bool {
+                    self.wip
+                }
             }
 
             #[automatically_derived]
@@ -439,6 +520,9 @@ mod spec {
 
                  #[doc(hidden)]
                  pub func: ::cucumber::Step<World>,
+
+                 #[doc(hidden)]
+                 pub wip: bool,
             }
 
             #[automatically_derived]
@@ -452,10 +536,50 @@ mod spec {
                 ) {
                     (self.loc, self.regex, self.func)
                 }
+
+                fn is_wip(&self) -> bool {
+                    self.wip
+                }
             }
 
             #[automatically_derived]
             ::cucumber::codegen::collect!(CucumberThenWorld);
+
+            #[automatically_derived]
+            #[doc(hidden)]
+            pub struct CucumberAnyWorld {
+                 #[doc(hidden)]
+                 pub loc: ::cucumber::step::Location,
+
+                 #[doc(hidden)]
+                 pub regex: ::cucumber::codegen::LazyRegex,
+
+                 #[doc(hidden)]
+                 pub func: ::cucumber::Step<World>,
+
+                 #[doc(hidden)]
+                 pub wip: bool,
+            }
+
+            #[automatically_derived]
+            impl<T> ::cucumber::codegen::StepConstructor<World<T> > for
+                CucumberAnyWorld
+            {
+                fn inner(&self) -> (
+                    ::cucumber::step::Location,
+                    ::cucumber::codegen::LazyRegex,
+                    ::cucumber::Step<World>,
+                ) {
+                    (self.loc, self.regex, self.func)
+                }
+
+                fn is_wip(&self) -> bool {
+                    self.wip
+                }
+            }
+
+            #[automatically_derived]
+            ::cucumber::codegen::collect!(CucumberAnyWorld);
         };
 
         assert_eq!(
@@ -477,6 +601,7 @@ mod spec {
                 type Given = CucumberGivenWorld;
                 type When = CucumberWhenWorld;
                 type Then = CucumberThenWorld;
+                type Any = CucumberAnyWorld;
             }
 
             #[automatically_derived]
@@ -512,6 +637,9 @@ mod spec {
 
                  #[doc(hidden)]
                  pub func: ::cucumber::Step<World>,
+
+                 #[doc(hidden)]
+                 pub wip: bool,
             }
 
             #[automatically_derived]
@@ -525,6 +653,10 @@ mod spec {
                 ) {
                     (self.loc, self.regex, self.func)
                 }
+
+                fn is_wip(&self) -> bool {
+                    self.wip
+                }
             }
 
             #[automatically_derived]
@@ -541,6 +673,9 @@ mod spec {
 
                  #[doc(hidden)]
                  pub func: ::cucumber::Step<World>,
+
+                 #[doc(hidden)]
+                 pub wip: bool,
             }
 
             #[automatically_derived]
@@ -554,6 +689,10 @@ mod spec {
                 ) {
                     (self.loc, self.regex, self.func)
                 }
+
+                fn is_wip(&self) -> bool {
+                    self.wip
+                }
             }
 
             #[automatically_derived]
@@ -570,6 +709,9 @@ mod spec {
 
                  #[doc(hidden)]
                  pub func: ::cucumber::Step<World>,
+
+                 #[doc(hidden)]
+                 pub wip: bool,
             }
 
             #[automatically_derived]
@@ -583,10 +725,50 @@ mod spec {
                 ) {
                     (self.loc, self.regex, self.func)
                 }
+
+                fn is_wip(&self) -> bool {
+                    self.wip
+                }
             }
 
             #[automatically_derived]
             ::cucumber::codegen::collect!(CucumberThenWorld);
+
+            #[automatically_derived]
+            #[doc(hidden)]
+            pub struct CucumberAnyWorld {
+                 #[doc(hidden)]
+                 pub loc: ::cucumber::step::Location,
+
+                 #[doc(hidden)]
+                 pub regex: ::cucumber::codegen::LazyRegex,
+
+                 #[doc(hidden)]
+                 pub func: ::cucumber::Step<World>,
+
+                 #[doc(hidden)]
+                 pub wip: bool,
+            }
+
+            #[automatically_derived]
+            impl<T> ::cucumber::codegen::StepConstructor<World<T> > for
+                CucumberAnyWorld
+            {
+                fn inner(&self) -> (
+                    ::cucumber::step::Location,
+                    ::cucumber::codegen::LazyRegex,
+                    ::cucumber::Step<World>,
+                ) {
+                    (self.loc, self.regex, self.func)
+                }
+
+                fn is_wip(&self) -> bool {
+                    self.wip
+                }
+            }
+
+            #[automatically_derived]
+            ::cucumber::codegen::collect!(CucumberAnyWorld);
         };
 
         assert_eq!(
@@ -27,10 +27,12 @@
 //! [`Writer`]: crate::Writer
 //! [1]: https://cucumber.io/docs/cucumber/api#tag-expressions
 
+use std::env;
+
 use gherkin::tagexpr::TagOperation;
 use regex::Regex;
 
-use crate::writer::Coloring;
+use crate::{diagnostics, writer::Coloring};
 
 pub use clap::{Args, Parser};
 
@@ -92,6 +94,9 @@ where
     Custom: Args,
 {
     /// Regex to filter scenarios by their name.
+    ///
+    /// May be also set via `CUCUMBER_NAME` environment variable. Composes
+    /// with `--tags`, so only scenarios matching both are run.
     #[arg(
         id = "name",
         long = "name",
@@ -111,7 +116,6 @@ where
         long = "tags",
         short = 't',
         value_name = "tagexpr",
-        conflicts_with = "name",
         global = true
     )]
     pub tags_filter: Option<TagOperation>,
@@ -154,6 +158,26 @@ where
     }
 }
 
+/// Name of the environment variable providing a [`Regex`] to filter
+/// [`Scenario`]s by their name, if [`Opts::re_filter`] is not specified.
+///
+/// [`Scenario`]: gherkin::Scenario
+pub const NAME_ENV_VAR: &str = "CUCUMBER_NAME";
+
+/// Parses the [`NAME_ENV_VAR`] environment variable, if it's set.
+///
+/// Invalid values are ignored with a warning.
+pub(crate) fn name_filter_from_env() -> Option<Regex> {
+    let name = env::var(NAME_ENV_VAR).ok()?;
+    Regex::new(&name)
+        .map_err(|e| {
+            diagnostics::emit(format_args!(
+                "ignoring invalid `{NAME_ENV_VAR}` value \"{name}\": {e}",
+            ));
+        })
+        .ok()
+}
+
 /// Indication whether a [`Writer`] using CLI options supports colored output.
 ///
 /// [`Writer`]: crate::Writer
@@ -42,20 +42,31 @@ pub trait WorldInventory: World {
     ///
     /// [`then`]: crate::then
     type Then: inventory::Collect + StepConstructor<Self>;
+
+    /// Struct [`submit`]ted in an [`any`] macro.
+    ///
+    /// [`any`]: crate::any
+    type Any: inventory::Collect + StepConstructor<Self>;
 }
 
 /// Alias for a [`fn`] returning a [`Lazy`] [`Regex`].
 pub type LazyRegex = fn() -> Regex;
 
-/// Trait for registering a [`Step`] with [`given`], [`when`] and [`then`]
-/// attributes inside [`World::collection()`] method.
+/// Trait for registering a [`Step`] with [`given`], [`when`], [`then`] and
+/// [`any`] attributes inside [`World::collection()`] method.
 ///
+/// [`any`]: crate::any
 /// [`given`]: crate::given
 /// [`when`]: crate::when
 /// [`then`]: crate::then
 pub trait StepConstructor<W> {
     /// Returns an inner [`Step`] with the corresponding [`Regex`].
     fn inner(&self) -> (step::Location, LazyRegex, Step<W>);
+
+    /// Indicates whether this [`Step`] is marked as a work-in-progress one.
+    fn is_wip(&self) -> bool {
+        false
+    }
 }
 
 /// Custom parameter of a [Cucumber Expression].
@@ -14,24 +14,31 @@
 
 use std::{
     borrow::Cow,
+    cell::Cell,
     fmt::{Debug, Formatter},
+    iter,
     marker::PhantomData,
     mem,
-    path::Path,
+    path::PathBuf,
+    process,
+    rc::Rc,
     time::Duration,
 };
 
-use futures::{future::LocalBoxFuture, StreamExt as _};
+use futures::{future::LocalBoxFuture, stream, StreamExt as _};
 use gherkin::tagexpr::TagOperation;
 use regex::Regex;
 
 use crate::{
-    cli, event, parser,
+    cli,
+    diagnostics::{self, debug},
+    event,
+    feature::{self, Ext as _},
+    parser, result,
     runner::{self, basic::RetryOptions},
     step,
     tag::Ext as _,
-    writer, Event, Parser, Runner, ScenarioType, Step, World, Writer,
-    WriterExt as _,
+    writer, Event, Parser, Runner, ScenarioType, World, Writer, WriterExt as _,
 };
 
 /// Top-level [Cucumber] executor.
@@ -50,6 +57,7 @@ use crate::{
 /// [`Cucumber::with_writer()`] to construct your dream [Cucumber] executor!
 ///
 /// [Cucumber]: https://cucumber.io
+/// [`Step`]: crate::Step
 pub struct Cucumber<W, P, I, R, Wr, Cli = cli::Empty>
 where
     W: World,
@@ -385,6 +393,48 @@ where
         }
     }
 
+    /// Writes a rerun file of failed [`Scenario`]s into the provided `path` at
+    /// the end of the run.
+    ///
+    /// The written file may be passed prefixed with `@` as an input of the next
+    /// run to execute only the failed [`Scenario`]s:
+    /// ```rust
+    /// # use cucumber::World;
+    /// #
+    /// # #[derive(Debug, Default, World)]
+    /// # struct MyWorld;
+    /// #
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// MyWorld::cucumber()
+    ///     .rerun_file("target/rerun.txt")
+    ///     .run("tests/features/readme")
+    ///     .await;
+    ///
+    /// MyWorld::cucumber()
+    ///     .run("@target/rerun.txt")
+    ///     .await;
+    /// # }
+    /// ```
+    ///
+    /// See [`writer::Rerun`] for more information.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn rerun_file(
+        self,
+        path: impl Into<PathBuf>,
+    ) -> Cucumber<W, P, I, R, writer::Rerun<Wr>, Cli> {
+        Cucumber {
+            parser: self.parser,
+            runner: self.runner,
+            writer: self.writer.rerun_file(path),
+            cli: self.cli,
+            _world: PhantomData,
+            _parser_input: PhantomData,
+        }
+    }
+
     /// Consider [`Skipped`] [`Background`] or regular [`Step`]s as [`Failed`]
     /// if their [`Scenario`] isn't marked with `@allow.skipped` tag.
     ///
@@ -713,6 +763,7 @@ where
     ///
     /// [`Feature`]: gherkin::Feature
     /// [`Scenario`]: gherkin::Scenario
+    #[allow(clippy::too_many_lines)]
     pub async fn filter_run<F>(self, input: I, filter: F) -> Wr
     where
         F: Fn(
@@ -731,27 +782,57 @@ where
             ..
         } = self.cli.unwrap_or_else(cli::Opts::<_, _, _, _>::parsed);
 
+        let re_filter = re_filter.or_else(cli::name_filter_from_env);
+        let filter_kind = match (&re_filter, &tags_filter) {
+            (Some(re), Some(_)) => {
+                format!("name regex `{re}` and tag expression")
+            }
+            (Some(re), None) => format!("name regex `{re}`"),
+            (None, Some(_)) => "tag expression".to_owned(),
+            (None, None) => "`filter_run()` predicate".to_owned(),
+        };
+        let filtered_out = Rc::new(Cell::new(0));
+        let filtered_counter = Rc::clone(&filtered_out);
         let filter = move |feat: &gherkin::Feature,
                            rule: Option<&gherkin::Rule>,
                            scenario: &gherkin::Scenario| {
-            re_filter.as_ref().map_or_else(
-                || {
-                    tags_filter.as_ref().map_or_else(
-                        || filter(feat, rule, scenario),
-                        |tags| {
-                            // The order `Feature` -> `Rule` -> `Scenario`
-                            // matters here.
-                            tags.eval(
-                                feat.tags
-                                    .iter()
-                                    .chain(rule.iter().flat_map(|r| &r.tags))
-                                    .chain(scenario.tags.iter()),
-                            )
-                        },
-                    )
+            let matches_name = |re: &Regex| {
+                re.is_match(&scenario.name)
+                    || re.is_match(&feature::display_name(scenario))
+            };
+            let matches_tags = |tags: &TagOperation| {
+                // The order `Feature` -> `Rule` -> `Scenario` matters here.
+                tags.eval(
+                    feat.tags
+                        .iter()
+                        .chain(rule.iter().flat_map(|r| &r.tags))
+                        .chain(scenario.tags.iter()),
+                )
+            };
+            let is_included = if re_filter.is_none() && tags_filter.is_none() {
+                filter(feat, rule, scenario)
+            } else {
+                re_filter.as_ref().map_or(true, matches_name)
+                    && tags_filter.as_ref().map_or(true, matches_tags)
+            };
+            debug!(
+                "{} `Scenario` \"{}\" at {}:{} by {filter_kind}",
+                if is_included {
+                    "included"
+                } else {
+                    "filtered out"
                 },
-                |re| re.is_match(&scenario.name),
-            )
+                scenario.name,
+                feat.path.as_deref().map_or_else(
+                    || feat.name.clone(),
+                    |p| p.display().to_string()
+                ),
+                scenario.position.line,
+            );
+            if !is_included {
+                filtered_counter.set(filtered_counter.get() + 1);
+            }
+            is_included
         };
 
         let Self {
@@ -763,8 +844,12 @@ where
 
         let features = parser.parse(input, parser_cli);
 
+        let deselected_counter = Rc::clone(&filtered_out);
         let filtered = features.map(move |feature| {
             let mut feature = feature?;
+            let deselected = parser::basic::take_deselected(&mut feature);
+            deselected_counter.set(deselected_counter.get() + deselected);
+
             let feat_scenarios = mem::take(&mut feature.scenarios);
             feature.scenarios = feat_scenarios
                 .into_iter()
@@ -786,11 +871,121 @@ where
 
         let events_stream = runner.run(filtered, runner_cli);
         futures::pin_mut!(events_stream);
-        while let Some(ev) = events_stream.next().await {
+        while let Some(mut ev) = events_stream.next().await {
+            // All the `Feature`s have been filtered once parsing is finished.
+            if let Ok(Event {
+                value:
+                    event::Cucumber::ParsingFinished {
+                        filtered_scenarios, ..
+                    },
+                ..
+            }) = &mut ev
+            {
+                *filtered_scenarios += filtered_out.get();
+            }
             writer.handle_event(ev, &writer_cli).await;
         }
         writer
     }
+
+    /// Runs the given single [`Scenario`] of the provided [`Feature`] (and
+    /// [`Rule`], if the [`Scenario`] belongs to one), bypassing the [`Parser`]
+    /// and any filtering, and returns its [`ScenarioResult`]s.
+    ///
+    /// Useful for driving [`Scenario`]s from a custom test harness (generating
+    /// them with property-based testing, for example). The produced events are
+    /// handled by the [`Writer`] as usual.
+    ///
+    /// Several [`ScenarioResult`]s are returned for a [`Scenario`] with
+    /// [`Examples`] (one for each of their rows) or a retried one (one for each
+    /// attempt).
+    ///
+    /// ```rust
+    /// # use cucumber::{gherkin, given, result::Status, World};
+    /// #
+    /// # #[derive(Debug, Default, World)]
+    /// # struct MyWorld;
+    /// #
+    /// #[given("a hungry cat")]
+    /// fn hungry_cat(_: &mut MyWorld) {}
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let feature = gherkin::Feature::parse(
+    ///     "Feature: Animal feature\n\
+    ///        Scenario: If we feed a hungry cat it will no longer be hungry\n\
+    ///          Given a hungry cat",
+    ///     gherkin::GherkinEnv::default(),
+    /// )
+    /// .unwrap();
+    ///
+    /// // No input is parsed, so its type is arbitrary.
+    /// let results = MyWorld::cucumber::<&str>()
+    ///     .run_scenario(&feature, None, &feature.scenarios[0])
+    ///     .await;
+    /// assert_eq!(results[0].status(), Status::Passed);
+    /// # }
+    /// ```
+    ///
+    /// [`Examples`]: gherkin::Examples
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`ScenarioResult`]: result::ScenarioResult
+    pub async fn run_scenario(
+        self,
+        feature: &gherkin::Feature,
+        rule: Option<&gherkin::Rule>,
+        scenario: &gherkin::Scenario,
+    ) -> Vec<result::ScenarioResult> {
+        let cli::Opts {
+            runner: runner_cli,
+            writer: writer_cli,
+            ..
+        } = self.cli.unwrap_or_else(cli::Opts::<_, _, _, _>::parsed);
+
+        let Self {
+            runner, mut writer, ..
+        } = self;
+
+        let feature = gherkin::Feature {
+            scenarios: rule
+                .is_none()
+                .then(|| scenario.clone())
+                .into_iter()
+                .collect(),
+            rules: rule
+                .map(|r| gherkin::Rule {
+                    scenarios: vec![scenario.clone()],
+                    ..r.clone()
+                })
+                .into_iter()
+                .collect(),
+            ..feature.clone()
+        }
+        .expand_examples()
+        .map_err(parser::Error::from);
+
+        let mut results = Vec::new();
+
+        let events_stream =
+            runner.run(stream::iter(iter::once(feature)), runner_cli);
+        futures::pin_mut!(events_stream);
+        while let Some(ev) = events_stream.next().await {
+            if let Ok(event::Cucumber::Feature(
+                _,
+                event::Feature::Scenario(_, sc)
+                | event::Feature::Rule(_, event::Rule::Scenario(_, sc)),
+            )) = ev.as_deref()
+            {
+                if let event::Scenario::Finished(res) = &sc.event {
+                    results.push(res.as_ref().clone());
+                }
+            }
+            writer.handle_event(ev, &writer_cli).await;
+        }
+        results
+    }
 }
 
 // Implemented manually to omit redundant `W: Clone` and `I: Clone` trait
@@ -851,7 +1046,7 @@ pub(crate) type DefaultCucumber<W, I> = Cucumber<
 impl<W, I> Default for DefaultCucumber<W, I>
 where
     W: World + Debug,
-    I: AsRef<Path>,
+    parser::Basic: Parser<I>,
 {
     fn default() -> Self {
         Self::custom(
@@ -865,7 +1060,7 @@ where
 impl<W, I> DefaultCucumber<W, I>
 where
     W: World + Debug,
-    I: AsRef<Path>,
+    parser::Basic: Parser<I>,
 {
     /// Creates a default [`Cucumber`] executor.
     ///
@@ -873,7 +1068,7 @@ where
     ///
     /// * [`Runner`] — [`runner::Basic`]
     ///   * [`ScenarioType`] — [`Concurrent`] by default, [`Serial`] if
-    ///     `@serial` [tag] is present on a [`Scenario`];
+    ///     `@serial` or `@serial_world` [tag] is present on a [`Scenario`];
     ///   * Allowed to run up to 64 [`Concurrent`] [`Scenario`]s.
     ///
     /// * [`Writer`] — [`Normalize`] and [`Summarize`] [`writer::Basic`].
@@ -897,7 +1092,7 @@ where
     R: Runner<W>,
     Wr: Writer<W>,
     Cli: clap::Args,
-    I: AsRef<Path>,
+    parser::Basic: Parser<I>,
 {
     /// Sets the provided language of [`gherkin`] files.
     ///
@@ -980,6 +1175,135 @@ where
         self
     }
 
+    /// Makes only resolve [`Step`]s of all [`Scenario`]s to their definitions,
+    /// without executing them, so undefined and ambiguous [`Step`]s may be
+    /// detected quickly.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[allow(clippy::missing_const_for_fn)] // false positive: drop in const
+    #[must_use]
+    pub fn dry_run(mut self) -> Self {
+        self.runner = self.runner.dry_run();
+        self
+    }
+
+    /// Sets the [`runner::basic::Order`] to run [`Scenario`]s in.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[allow(clippy::missing_const_for_fn)] // false positive: drop in const
+    #[must_use]
+    pub fn order(mut self, order: runner::basic::Order) -> Self {
+        self.runner = self.runner.order(order);
+        self
+    }
+
+    /// Makes all [`Scenario`]s being run the specified number of times,
+    /// reusing the already parsed [`Feature`]s.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn repeat(mut self, times: impl Into<Option<usize>>) -> Self {
+        self.runner = self.runner.repeat(times);
+        self
+    }
+
+    /// Makes [`Scenario`]s of every [`Feature`] share a single [`World`],
+    /// instead of constructing a new one for each [`Scenario`]. Without this,
+    /// only [`Feature`]s tagged with `@serial_world` share their [`World`]s.
+    ///
+    /// Once any [`Scenario`] sharing a [`World`] fails, the next ones are
+    /// failed with an [`event::StepError::WorldPoisoned`] without being run.
+    ///
+    /// Useful when constructing a [`World`] is expensive, and [`Scenario`]s of
+    /// a [`Feature`] are deliberately sequential.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn world_per_feature(mut self) -> Self {
+        self.runner = self.runner.world_per_feature();
+        self
+    }
+
+    /// Makes report [`Step`] definitions not matched by any [`Step`] once all
+    /// [`Scenario`]s are finished, so the rotten ones may be found.
+    ///
+    /// Consider combining this with a [`Cucumber::dry_run()`] to check all
+    /// the [`Scenario`]s quickly.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn report_unused_steps(mut self) -> Self {
+        self.runner = self.runner.report_unused_steps();
+        self
+    }
+
+    /// Sets the [`runner::StopHandle`] to request a cooperative stop of
+    /// execution with (on receiving `SIGINT`, for example).
+    ///
+    /// __NOTE__: All the already started [`Scenario`]s at the moment of the
+    ///           request will be finished, skipping their remaining [`Step`]s.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn stop_handle(mut self, handle: runner::StopHandle) -> Self {
+        self.runner = self.runner.stop_handle(handle);
+        self
+    }
+
+    /// Makes the first `SIGINT` (Ctrl-C) request a cooperative stop of
+    /// execution (as via a [`Cucumber::stop_handle()`]), marking it as
+    /// [`Aborted`], and a repeated one exit the process immediately with the
+    /// [`Outcome::Aborted`] exit code.
+    ///
+    /// # Panics
+    ///
+    /// If another `SIGINT` handler has been already installed via the
+    /// [`ctrlc`][1] crate.
+    ///
+    /// [`Aborted`]: event::Cucumber::Aborted
+    /// [`Outcome::Aborted`]: writer::Outcome::Aborted
+    /// [1]: https://docs.rs/ctrlc
+    #[cfg(feature = "ctrlc")]
+    #[must_use]
+    pub fn handle_ctrl_c(self) -> Self {
+        self.stop_handle(runner::StopHandle::ctrl_c())
+    }
+
+    /// Makes [`World`]s of finished [`Scenario`]s being pooled and
+    /// [reset][1] for reuse by the next [`Scenario`]s, instead of constructing
+    /// a new [`World`] for each one.
+    ///
+    /// Useful when constructing a [`World`] is expensive.
+    ///
+    /// [1]: World::reset
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn pool_worlds(
+        mut self,
+        options: impl Into<Option<runner::basic::PoolOptions>>,
+    ) -> Self {
+        self.runner = self.runner.pool_worlds(options);
+        self
+    }
+
+    /// Sets a function to be executed once after all [`Scenario`]s are
+    /// finished, even if the execution is aborted by a panic.
+    ///
+    /// Useful for tearing down resources shared by all [`Scenario`]s (like a
+    /// server started before calling [`Cucumber::run()`]).
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn after_all(mut self, func: impl FnOnce() + 'static) -> Self {
+        self.runner = self.runner.after_all(func);
+        self
+    }
+
     /// Makes failed [`Scenario`]s being retried after the specified
     /// [`Duration`] passes.
     ///
@@ -990,6 +1314,22 @@ where
         self
     }
 
+    /// Makes [`Step`]s running longer than the specified [`Duration`] being
+    /// failed with an [`event::StepError::Timeout`].
+    ///
+    /// __NOTE__: Only [`Step`]s yielding to the async runtime can be
+    ///           interrupted.
+    ///
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn step_timeout(
+        mut self,
+        timeout: impl Into<Option<Duration>>,
+    ) -> Self {
+        self.runner = self.runner.step_timeout(timeout);
+        self
+    }
+
     /// Makes failed [`Scenario`]s being retried only if they're matching the
     /// specified `tag_expression`.
     ///
@@ -1060,6 +1400,9 @@ where
     /// Sets a hook, executed on each [`Scenario`] before running all its
     /// [`Step`]s, including [`Background`] ones.
     ///
+    /// This hook is executed before any [`step::BeforeHook`]s of the
+    /// [`step::Collection`] and [`World::before_scenario()`].
+    ///
     /// [`Background`]: gherkin::Background
     /// [`Scenario`]: gherkin::Scenario
     /// [`Step`]: gherkin::Step
@@ -1100,6 +1443,9 @@ where
     /// Last `World` argument is supplied to the function, in case it was
     /// initialized before by running [`before`] hook or any [`Step`].
     ///
+    /// This hook is executed after [`World::after_scenario()`] and all the
+    /// [`step::AfterHook`]s of the [`step::Collection`].
+    ///
     /// [`before`]: Self::before()
     /// [`Failed`]: event::Step::Failed
     /// [`Scenario`]: gherkin::Scenario
@@ -1147,11 +1493,60 @@ where
         self
     }
 
+    /// Registers [`Step`]s and hooks of the given [`step::Provider`], in
+    /// addition to the already present ones.
+    ///
+    /// May be called multiple times to plug in several [`step::Provider`]s,
+    /// including [`step::Collection`]s of local [`Step`]s.
+    ///
+    /// [`Step`]: step::Step
+    #[must_use]
+    pub fn provide<Pr>(mut self, provider: &Pr) -> Self
+    where
+        Pr: step::Provider<W> + ?Sized,
+    {
+        self.runner = self.runner.provide(provider);
+        self
+    }
+
+    /// Makes [`Step`] [`Regex`]es matching only a part of a [`Step`] text to
+    /// be considered as matches, if `allow` is `true`.
+    ///
+    /// See [`step::Collection::partial_matches()`] for details.
+    ///
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn partial_step_matches(mut self, allow: bool) -> Self {
+        self.runner = self.runner.partial_step_matches(allow);
+        self
+    }
+
+    /// Makes [`Step`]s with the same [`Regex`]es as the already registered ones
+    /// to replace them, if `allow` is `true`.
+    ///
+    /// Affects only [`Step`]s registered after this call (via
+    /// [`step::Provider`]s, for example). See
+    /// [`step::Collection::allow_overrides()`] for details.
+    ///
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn allow_step_overrides(mut self, allow: bool) -> Self {
+        self.runner = self.runner.allow_step_overrides(allow);
+        self
+    }
+
     /// Inserts [Given] [`Step`].
     ///
     /// [Given]: https://cucumber.io/docs/gherkin/reference#given
+    /// [`Step`]: crate::Step
     #[must_use]
-    pub fn given(mut self, regex: Regex, step: Step<W>) -> Self {
+    pub fn given<S>(mut self, regex: Regex, step: S) -> Self
+    where
+        S: for<'a> Fn(&'a mut W, step::Context) -> LocalBoxFuture<'a, ()>
+            + Send
+            + Sync
+            + 'static,
+    {
         self.runner = self.runner.given(regex, step);
         self
     }
@@ -1159,8 +1554,15 @@ where
     /// Inserts [When] [`Step`].
     ///
     /// [When]: https://cucumber.io/docs/gherkin/reference#when
+    /// [`Step`]: crate::Step
     #[must_use]
-    pub fn when(mut self, regex: Regex, step: Step<W>) -> Self {
+    pub fn when<S>(mut self, regex: Regex, step: S) -> Self
+    where
+        S: for<'a> Fn(&'a mut W, step::Context) -> LocalBoxFuture<'a, ()>
+            + Send
+            + Sync
+            + 'static,
+    {
         self.runner = self.runner.when(regex, step);
         self
     }
@@ -1168,8 +1570,15 @@ where
     /// Inserts [Then] [`Step`].
     ///
     /// [Then]: https://cucumber.io/docs/gherkin/reference#then
+    /// [`Step`]: crate::Step
     #[must_use]
-    pub fn then(mut self, regex: Regex, step: Step<W>) -> Self {
+    pub fn then<S>(mut self, regex: Regex, step: S) -> Self
+    where
+        S: for<'a> Fn(&'a mut W, step::Context) -> LocalBoxFuture<'a, ()>
+            + Send
+            + Sync
+            + 'static,
+    {
         self.runner = self.runner.then(regex, step);
         self
     }
@@ -1188,13 +1597,16 @@ where
     /// [`Feature`]s sourced from a [`Parser`] are fed to a [`Runner`], which
     /// produces events handled by a [`Writer`].
     ///
-    /// # Panics
+    /// # Exit code
     ///
-    /// If encountered errors while parsing [`Feature`]s or at least one
-    /// [`Step`] [`Failed`].
+    /// If the run hasn't [`Passed`], then exits the current process with the
+    /// [`Outcome::exit_code()`] of the [`Writer`] (encountered errors while
+    /// parsing [`Feature`]s, at least one [`Step`] [`Failed`], etc).
     ///
     /// [`Failed`]: crate::event::Step::Failed
     /// [`Feature`]: gherkin::Feature
+    /// [`Outcome::exit_code()`]: writer::Outcome::exit_code
+    /// [`Passed`]: writer::Outcome::Passed
     /// [`Step`]: gherkin::Step
     pub async fn run_and_exit(self, input: I) {
         self.filter_run_and_exit(input, |_, _, _| true).await;
@@ -1205,10 +1617,11 @@ where
     /// [`Feature`]s sourced from a [`Parser`] are fed to a [`Runner`], which
     /// produces events handled by a [`Writer`].
     ///
-    /// # Panics
+    /// # Exit code
     ///
-    /// If encountered errors while parsing [`Feature`]s or at least one
-    /// [`Step`] [`Failed`].
+    /// If the run hasn't [`Passed`], then exits the current process with the
+    /// [`Outcome::exit_code()`] of the [`Writer`] (encountered errors while
+    /// parsing [`Feature`]s, at least one [`Step`] [`Failed`], etc).
     ///
     /// # Example
     ///
@@ -1251,8 +1664,11 @@ where
     ///
     /// [`Failed`]: crate::event::Step::Failed
     /// [`Feature`]: gherkin::Feature
+    /// [`Outcome::exit_code()`]: writer::Outcome::exit_code
+    /// [`Passed`]: writer::Outcome::Passed
     /// [`Scenario`]: gherkin::Scenario
     /// [`Step`]: crate::Step
+    #[allow(clippy::exit)] // exiting with the `Outcome` is the whole point
     pub async fn filter_run_and_exit<Filter>(self, input: I, filter: Filter)
     where
         Filter: Fn(
@@ -1263,34 +1679,52 @@ where
             + 'static,
     {
         let writer = self.filter_run(input, filter).await;
-        if writer.execution_has_failed() {
-            let mut msg = Vec::with_capacity(3);
-
-            let failed_steps = writer.failed_steps();
-            if failed_steps > 0 {
-                msg.push(format!(
-                    "{failed_steps} step{} failed",
-                    (failed_steps > 1).then_some("s").unwrap_or_default(),
-                ));
-            }
+        let outcome = writer.outcome();
+        if outcome == writer::Outcome::Passed {
+            return;
+        }
 
-            let parsing_errors = writer.parsing_errors();
-            if parsing_errors > 0 {
-                msg.push(format!(
-                    "{parsing_errors} parsing error{}",
-                    (parsing_errors > 1).then_some("s").unwrap_or_default(),
-                ));
-            }
+        let mut msg = Vec::with_capacity(5);
 
-            let hook_errors = writer.hook_errors();
-            if hook_errors > 0 {
-                msg.push(format!(
-                    "{hook_errors} hook error{}",
-                    (hook_errors > 1).then_some("s").unwrap_or_default(),
-                ));
-            }
+        if writer.execution_aborted() {
+            msg.push("execution aborted".to_owned());
+        }
 
-            panic!("{}", msg.join(", "));
+        let strict_failed_steps = writer.strict_failed_steps();
+        let failed_steps = writer.failed_steps() - strict_failed_steps;
+        if failed_steps > 0 {
+            msg.push(format!(
+                "{failed_steps} step{} failed",
+                (failed_steps > 1).then_some("s").unwrap_or_default(),
+            ));
         }
+        if strict_failed_steps > 0 {
+            msg.push(format!(
+                "{strict_failed_steps} step{} undefined or pending",
+                (strict_failed_steps > 1).then_some("s").unwrap_or_default(),
+            ));
+        }
+
+        let parsing_errors = writer.parsing_errors();
+        if parsing_errors > 0 {
+            msg.push(format!(
+                "{parsing_errors} parsing error{}",
+                (parsing_errors > 1).then_some("s").unwrap_or_default(),
+            ));
+        }
+
+        let hook_errors = writer.hook_errors();
+        if hook_errors > 0 {
+            msg.push(format!(
+                "{hook_errors} hook error{}",
+                (hook_errors > 1).then_some("s").unwrap_or_default(),
+            ));
+        }
+
+        // `process::exit()` runs no destructors, so the `Writer` should flush
+        // its output before.
+        drop(writer);
+        diagnostics::emit(format_args!("{}", msg.join(", ")));
+        process::exit(outcome.exit_code().into());
     }
 }
@@ -0,0 +1,54 @@
+//! Internal diagnostics of a [`Cucumber`] run.
+//!
+//! Describes decisions made by [`Parser`], [`Runner`] and [`Cucumber`] itself
+//! (discovered files, filtered [`Scenario`]s, resolved [`Step`]s), and is
+//! enabled by setting the [`ENV_VAR`] environment variable to `debug`.
+//!
+//! Diagnostics are always written directly to [`io::Stderr`], bypassing any
+//! [`Writer`], so they never corrupt the output of the latter.
+//!
+//! [`Cucumber`]: crate::Cucumber
+//! [`Parser`]: crate::Parser
+//! [`Runner`]: crate::Runner
+//! [`Scenario`]: gherkin::Scenario
+//! [`Step`]: gherkin::Step
+//! [`Writer`]: crate::Writer
+
+use std::{env, fmt, io, io::Write as _};
+
+use once_cell::sync::Lazy;
+
+/// Name of the environment variable enabling internal diagnostics.
+pub(crate) const ENV_VAR: &str = "CUCUMBER_LOG";
+
+/// Indicates whether internal diagnostics are enabled.
+#[must_use]
+pub(crate) fn enabled() -> bool {
+    /// Cached value of the [`ENV_VAR`] check.
+    static ENABLED: Lazy<bool> = Lazy::new(|| {
+        env::var(ENV_VAR).is_ok_and(|v| v.eq_ignore_ascii_case("debug"))
+    });
+
+    *ENABLED
+}
+
+/// Writes the given diagnostic message into [`io::Stderr`].
+///
+/// Failures are ignored, as diagnostics must never affect the run itself.
+pub(crate) fn emit(msg: fmt::Arguments<'_>) {
+    drop(writeln!(io::stderr().lock(), "[cucumber] {msg}"));
+}
+
+/// Emits a diagnostic message, if [`enabled()`].
+///
+/// Arguments are formatted lazily, so this macro costs nothing when
+/// diagnostics are disabled.
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::diagnostics::enabled() {
+            $crate::diagnostics::emit(format_args!($($arg)*));
+        }
+    };
+}
+
+pub(crate) use debug;
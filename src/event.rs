@@ -19,14 +19,14 @@
 //! [`Runner`]: crate::Runner
 //! [Cucumber]: https://cucumber.io
 
-use std::{any::Any, fmt, sync::Arc};
+use std::{any::Any, backtrace::Backtrace, fmt, sync::Arc, time::Duration};
 
 #[cfg(feature = "timestamps")]
 use std::time::SystemTime;
 
 use derive_more::{AsRef, Deref, DerefMut, Display, Error, From};
 
-use crate::{step, writer::basic::coerce_error};
+use crate::{result, step, writer::basic::coerce_error};
 
 /// Alias for a [`catch_unwind()`] error.
 ///
@@ -185,8 +185,65 @@ pub enum Cucumber<World> {
         ///
         /// [`Parser`]: crate::Parser
         parser_errors: usize,
+
+        /// Number of [`Scenario`]s filtered out and not run (by a tag
+        /// expression, for example).
+        ///
+        /// These [`Scenario`]s are not counted in the `scenarios` field.
+        ///
+        /// [`Scenario`]: gherkin::Scenario
+        filtered_scenarios: usize,
+
+        /// Indicator whether [`Step`]s are only resolved to their definitions
+        /// without being executed (so the undefined ones fail the execution).
+        ///
+        /// [`Step`]: gherkin::Step
+        dry_run: bool,
+
+        /// Seed the [`Scenario`]s are shuffled with, if they're run in a
+        /// random order.
+        ///
+        /// Running them with the same seed again reproduces the same order.
+        ///
+        /// [`Scenario`]: gherkin::Scenario
+        seed: Option<u64>,
     },
 
+    /// [`Step`] definitions defined more than once (with the same [`Regex`]),
+    /// along with all their [`Location`]s.
+    ///
+    /// Emitted right after [`Cucumber::Started`], and only if there are any
+    /// and [`step::Collection::allow_overrides()`] is not used.
+    ///
+    /// [`Location`]: step::Location
+    /// [`Regex`]: regex::Regex
+    DuplicateSteps(Vec<step::Duplicate>),
+
+    /// [`Step`] definitions not matched by any [`Step`] during the run, along
+    /// with their [`StepType`]s ([`None`] for generic ones).
+    ///
+    /// Emitted right before [`Cucumber::Finished`], and only if reporting of
+    /// unused [`Step`] definitions is enabled.
+    ///
+    /// [`Step`]: gherkin::Step
+    /// [`StepType`]: gherkin::StepType
+    UnusedSteps(
+        Vec<(
+            Option<gherkin::StepType>,
+            step::HashableRegex,
+            Option<step::Location>,
+        )>,
+    ),
+
+    /// [`Cucumber`] execution being stopped before all the [`Scenario`]s have
+    /// been run, due to a requested stop (on receiving `SIGINT`, for example).
+    ///
+    /// Emitted right before [`Cucumber::Finished`] (and after
+    /// [`Cucumber::UnusedSteps`], if any).
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    Aborted,
+
     /// [`Cucumber`] execution being finished.
     Finished,
 }
@@ -204,13 +261,22 @@ impl<World> Clone for Cucumber<World> {
                 scenarios,
                 steps,
                 parser_errors,
+                filtered_scenarios,
+                dry_run,
+                seed,
             } => Self::ParsingFinished {
                 features: *features,
                 rules: *rules,
                 scenarios: *scenarios,
                 steps: *steps,
                 parser_errors: *parser_errors,
+                filtered_scenarios: *filtered_scenarios,
+                dry_run: *dry_run,
+                seed: *seed,
             },
+            Self::DuplicateSteps(steps) => Self::DuplicateSteps(steps.clone()),
+            Self::UnusedSteps(steps) => Self::UnusedSteps(steps.clone()),
+            Self::Aborted => Self::Aborted,
             Self::Finished => Self::Finished,
         }
     }
@@ -236,12 +302,16 @@ impl<World> Cucumber<World> {
         Self::Feature(feat, Feature::Rule(rule, Rule::Started))
     }
 
-    /// Constructs an event of a [`Feature`] being finished.
+    /// Constructs an event of a [`Feature`] being finished with the given
+    /// [`result::FeatureResult`].
     ///
     /// [`Feature`]: gherkin::Feature
     #[must_use]
-    pub fn feature_finished(feat: Arc<gherkin::Feature>) -> Self {
-        Self::Feature(feat, Feature::Finished)
+    pub const fn feature_finished(
+        feat: Arc<gherkin::Feature>,
+        result: Arc<result::FeatureResult>,
+    ) -> Self {
+        Self::Feature(feat, Feature::Finished(result))
     }
 
     /// Constructs an event of a [`Rule`] being finished.
@@ -290,10 +360,11 @@ pub enum Feature<World> {
     /// [`Scenario`] event.
     Scenario(Arc<gherkin::Scenario>, RetryableScenario<World>),
 
-    /// [`Feature`] execution being finished.
+    /// [`Feature`] execution being finished, along with its aggregated
+    /// [`result::FeatureResult`].
     ///
     /// [`Feature`]: gherkin::Feature
-    Finished,
+    Finished(Arc<result::FeatureResult>),
 }
 
 // Manual implementation is required to omit the redundant `World: Clone` trait
@@ -304,7 +375,7 @@ impl<World> Clone for Feature<World> {
             Self::Started => Self::Started,
             Self::Rule(r, ev) => Self::Rule(Arc::clone(r), ev.clone()),
             Self::Scenario(s, ev) => Self::Scenario(Arc::clone(s), ev.clone()),
-            Self::Finished => Self::Finished,
+            Self::Finished(res) => Self::Finished(Arc::clone(res)),
         }
     }
 }
@@ -360,6 +431,30 @@ pub enum Step<World> {
     /// [`step::Collection`]: crate::step::Collection
     Skipped,
 
+    /// [`Step`] being pending.
+    ///
+    /// That means the [`Step`] matches a work-in-progress definition in a
+    /// [`step::Collection`], so it's not executed, or its execution has been
+    /// stopped by the [`pending!`] macro.
+    ///
+    /// [`pending!`]: crate::pending!
+    /// [`Step`]: gherkin::Step
+    /// [`step::Collection`]: crate::step::Collection
+    Pending(Option<step::Location>),
+
+    /// [`Step`] being skipped at runtime by the [`skip!`] macro, with the
+    /// provided reason.
+    ///
+    /// Skips the rest of the [`Scenario`] without failing it.
+    ///
+    /// Also reported for the [`Step`]s resolved to their definitions without
+    /// being executed in a dry run.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`skip!`]: crate::skip!
+    /// [`Step`]: gherkin::Step
+    SkippedWithReason(Option<step::Location>, String),
+
     /// [`Step`] passed.
     ///
     /// [`Step`]: gherkin::Step
@@ -383,6 +478,10 @@ impl<World> Clone for Step<World> {
         match self {
             Self::Started => Self::Started,
             Self::Skipped => Self::Skipped,
+            Self::Pending(loc) => Self::Pending(*loc),
+            Self::SkippedWithReason(loc, reason) => {
+                Self::SkippedWithReason(*loc, reason.clone())
+            }
             Self::Passed(captures, loc) => Self::Passed(captures.clone(), *loc),
             Self::Failed(captures, loc, w, info) => {
                 Self::Failed(captures.clone(), *loc, w.clone(), info.clone())
@@ -406,6 +505,18 @@ pub enum StepError {
     #[display(fmt = "Step doesn't match any function")]
     NotFound,
 
+    /// [`Step`] matches a work-in-progress definition, or is marked as pending
+    /// by the [`pending!`] macro.
+    ///
+    /// It's emitted whenever a [`Step::Pending`] event cannot be tolerated
+    /// (such as when [`fail_on_pending()`] is used).
+    ///
+    /// [`fail_on_pending()`]: crate::writer::FailOnSkipped::fail_on_pending()
+    /// [`pending!`]: crate::pending!
+    /// [`Step`]: gherkin::Step
+    #[display(fmt = "Step is work-in-progress")]
+    Pending,
+
     /// [`Step`] matches multiple [`Regex`]es.
     ///
     /// [`Regex`]: regex::Regex
@@ -416,8 +527,89 @@ pub enum StepError {
     /// [`Step`] panicked.
     ///
     /// [`Step`]: gherkin::Step
-    #[display(fmt = "Step panicked. Captured output: {}", "coerce_error(_0)")]
-    Panic(#[error(not(source))] Info),
+    #[display(
+        fmt = "Step panicked. Captured output: {}",
+        "coerce_error(&_0.payload)"
+    )]
+    Panic(#[error(not(source))] PanicDetails),
+
+    /// [`Step`] hasn't finished in the configured [`Duration`].
+    ///
+    /// [`Step`]: gherkin::Step
+    #[display(
+        fmt = "Step timed out after {}",
+        "humantime::format_duration(*_0)"
+    )]
+    Timeout(#[error(not(source))] Duration),
+
+    /// [`Step`] wasn't run, because its [`World`] shared between [`Scenario`]s
+    /// of a [`Feature`] is poisoned by a failure of a previous [`Scenario`].
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    /// [`World`]: crate::World
+    #[display(fmt = "World is poisoned by a previously failed Scenario")]
+    WorldPoisoned,
+}
+
+/// Details of a [`Step`] panic.
+///
+/// [`Step`]: gherkin::Step
+#[derive(Clone)]
+pub struct PanicDetails {
+    /// Payload of the panic.
+    pub payload: Info,
+
+    /// [`PanicLocation`] of the panic, if known.
+    ///
+    /// Unknown for panics happened outside the thread running the [`Step`].
+    ///
+    /// [`Step`]: gherkin::Step
+    pub location: Option<PanicLocation>,
+
+    /// [`Backtrace`] of the panic, if it has been captured.
+    ///
+    /// It's captured only when enabled via `RUST_BACKTRACE` or
+    /// `RUST_LIB_BACKTRACE` environment variables.
+    pub backtrace: Option<Arc<Backtrace>>,
+}
+
+impl PanicDetails {
+    /// Creates new [`PanicDetails`] out of the given `payload` only.
+    #[must_use]
+    pub fn new(payload: Info) -> Self {
+        Self {
+            payload,
+            location: None,
+            backtrace: None,
+        }
+    }
+}
+
+// Implemented manually to omit the lengthy `backtrace`, which is output only
+// on demand.
+impl fmt::Debug for PanicDetails {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PanicDetails")
+            .field("payload", &self.payload)
+            .field("location", &self.location)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Location in source code, where a panic has happened.
+#[derive(Clone, Debug, Display, Eq, PartialEq)]
+#[display(fmt = "{}:{}:{}", file, line, column)]
+pub struct PanicLocation {
+    /// Path to the file where the panic has happened.
+    pub file: String,
+
+    /// Line of the panic.
+    pub line: u32,
+
+    /// Column of the panic.
+    pub column: u32,
 }
 
 /// Type of a hook executed before or after all [`Scenario`]'s [`Step`]s.
@@ -498,10 +690,11 @@ pub enum Scenario<World> {
     /// [`Scenario`]'s log entry is emitted.
     Log(String),
 
-    /// [`Scenario`] execution being finished.
+    /// [`Scenario`] execution being finished, along with its aggregated
+    /// [`result::ScenarioResult`].
     ///
     /// [`Scenario`]: gherkin::Scenario
-    Finished,
+    Finished(Arc<result::ScenarioResult>),
 }
 
 // Manual implementation is required to omit the redundant `World: Clone` trait
@@ -516,7 +709,7 @@ impl<World> Clone for Scenario<World> {
             }
             Self::Step(st, ev) => Self::Step(Arc::clone(st), ev.clone()),
             Self::Log(msg) => Self::Log(msg.clone()),
-            Self::Finished => Self::Finished,
+            Self::Finished(res) => Self::Finished(Arc::clone(res)),
         }
     }
 }
@@ -608,6 +801,55 @@ impl<World> Scenario<World> {
         Self::Background(step, Step::Skipped)
     }
 
+    /// Constructs an event of a [`Step`] skipped with the given `reason`.
+    ///
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub const fn step_skipped_with_reason(
+        step: Arc<gherkin::Step>,
+        loc: Option<step::Location>,
+        reason: String,
+    ) -> Self {
+        Self::Step(step, Step::SkippedWithReason(loc, reason))
+    }
+
+    /// Constructs an event of a [`Background`] [`Step`] skipped with the given
+    /// `reason`.
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub const fn background_step_skipped_with_reason(
+        step: Arc<gherkin::Step>,
+        loc: Option<step::Location>,
+        reason: String,
+    ) -> Self {
+        Self::Background(step, Step::SkippedWithReason(loc, reason))
+    }
+
+    /// Constructs an event of a pending [`Step`].
+    ///
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub const fn step_pending(
+        step: Arc<gherkin::Step>,
+        loc: Option<step::Location>,
+    ) -> Self {
+        Self::Step(step, Step::Pending(loc))
+    }
+
+    /// Constructs an event of a pending [`Background`] [`Step`].
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub const fn background_step_pending(
+        step: Arc<gherkin::Step>,
+        loc: Option<step::Location>,
+    ) -> Self {
+        Self::Background(step, Step::Pending(loc))
+    }
+
     /// Constructs an event of a failed [`Step`].
     ///
     /// [`Step`]: gherkin::Step
@@ -687,7 +929,7 @@ pub enum ScenarioFinished {
     /// [`Step::Passed`].
     StepPassed,
 
-    /// [`Step::Skipped`].
+    /// [`Step::Skipped`] or [`Step::Pending`].
     StepSkipped,
 
     /// [`Step::Failed`].
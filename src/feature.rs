@@ -11,8 +11,12 @@
 //! [`gherkin::Feature`] extension.
 
 use std::{
+    borrow::Cow,
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash as _, Hasher as _},
     iter, mem,
     path::{Path, PathBuf},
+    sync::{Mutex, PoisonError},
 };
 
 use derive_more::{Display, Error};
@@ -96,21 +100,8 @@ pub trait Ext: Sized {
 
 #[sealed]
 impl Ext for gherkin::Feature {
-    fn expand_examples(mut self) -> Result<Self, ExpandExamplesError> {
-        let path = self.path.clone();
-        let expand = |scenarios: Vec<gherkin::Scenario>| -> Result<_, _> {
-            scenarios
-                .into_iter()
-                .flat_map(|s| expand_scenario(s, path.as_ref()))
-                .collect()
-        };
-
-        for r in &mut self.rules {
-            r.scenarios = expand(mem::take(&mut r.scenarios))?;
-        }
-        self.scenarios = expand(mem::take(&mut self.scenarios))?;
-
-        Ok(self)
+    fn expand_examples(self) -> Result<Self, ExpandExamplesError> {
+        expand_examples_in(self, None)
     }
 
     fn count_scenarios(&self) -> usize {
@@ -129,6 +120,37 @@ impl Ext for gherkin::Feature {
     }
 }
 
+/// Expands [`Scenario Outline`][1] [`Examples`] of the given [`Feature`] in the
+/// same way as [`Ext::expand_examples()`] does, looking up the lines of their
+/// rows in the `source` the [`Feature`] was parsed from, if provided.
+///
+/// # Errors
+///
+/// See [`ExpandExamplesError`] for details.
+///
+/// [`Examples`]: gherkin::Examples
+/// [`Feature`]: gherkin::Feature
+/// [1]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+pub(crate) fn expand_examples_in(
+    mut feature: gherkin::Feature,
+    source: Option<&str>,
+) -> Result<gherkin::Feature, ExpandExamplesError> {
+    let path = feature.path.clone();
+    let expand = |scenarios: Vec<gherkin::Scenario>| -> Result<_, _> {
+        scenarios
+            .into_iter()
+            .flat_map(|s| expand_scenario(s, path.as_ref(), source))
+            .collect()
+    };
+
+    for r in &mut feature.rules {
+        r.scenarios = expand(mem::take(&mut r.scenarios))?;
+    }
+    feature.scenarios = expand(mem::take(&mut feature.scenarios))?;
+
+    Ok(feature)
+}
+
 /// Expands [`Scenario`] [`Examples`], if any.
 ///
 /// # Errors
@@ -140,6 +162,7 @@ impl Ext for gherkin::Feature {
 fn expand_scenario(
     scenario: gherkin::Scenario,
     path: Option<&PathBuf>,
+    source: Option<&str>,
 ) -> Vec<Result<gherkin::Scenario, ExpandExamplesError>> {
     /// [`Regex`] matching placeholders [`Examples`] should expand into.
     ///
@@ -153,20 +176,31 @@ fn expand_scenario(
     scenario
         .examples
         .iter()
-        .filter_map(|ex| {
-            ex.table
-                .as_ref()?
+        .enumerate()
+        .filter_map(|(n, ex)| {
+            let table = ex.table.as_ref()?;
+            table
                 .rows
                 .split_first()
-                .map(|(h, v)| (h, v, ex))
+                .map(|(h, v)| (n, h, v, ex, rows_lines(table, source)))
         })
-        .flat_map(|(header, vals, example)| {
+        .flat_map(|(n, header, vals, example, lines)| {
             vals.iter()
                 .map(|v| header.iter().zip(v))
+                .zip(lines)
                 .enumerate()
-                .zip(iter::repeat((example.position, example.tags.iter())))
+                .map(move |(id, (row, line))| {
+                    let origin = ExamplesRow {
+                        examples: n,
+                        row: id,
+                        number: 0,
+                        line,
+                    };
+                    (origin, row, example)
+                })
         })
-        .map(|((id, row), (position, tags))| {
+        .enumerate()
+        .map(|(number, (mut origin, row, example))| {
             let replace_templates = |str: &str, pos| {
                 let mut err = None;
                 let replaced = TEMPLATE_REGEX
@@ -198,10 +232,10 @@ fn expand_scenario(
 
             // This is done to differentiate `Hash`es of
             // scenario outlines with the same examples.
-            expanded.position = position;
-            expanded.position.line += id + 2;
+            expanded.position = example.position;
+            expanded.position.line += origin.row + 2;
 
-            expanded.tags.extend(tags.cloned());
+            expanded.tags.extend(example.tags.iter().cloned());
 
             expanded.name =
                 replace_templates(&expanded.name, expanded.position)?;
@@ -216,11 +250,184 @@ fn expand_scenario(
                 }
             }
 
+            origin.number = number + 1;
+            _ = EXPANDED
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .insert(hash(&expanded), origin);
+
             Ok(expanded)
         })
         .collect()
 }
 
+/// [`ExamplesRow`]s of all the [`Scenario`]s expanded so far, keyed by their
+/// [`hash()`].
+///
+/// An expanded [`Scenario`] cannot hold its origin by itself, while writing it
+/// into its `position` would change the output of the expanded [`Scenario`]s.
+///
+/// [`Scenario`]: gherkin::Scenario
+static EXPANDED: Lazy<Mutex<HashMap<u64, ExamplesRow>>> =
+    Lazy::new(Mutex::default);
+
+/// Origin of a [`Scenario`] expanded from a [`Scenario Outline`][1].
+///
+/// [`Scenario`]: gherkin::Scenario
+/// [1]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+#[derive(Clone, Copy, Debug)]
+struct ExamplesRow {
+    /// Index of the [`Examples`] in the [`Scenario Outline`][1].
+    ///
+    /// [`Examples`]: gherkin::Examples
+    /// [1]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+    examples: usize,
+
+    /// Index of the row in the [`Examples`] table, excluding its header.
+    ///
+    /// [`Examples`]: gherkin::Examples
+    row: usize,
+
+    /// 1-based number of the row, counting across all the [`Examples`] of the
+    /// [`Scenario Outline`][1].
+    ///
+    /// [`Examples`]: gherkin::Examples
+    /// [1]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+    number: usize,
+
+    /// Line of the row in its `.feature` file.
+    line: usize,
+}
+
+/// Hashes the given [`Scenario`] to look up its [`ExamplesRow`] with.
+///
+/// [`Scenario`]: gherkin::Scenario
+fn hash(scenario: &gherkin::Scenario) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    scenario.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Returns the [`ExamplesRow`] the given [`Scenario`] was expanded from, if
+/// any.
+///
+/// [`Scenario`]: gherkin::Scenario
+fn origin(scenario: &gherkin::Scenario) -> Option<ExamplesRow> {
+    // Only `Scenario Outline`s have `Examples`, so other `Scenario`s are not
+    // worth hashing.
+    if scenario.examples.is_empty() {
+        return None;
+    }
+    EXPANDED
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(&hash(scenario))
+        .copied()
+}
+
+/// Returns lines of the given [`gherkin::Table`] rows, excluding its header.
+///
+/// The lines are looked up in the `source` the [`gherkin::Table`] was parsed
+/// from, if provided, as the rows may be separated by blank lines and
+/// comments. Otherwise, the rows are considered to follow each other.
+#[must_use]
+pub(crate) fn rows_lines(
+    table: &gherkin::Table,
+    source: Option<&str>,
+) -> Vec<usize> {
+    let len = table.rows.len().saturating_sub(1);
+    let consecutive = || (1..=len).map(|n| table.position.line + n).collect();
+
+    source.map_or_else(consecutive, |src| {
+        // `gherkin::LineCol::line` is 1-based, so skipping that many lines
+        // skips the table header as well.
+        let lines = src
+            .lines()
+            .enumerate()
+            .skip(table.position.line)
+            .filter(|(_, l)| l.trim_start().starts_with('|'))
+            .take(len)
+            .map(|(n, _)| n + 1)
+            .collect::<Vec<_>>();
+        if lines.len() == len {
+            lines
+        } else {
+            consecutive()
+        }
+    })
+}
+
+/// Returns the [`Examples`] row the given [`Scenario`] was expanded from, as a
+/// [`gherkin::Table`] consisting of the header and the row values, positioned
+/// at the row's line.
+///
+/// Returns [`None`] if the given [`Scenario`] wasn't expanded from a
+/// [`Scenario Outline`][1].
+///
+/// [`Examples`]: gherkin::Examples
+/// [`Scenario`]: gherkin::Scenario
+/// [1]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+#[must_use]
+pub(crate) fn examples_row(
+    scenario: &gherkin::Scenario,
+) -> Option<gherkin::Table> {
+    let origin = origin(scenario)?;
+    let table = scenario.examples.get(origin.examples)?.table.as_ref()?;
+    let (header, vals) = table.rows.split_first()?;
+
+    Some(gherkin::Table {
+        rows: vec![header.clone(), vals.get(origin.row)?.clone()],
+        span: table.span,
+        position: gherkin::LineCol {
+            line: origin.line,
+            col: table.position.col,
+        },
+    })
+}
+
+/// Returns the line the given [`Scenario`] is defined at in its `.feature`
+/// file: the line of its [`Examples`] row, if it was expanded from a
+/// [`Scenario Outline`][1], or its own line otherwise.
+///
+/// [`Examples`]: gherkin::Examples
+/// [`Scenario`]: gherkin::Scenario
+/// [1]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+#[must_use]
+pub(crate) fn source_line(scenario: &gherkin::Scenario) -> usize {
+    origin(scenario).map_or(scenario.position.line, |o| o.line)
+}
+
+/// Returns the 1-based number of the [`Examples`] row the given [`Scenario`] was
+/// expanded from, counting across all the [`Examples`] of its
+/// [`Scenario Outline`][1].
+///
+/// Returns [`None`] if the given [`Scenario`] wasn't expanded from a
+/// [`Scenario Outline`][1].
+///
+/// [`Examples`]: gherkin::Examples
+/// [`Scenario`]: gherkin::Scenario
+/// [1]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+#[must_use]
+pub(crate) fn examples_row_number(
+    scenario: &gherkin::Scenario,
+) -> Option<usize> {
+    origin(scenario).map(|o| o.number)
+}
+
+/// Returns the name of the given [`Scenario`] to be displayed, decorated with
+/// its [`examples_row_number()`] (like `Transfer money [3]`), if it was
+/// expanded from a [`Scenario Outline`][1].
+///
+/// [`Scenario`]: gherkin::Scenario
+/// [1]: https://cucumber.io/docs/gherkin/reference#scenario-outline
+#[must_use]
+pub(crate) fn display_name(scenario: &gherkin::Scenario) -> Cow<'_, str> {
+    examples_row_number(scenario)
+        .map_or(Cow::Borrowed(scenario.name.as_str()), |n| {
+            Cow::Owned(format!("{} [{n}]", scenario.name))
+        })
+}
+
 /// Error of [`Scenario Outline`][1] expansion encountering an unknown template.
 ///
 /// [1]: https://cucumber.io/docs/gherkin/reference#scenario-outline
@@ -242,3 +449,84 @@ pub struct ExpandExamplesError {
     /// [`Path`] to the `.feature` file, if present.
     pub path: Option<PathBuf>,
 }
+
+#[cfg(test)]
+mod examples_row {
+    use gherkin::GherkinEnv;
+
+    use super::{display_name, examples_row, expand_examples_in, Ext as _};
+
+    // language=Gherkin
+    const FEATURE: &str = "
+Feature: outline
+  Scenario: plain
+    Given a step
+
+  Scenario Outline: outline
+    Given <foo> and <bar>
+
+    Examples:
+      | foo | bar |
+      | 1   | 2   |
+      | 3   | 4   |
+
+    Examples:
+      | foo | bar |
+      | 5   | 6   |
+
+    Examples: described
+      Rows are separated from the keyword.
+
+      | foo | bar |
+      # Comments are allowed between the rows.
+      | 7   | 8   |
+";
+
+    #[test]
+    fn resolves_expanded_rows() {
+        let f = gherkin::Feature::parse(FEATURE, GherkinEnv::default())
+            .unwrap_or_else(|e| panic!("failed to parse feature: {e}"));
+        let f = expand_examples_in(f, Some(FEATURE))
+            .unwrap_or_else(|e| panic!("failed to expand examples: {e}"));
+
+        assert_eq!(examples_row(&f.scenarios[0]), None);
+
+        let rows = f.scenarios[1..]
+            .iter()
+            .map(|sc| {
+                let row = examples_row(sc)
+                    .unwrap_or_else(|| panic!("no `Examples` row"));
+                (row.position.line, row.rows.concat().join(","))
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            [
+                (11, "foo,bar,1,2".to_owned()),
+                (12, "foo,bar,3,4".to_owned()),
+                (16, "foo,bar,5,6".to_owned()),
+                (23, "foo,bar,7,8".to_owned()),
+            ],
+        );
+    }
+
+    #[test]
+    fn decorates_expanded_names() {
+        let f = gherkin::Feature::parse(FEATURE, GherkinEnv::default())
+            .unwrap_or_else(|e| panic!("failed to parse feature: {e}"))
+            .expand_examples()
+            .unwrap_or_else(|e| panic!("failed to expand examples: {e}"));
+
+        let names = f.scenarios.iter().map(display_name).collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            [
+                "plain",
+                "outline [1]",
+                "outline [2]",
+                "outline [3]",
+                "outline [4]",
+            ],
+        );
+    }
+}
@@ -148,10 +148,12 @@
 
 pub mod cli;
 mod cucumber;
+pub(crate) mod diagnostics;
 pub mod event;
 pub mod feature;
 pub(crate) mod future;
 pub mod parser;
+pub mod result;
 pub mod runner;
 pub mod step;
 pub mod tag;
@@ -189,7 +191,7 @@ pub use gherkin;
 pub use self::codegen::Parameter;
 #[cfg(feature = "macros")]
 #[doc(inline)]
-pub use cucumber_codegen::{given, then, when, Parameter, World};
+pub use cucumber_codegen::{any, given, then, when, Parameter, World};
 
 #[doc(inline)]
 pub use self::{
@@ -225,9 +227,68 @@ pub trait World: Sized + 'static {
     /// Creates a new [`World`] instance.
     async fn new() -> Result<Self, Self::Error>;
 
+    /// Executed on this [`World`] right after its construction, before any
+    /// [`Step`] of the [`Scenario`] is run.
+    ///
+    /// Runs after all the [`Cucumber::before()`] and [`step::Collection`]
+    /// hooks, so it's the innermost one. A panic inside it fails the
+    /// [`Scenario`] the same way a [`HookType::Before`] panic does, or fails
+    /// the first [`Step`], if the [`World`] was lazily constructed by it (when
+    /// there are no [`HookType::Before`] hooks for the [`Scenario`]).
+    ///
+    /// Does nothing by default.
+    ///
+    /// [`HookType::Before`]: event::HookType::Before
+    /// [`Scenario`]: gherkin::Scenario
+    async fn before_scenario(
+        &mut self,
+        _feature: &gherkin::Feature,
+        _rule: Option<&gherkin::Rule>,
+        _scenario: &gherkin::Scenario,
+    ) {
+    }
+
+    /// Executed on this [`World`] after all the [`Step`]s of the [`Scenario`]
+    /// have been run, before it's dropped.
+    ///
+    /// Runs before all the [`step::Collection`] and [`Cucumber::after()`]
+    /// hooks, so it's the innermost one. A panic inside it fails the
+    /// [`Scenario`] the same way a [`HookType::After`] panic does.
+    ///
+    /// Isn't executed if this [`World`] hasn't been constructed at all (for
+    /// example, when the first [`Step`] is not matched).
+    ///
+    /// Does nothing by default.
+    ///
+    /// [`HookType::After`]: event::HookType::After
+    /// [`Scenario`]: gherkin::Scenario
+    async fn after_scenario(
+        &mut self,
+        _feature: &gherkin::Feature,
+        _rule: Option<&gherkin::Rule>,
+        _scenario: &gherkin::Scenario,
+        _finished: &event::ScenarioFinished,
+    ) {
+    }
+
+    /// Resets this [`World`] to the state right after its construction, so it
+    /// may be reused by another [`Scenario`].
+    ///
+    /// Called only if [`World`]s pooling is enabled via
+    /// [`runner::Basic::pool_worlds()`], right before the pooled [`World`] is
+    /// handed to the next [`Scenario`], instead of constructing a new one with
+    /// the [`World::new()`]. A panic inside it fails the [`Scenario`] the same
+    /// way a panic inside the [`World::new()`] does.
+    ///
+    /// Does nothing by default, so should be implemented for clearing any
+    /// [`Scenario`]-specific state once pooling is enabled.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    async fn reset(&mut self) {}
+
     #[cfg(feature = "macros")]
     /// Returns runner for tests with auto-wired steps marked by [`given`],
-    /// [`when`] and [`then`] attributes.
+    /// [`when`], [`then`] and [`any`] attributes.
     #[must_use]
     fn collection() -> step::Collection<Self>
     where
@@ -237,28 +298,50 @@ pub trait World: Sized + 'static {
 
         for given in inventory::iter::<Self::Given> {
             let (loc, regex, fun) = given.inner();
-            out = out.given(Some(loc), regex(), fun);
+            out = if given.is_wip() {
+                out.given_wip(Some(loc), regex(), fun)
+            } else {
+                out.given(Some(loc), regex(), fun)
+            };
         }
 
         for when in inventory::iter::<Self::When> {
             let (loc, regex, fun) = when.inner();
-            out = out.when(Some(loc), regex(), fun);
+            out = if when.is_wip() {
+                out.when_wip(Some(loc), regex(), fun)
+            } else {
+                out.when(Some(loc), regex(), fun)
+            };
         }
 
         for then in inventory::iter::<Self::Then> {
             let (loc, regex, fun) = then.inner();
-            out = out.then(Some(loc), regex(), fun);
+            out = if then.is_wip() {
+                out.then_wip(Some(loc), regex(), fun)
+            } else {
+                out.then(Some(loc), regex(), fun)
+            };
+        }
+
+        for any in inventory::iter::<Self::Any> {
+            let (loc, regex, fun) = any.inner();
+            out = if any.is_wip() {
+                out.any_wip(Some(loc), regex(), fun)
+            } else {
+                out.any(Some(loc), regex(), fun)
+            };
         }
 
-        out
+        out.with_plain_regexes()
     }
 
     #[cfg(feature = "macros")]
     /// Returns default [`Cucumber`] with all the auto-wired [`Step`]s.
     #[must_use]
-    fn cucumber<I: AsRef<Path>>() -> DefaultCucumber<Self, I>
+    fn cucumber<I>() -> DefaultCucumber<Self, I>
     where
         Self: Debug + WorldInventory,
+        parser::Basic: Parser<I>,
     {
         Cucumber::new().steps(Self::collection())
     }
@@ -269,10 +352,11 @@ pub trait World: Sized + 'static {
     /// [`Feature`]s sourced by [`Parser`] are fed into [`Runner`] where the
     /// later produces events handled by [`Writer`].
     ///
-    /// # Panics
+    /// # Exit code
     ///
-    /// If encountered errors while parsing [`Feature`]s or at least one
-    /// [`Step`] panicked.
+    /// If the run hasn't passed, exits the current process with the
+    /// [`writer::Outcome::exit_code()`] (encountered errors while parsing
+    /// [`Feature`]s, at least one [`Step`] panicked, etc).
     ///
     /// [`Feature`]: gherkin::Feature
     async fn run<I: AsRef<Path>>(input: I)
@@ -288,10 +372,11 @@ pub trait World: Sized + 'static {
     /// [`Feature`]s sourced by [`Parser`] are fed into [`Runner`] where the
     /// later produces events handled by [`Writer`].
     ///
-    /// # Panics
+    /// # Exit code
     ///
-    /// If encountered errors while parsing [`Feature`]s or at least one
-    /// [`Step`] panicked.
+    /// If the run hasn't passed, exits the current process with the
+    /// [`writer::Outcome::exit_code()`] (encountered errors while parsing
+    /// [`Feature`]s, at least one [`Step`] panicked, etc).
     ///
     /// [`Feature`]: gherkin::Feature
     /// [`Scenario`]: gherkin::Scenario
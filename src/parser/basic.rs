@@ -12,6 +12,8 @@
 
 use std::{
     borrow::Cow,
+    collections::BTreeSet,
+    fs, io, iter,
     path::{Path, PathBuf},
     str::FromStr,
     vec,
@@ -20,10 +22,14 @@ use std::{
 use derive_more::{Display, Error};
 use futures::stream;
 use gherkin::GherkinEnv;
-use globwalk::{GlobWalker, GlobWalkerBuilder};
+use globwalk::{DirEntry, FileType, GlobWalker, GlobWalkerBuilder};
 use itertools::Itertools as _;
+use linked_hash_map::LinkedHashMap;
 
-use crate::feature::Ext as _;
+use crate::{
+    diagnostics::debug,
+    feature::{self, Ext as _},
+};
 
 use super::{Error as ParseError, Parser};
 
@@ -47,6 +53,22 @@ pub struct Cli {
 ///
 /// As there is no async runtime-agnostic way to interact with IO, this
 /// [`Parser`] is blocking.
+///
+/// Accepts paths to `.feature` files or directories containing them. A path to
+/// a `.feature` file may be suffixed with `:line` selectors (like
+/// `features/login.feature:27:40`) to run only the [`Scenario`]s spanning the
+/// specified lines, and a path prefixed with `@` is read as a rerun file (see
+/// [`writer::Rerun`]) listing such selectors.
+///
+/// Other [`Scenario`]s of such `.feature` file are still emitted, but marked
+/// with a tag, so [`Cucumber`] and [`runner::Basic`] filter them out and count
+/// them in [`event::Cucumber::ParsingFinished`].
+///
+/// [`Cucumber`]: crate::Cucumber
+/// [`event::Cucumber::ParsingFinished`]: crate::event::Cucumber::ParsingFinished
+/// [`runner::Basic`]: crate::runner::Basic
+/// [`Scenario`]: gherkin::Scenario
+/// [`writer::Rerun`]: crate::writer::Rerun
 #[derive(Clone, Debug, Default)]
 pub struct Basic {
     /// Optional custom language of [`gherkin`] keywords.
@@ -58,86 +80,147 @@ pub struct Basic {
 impl<I: AsRef<Path>> Parser<I> for Basic {
     type Cli = Cli;
 
-    type Output =
-        stream::Iter<vec::IntoIter<Result<gherkin::Feature, ParseError>>>;
+    type Output = stream::Iter<
+        iter::Map<
+            vec::IntoIter<Result<Selected, gherkin::ParseFileError>>,
+            fn(
+                Result<Selected, gherkin::ParseFileError>,
+            ) -> Result<gherkin::Feature, ParseError>,
+        >,
+    >;
 
     fn parse(self, path: I, cli: Self::Cli) -> Self::Output {
-        let walk = |walker: GlobWalker| {
-            walker
-                .filter_map(Result::ok)
-                .sorted_by(|l, r| Ord::cmp(l.path(), r.path()))
-                .map(|file| {
-                    let env = self
-                        .language
-                        .as_ref()
-                        .and_then(|l| GherkinEnv::new(l).ok())
-                        .unwrap_or_default();
-                    gherkin::Feature::parse_path(file.path(), env)
-                })
-                .collect::<Vec<_>>()
-        };
+        self.parse_paths(iter::once(path), cli)
+    }
+}
 
-        let get_features_path = || {
-            let path = path.as_ref();
-            path.canonicalize()
-                .or_else(|_| {
-                    let mut buf = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
-                    buf.push(
-                        path.strip_prefix("/")
-                            .or_else(|_| path.strip_prefix("./"))
-                            .unwrap_or(path),
-                    );
-                    buf.as_path().canonicalize()
-                })
-                .map_err(|e| gherkin::ParseFileError::Reading {
-                    path: path.to_path_buf(),
-                    source: e,
-                })
-        };
+impl<P: AsRef<Path>> Parser<Paths<P>> for Basic {
+    type Cli = Cli;
+
+    type Output = <Self as Parser<P>>::Output;
 
+    fn parse(self, paths: Paths<P>, cli: Self::Cli) -> Self::Output {
+        self.parse_paths(paths.0, cli)
+    }
+}
+
+impl Parser<Inline> for Basic {
+    type Cli = Cli;
+
+    type Output =
+        stream::Iter<vec::IntoIter<Result<gherkin::Feature, ParseError>>>;
+
+    fn parse(self, inline: Inline, _: Self::Cli) -> Self::Output {
+        let features = inline
+            .0
+            .into_iter()
+            .map(|(path, source)| {
+                debug!("using inline `.feature` source: {}", path.display());
+                parse_source(path, &source, self.gherkin_env())
+                    .map_err(ParseError::from)
+                    .and_then(|f| {
+                        feature::expand_examples_in(f, Some(&source))
+                            .map_err(ParseError::from)
+                    })
+            })
+            .collect::<Vec<_>>();
+        stream::iter(features)
+    }
+}
+
+impl Basic {
+    /// Parses [`Feature`]s from all the provided `paths` (or the ones matching
+    /// the [`Cli::features`] glob, if it's specified), omitting duplicates.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    fn parse_paths<P: AsRef<Path>>(
+        self,
+        paths: impl IntoIterator<Item = P>,
+        cli: Cli,
+    ) -> <Self as Parser<P>>::Output {
         let features = || {
-            let features = if let Some(walker) = cli.features {
+            let files = if let Some(walker) = cli.features {
                 walk(globwalk::glob(walker.0).unwrap_or_else(|e| {
                     unreachable!("Invalid glob pattern: {e}")
                 }))
+                .into_iter()
+                .map(|f| Ok((f, None)))
+                .collect::<Vec<_>>()
             } else {
-                let feats_path = match get_features_path() {
-                    Ok(p) => p,
-                    Err(e) => return vec![Err(e.into())],
-                };
-
-                if feats_path.is_file() {
-                    let env = self
-                        .language
-                        .as_ref()
-                        .and_then(|l| GherkinEnv::new(l).ok())
-                        .unwrap_or_default();
-                    vec![gherkin::Feature::parse_path(feats_path, env)]
-                } else {
-                    let w = GlobWalkerBuilder::new(feats_path, "*.feature")
-                        .case_insensitive(true)
-                        .build()
-                        .unwrap_or_else(|e| {
-                            unreachable!("GlobWalkerBuilder panicked: {e}")
-                        });
-                    walk(w)
-                }
+                paths
+                    .into_iter()
+                    .map(|p| discover(p.as_ref()))
+                    .flat_map(|res| match res {
+                        Ok(files) => files.into_iter().map(Ok).collect(),
+                        Err(e) => vec![Err(e)],
+                    })
+                    .collect()
             };
 
-            features
+            // The same `.feature` file may be reachable via several paths, so
+            // its selected lines are merged, while no lines selected at all
+            // means the whole file.
+            let mut errors = Vec::new();
+            let mut selected = LinkedHashMap::<_, Option<BTreeSet<_>>>::new();
+            for file in files {
+                match file {
+                    Ok((path, lines)) => match selected.get_mut(&path) {
+                        Some(existing) => {
+                            if let (Some(ex), Some(l)) =
+                                (existing.as_mut(), lines)
+                            {
+                                ex.extend(l);
+                            } else {
+                                *existing = None;
+                            }
+                        }
+                        None => drop(selected.insert(path, lines)),
+                    },
+                    Err(e) => errors.push(e),
+                }
+            }
+
+            let features = errors
                 .into_iter()
-                .map(|f| match f {
-                    Ok(f) => f.expand_examples().map_err(ParseError::from),
-                    Err(e) => Err(e.into()),
-                })
-                .collect()
+                .map(Err)
+                .chain(selected.into_iter().map(|(file, lines)| {
+                    debug!("discovered `.feature` file: {}", file.display());
+                    parse_feature(file, self.gherkin_env()).map(|(f, src)| {
+                        let lines = lines.map(|l| resolve_lines(&f, &src, &l));
+                        (f, src, lines)
+                    })
+                }))
+                .collect::<Vec<_>>();
+
+            debug!("discovered {} `.feature` file(s) total", features.len());
+
+            features
         };
 
-        stream::iter(features())
+        // `Examples` are expanded lazily, only once the `Feature` is polled
+        // from the returned `Stream`, so the first `Feature`s may be run
+        // before the latter ones are expanded.
+        let expand: fn(Result<Selected, _>) -> _ = |f| match f {
+            Ok((f, source, lines)) => {
+                feature::expand_examples_in(f, Some(&source))
+                    .map(|f| select_lines(f, lines.as_ref()))
+                    .map_err(ParseError::from)
+            }
+            Err(e) => Err(ParseError::from(e)),
+        };
+        stream::iter(features().into_iter().map(expand))
+    }
+
+    /// Returns the [`GherkinEnv`] to parse [`Feature`]s with.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    fn gherkin_env(&self) -> GherkinEnv {
+        self.language
+            .as_ref()
+            .and_then(|l| GherkinEnv::new(l).ok())
+            .unwrap_or_default()
     }
-}
 
-impl Basic {
     /// Creates a new [`Basic`] [`Parser`].
     #[must_use]
     pub const fn new() -> Self {
@@ -170,6 +253,433 @@ pub struct UnsupportedLanguageError(
     #[error(not(source))] pub Cow<'static, str>,
 );
 
+/// Multiple paths to look for [`Feature`]s in, each one being either a
+/// `.feature` file or a directory.
+///
+/// [`Feature`]s reachable via several paths are parsed only once.
+///
+/// [`Feature`]: gherkin::Feature
+#[derive(Clone, Debug)]
+pub struct Paths<P = PathBuf>(pub Vec<P>);
+
+impl<P> FromIterator<P> for Paths<P> {
+    fn from_iter<T: IntoIterator<Item = P>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<P, const N: usize> From<[P; N]> for Paths<P> {
+    fn from(paths: [P; N]) -> Self {
+        Self(paths.into())
+    }
+}
+
+/// Sources of `.feature` files embedded into a test binary (with the
+/// [`include_str!`] macro, for example), so it doesn't depend on a working
+/// directory.
+///
+/// Each source is paired with a path to display in the output, which is never
+/// read.
+///
+/// ```rust
+/// # use cucumber::{parser::basic::Inline, World};
+/// #
+/// # #[derive(Debug, Default, World)]
+/// # struct MyWorld;
+/// #
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// MyWorld::cucumber()
+///     .run(Inline::new().feature(
+///         "features/animal.feature",
+///         // Usually, `include_str!("../features/animal.feature")`.
+///         "Feature: Animal feature\n\
+///            Scenario: If we feed a hungry cat it will no longer be hungry\n\
+///              Given a hungry cat",
+///     ))
+///     .await;
+/// # }
+/// ```
+///
+/// __NOTE__: [`Cli::features`] glob is not applied to the [`Inline`] sources.
+#[derive(Clone, Debug, Default)]
+pub struct Inline(pub Vec<(PathBuf, Cow<'static, str>)>);
+
+impl Inline {
+    /// Creates a new empty [`Inline`] sources.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Adds the provided `.feature` file `source`, displayed with the given
+    /// `path`.
+    #[must_use]
+    pub fn feature(
+        mut self,
+        path: impl Into<PathBuf>,
+        source: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.0.push((path.into(), source.into()));
+        self
+    }
+}
+
+impl<P, S> FromIterator<(P, S)> for Inline
+where
+    P: Into<PathBuf>,
+    S: Into<Cow<'static, str>>,
+{
+    fn from_iter<T: IntoIterator<Item = (P, S)>>(iter: T) -> Self {
+        Self(
+            iter.into_iter()
+                .map(|(p, s)| (p.into(), s.into()))
+                .collect(),
+        )
+    }
+}
+
+/// Parsed [`Feature`] along with its source and the lines of its [`Scenario`]s
+/// selected to be run, if only some of them should be.
+///
+/// [`Feature`]: gherkin::Feature
+/// [`Scenario`]: gherkin::Scenario
+pub type Selected = (gherkin::Feature, String, Option<BTreeSet<usize>>);
+
+/// Collects the paths of the files found by the given [`GlobWalker`], sorted.
+fn walk(walker: GlobWalker) -> Vec<PathBuf> {
+    walker
+        .filter_map(Result::ok)
+        .map(DirEntry::into_path)
+        .sorted()
+        .collect::<Vec<_>>()
+}
+
+/// Resolves the given `path` to look for `.feature` files in, relative to the
+/// crate manifest directory, if it doesn't exist as is.
+///
+/// # Errors
+///
+/// If the `path` doesn't exist.
+fn features_path(path: &Path) -> Result<PathBuf, gherkin::ParseFileError> {
+    path.canonicalize()
+        .or_else(|_| {
+            let mut buf = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+            buf.push(
+                path.strip_prefix("/")
+                    .or_else(|_| path.strip_prefix("./"))
+                    .unwrap_or(path),
+            );
+            buf.as_path().canonicalize()
+        })
+        .map_err(|e| gherkin::ParseFileError::Reading {
+            path: path.to_path_buf(),
+            source: e,
+        })
+}
+
+/// Discovers the `.feature` files (along with their selected lines, if any)
+/// at the given `path`, being a file, a directory, a file with a trailing
+/// `:line` selector or a `@`-prefixed rerun file.
+///
+/// # Errors
+///
+/// If the `path` doesn't exist or the rerun file cannot be read.
+fn discover(
+    path: &Path,
+) -> Result<Vec<(PathBuf, Option<BTreeSet<usize>>)>, gherkin::ParseFileError> {
+    if let Some(rerun) = path.to_str().and_then(|p| p.strip_prefix('@')) {
+        return read_rerun_file(&features_path(Path::new(rerun))?)?
+            .into_iter()
+            .map(|(file, lines)| Ok((features_path(&file)?, Some(lines))))
+            .collect();
+    }
+
+    // A trailing `:line` selects the `Scenario`s of a `.feature` file
+    // spanning that line (like `features/login.feature:27`).
+    let feats_path = match features_path(path) {
+        Ok(p) => p,
+        Err(e) => match path.to_str().map(split_lines) {
+            Some((file, lines)) if !lines.is_empty() => {
+                let file = features_path(Path::new(file))?;
+                if !file.is_file() {
+                    return Err(gherkin::ParseFileError::Reading {
+                        path: path.to_path_buf(),
+                        source: io::Error::new(
+                            io::ErrorKind::InvalidInput,
+                            "`:line` selectors require a `.feature` \
+                             file, not a directory",
+                        ),
+                    });
+                }
+                debug!(
+                    "using lines {lines:?} of `.feature` file: {}",
+                    file.display(),
+                );
+                return Ok(vec![(file, Some(lines))]);
+            }
+            _ => return Err(e),
+        },
+    };
+
+    Ok(if feats_path.is_file() {
+        debug!("using single `.feature` file: {}", feats_path.display());
+        vec![(feats_path, None)]
+    } else {
+        // Hidden directories (like `.git`) are not traversed.
+        let w = GlobWalkerBuilder::from_patterns(
+            feats_path,
+            &["*.feature", "!.*/"],
+        )
+        .case_insensitive(true)
+        .file_type(FileType::FILE)
+        .build()
+        .unwrap_or_else(|e| unreachable!("GlobWalkerBuilder panicked: {e}"));
+        walk(w).into_iter().map(|f| (f, None)).collect()
+    })
+}
+
+/// Reads the `.feature` files and [`Scenario`]s lines listed in the given
+/// rerun file (as written by [`writer::Rerun`]).
+///
+/// Entries are `path:line` selectors (several lines may be specified with
+/// `path:line:line`), one per line, so paths may contain whitespaces. Blank
+/// lines are ignored.
+///
+/// # Errors
+///
+/// If the rerun file cannot be read, or contains a malformed entry.
+///
+/// [`Scenario`]: gherkin::Scenario
+/// [`writer::Rerun`]: crate::writer::Rerun
+fn read_rerun_file(
+    path: &Path,
+) -> Result<Vec<(PathBuf, BTreeSet<usize>)>, gherkin::ParseFileError> {
+    let err = |source| gherkin::ParseFileError::Reading {
+        path: path.to_path_buf(),
+        source,
+    };
+
+    let contents = fs::read_to_string(path).map_err(err)?;
+    debug!("using rerun file: {}", path.display());
+
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| match split_lines(entry) {
+            (file, lines) if !lines.is_empty() => Ok((file.into(), lines)),
+            _ => Err(err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("expected `path:line` entry, found `{entry}`"),
+            ))),
+        })
+        .collect()
+}
+
+/// Parses the [`Feature`] out of the `.feature` file at the given `path`,
+/// ignoring its leading UTF-8 BOM (byte order mark), if any, and returns it
+/// along with the file contents.
+///
+/// # Errors
+///
+/// If the `.feature` file cannot be read or parsed.
+///
+/// [`Feature`]: gherkin::Feature
+fn parse_feature(
+    path: PathBuf,
+    env: GherkinEnv,
+) -> Result<(gherkin::Feature, String), gherkin::ParseFileError> {
+    let contents = fs::read_to_string(&path).map_err(|source| {
+        gherkin::ParseFileError::Reading {
+            path: path.clone(),
+            source,
+        }
+    })?;
+
+    // `gherkin` doesn't recognize a BOM, so the file is parsed from a string
+    // then, while the one without it is parsed "as is" to keep the most
+    // detailed parsing errors.
+    let feature = if contents.starts_with('\u{feff}') {
+        parse_source(path, &contents, env)
+    } else {
+        gherkin::Feature::parse_path(path, env)
+    }?;
+    Ok((feature, contents))
+}
+
+/// Parses the [`Feature`] out of the given `.feature` file `source`, ignoring
+/// its leading UTF-8 BOM (byte order mark), if any, and attributing it to the
+/// provided `path`.
+///
+/// # Errors
+///
+/// If the `source` cannot be parsed.
+///
+/// [`Feature`]: gherkin::Feature
+fn parse_source(
+    path: PathBuf,
+    source: &str,
+    env: GherkinEnv,
+) -> Result<gherkin::Feature, gherkin::ParseFileError> {
+    let source = source.strip_prefix('\u{feff}').unwrap_or(source);
+    gherkin::Feature::parse(source, env)
+        .map(|f| gherkin::Feature {
+            path: Some(path.clone()),
+            ..f
+        })
+        .map_err(|source| gherkin::ParseFileError::Parsing {
+            path,
+            error: None,
+            source,
+        })
+}
+
+/// Splits trailing `:line` selectors (possibly multiple, like
+/// `path:12:34`) off the given `path`.
+fn split_lines(mut path: &str) -> (&str, BTreeSet<usize>) {
+    let mut lines = BTreeSet::new();
+    while let Some((rest, line)) = path
+        .rsplit_once(':')
+        .and_then(|(rest, l)| Some((rest, l.parse().ok()?)))
+    {
+        _ = lines.insert(line);
+        path = rest;
+    }
+    (path, lines)
+}
+
+/// Resolves the given selected `lines` of a [`Feature`] parsed from the
+/// provided `source` into the exact lines of its [`Scenario`]s and
+/// [`Examples`] rows to run.
+///
+/// A [`Scenario`] is selected if any of the `lines` falls into its body (up to
+/// the next [`Scenario`], [`Rule`] or [`Background`]). A line of an
+/// [`Examples`] table row selects only that row of a [`Scenario Outline`],
+/// while any other line of it selects all its rows.
+///
+/// [`Background`]: gherkin::Background
+/// [`Examples`]: gherkin::Examples
+/// [`Feature`]: gherkin::Feature
+/// [`Rule`]: gherkin::Rule
+/// [`Scenario`]: gherkin::Scenario
+/// [`Scenario Outline`]: gherkin::Scenario
+fn resolve_lines(
+    feature: &gherkin::Feature,
+    source: &str,
+    lines: &BTreeSet<usize>,
+) -> BTreeSet<usize> {
+    let scenarios = || {
+        feature
+            .scenarios
+            .iter()
+            .chain(feature.rules.iter().flat_map(|r| &r.scenarios))
+    };
+    let boundaries = scenarios()
+        .map(|sc| sc.position.line)
+        .chain(feature.rules.iter().map(|r| r.position.line))
+        .chain(
+            feature
+                .background
+                .iter()
+                .chain(
+                    feature.rules.iter().filter_map(|r| r.background.as_ref()),
+                )
+                .map(|bg| bg.position.line),
+        )
+        .collect::<BTreeSet<_>>();
+
+    scenarios()
+        .flat_map(|sc| {
+            let start = sc.position.line;
+            let end = boundaries
+                .range(start + 1..)
+                .next()
+                .copied()
+                .unwrap_or(usize::MAX);
+            let mut hits = lines.range(start..end).peekable();
+            if hits.peek().is_none() {
+                return vec![];
+            }
+
+            let rows = sc
+                .examples
+                .iter()
+                .filter_map(|ex| ex.table.as_ref())
+                .flat_map(|t| feature::rows_lines(t, Some(source)))
+                .collect::<Vec<_>>();
+            if rows.is_empty() {
+                vec![start]
+            } else if hits.any(|l| !rows.contains(l)) {
+                rows
+            } else {
+                rows.into_iter().filter(|r| lines.contains(r)).collect()
+            }
+        })
+        .collect()
+}
+
+/// Tag marking [`Scenario`]s not selected by `:line` selectors.
+///
+/// Contains whitespace, so cannot be specified in a `.feature` file.
+///
+/// [`Scenario`]: gherkin::Scenario
+const DESELECTED_TAG: &str = "cucumber: not selected by line";
+
+/// Marks [`Scenario`]s of the given [`Feature`] not defined at the provided
+/// `lines` (if any) as deselected, so they're filtered out by
+/// [`take_deselected()`] before being run.
+///
+/// An expanded [`Scenario Outline`] is considered to be defined at the line of
+/// its [`Examples`] row.
+///
+/// [`Examples`]: gherkin::Examples
+/// [`Feature`]: gherkin::Feature
+/// [`Scenario`]: gherkin::Scenario
+/// [`Scenario Outline`]: gherkin::Scenario
+fn select_lines(
+    mut feature: gherkin::Feature,
+    lines: Option<&BTreeSet<usize>>,
+) -> gherkin::Feature {
+    if let Some(lines) = lines {
+        let path = feature.path.clone().unwrap_or_default();
+        let deselect = |sc: &mut gherkin::Scenario| {
+            let line = feature::source_line(sc);
+            if !lines.contains(&line) {
+                debug!(
+                    "filtered out `Scenario` \"{}\" at {}:{line} by line \
+                     selector",
+                    sc.name,
+                    path.display(),
+                );
+                sc.tags.push(DESELECTED_TAG.to_owned());
+            }
+        };
+        feature.scenarios.iter_mut().for_each(&deselect);
+        for r in &mut feature.rules {
+            r.scenarios.iter_mut().for_each(&deselect);
+        }
+    }
+    feature
+}
+
+/// Removes [`Scenario`]s of the given [`Feature`] not selected by `:line`
+/// selectors, returning the number of the removed ones.
+///
+/// [`Feature`]: gherkin::Feature
+/// [`Scenario`]: gherkin::Scenario
+pub(crate) fn take_deselected(feature: &mut gherkin::Feature) -> usize {
+    let is_selected =
+        |sc: &gherkin::Scenario| !sc.tags.iter().any(|t| t == DESELECTED_TAG);
+
+    let total = feature.count_scenarios();
+    feature.scenarios.retain(is_selected);
+    for r in &mut feature.rules {
+        r.scenarios.retain(is_selected);
+    }
+    total - feature.count_scenarios()
+}
+
 /// Wrapper over [`GlobWalker`] implementing a [`FromStr`].
 #[derive(Clone, Debug)]
 pub struct Walker(String);
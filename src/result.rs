@@ -0,0 +1,598 @@
+// Copyright (c) 2018-2023  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Owned aggregated results of executed [`Scenario`]s and [`Feature`]s.
+//!
+//! [`ScenarioResult`]s and [`FeatureResult`]s are built by a [`Runner`] and
+//! passed along with the [`event::Scenario::Finished`] and
+//! [`event::Feature::Finished`] events, so custom [`Writer`]s don't need to
+//! rebuild such aggregates on their own.
+//!
+//! [`Feature`]: gherkin::Feature
+//! [`Runner`]: crate::Runner
+//! [`Scenario`]: gherkin::Scenario
+//! [`Writer`]: crate::Writer
+
+use std::{collections::HashMap, path::PathBuf, sync::Arc};
+
+#[cfg(feature = "timestamps")]
+use std::time::{Duration, SystemTime};
+
+use derive_more::Display;
+
+use crate::{
+    event::{self, HookType, Retries},
+    writer::basic::coerce_error,
+    Event,
+};
+
+/// Status of an executed [`Step`] or [`Scenario`].
+///
+/// [`Scenario`]: gherkin::Scenario
+/// [`Step`]: gherkin::Step
+#[derive(Clone, Copy, Debug, Display, Eq, Hash, PartialEq)]
+pub enum Status {
+    /// Execution has passed.
+    #[display(fmt = "passed")]
+    Passed,
+
+    /// Execution has been skipped.
+    #[display(fmt = "skipped")]
+    Skipped,
+
+    /// Execution hasn't happened, because of a work-in-progress definition.
+    #[display(fmt = "pending")]
+    Pending,
+
+    /// Execution has failed.
+    #[display(fmt = "failed")]
+    Failed,
+}
+
+/// Result of an executed [`Step`].
+///
+/// [`Step`]: gherkin::Step
+#[derive(Clone, Debug)]
+pub struct StepResult {
+    /// [`gherkin::Step::keyword`].
+    pub keyword: String,
+
+    /// [`gherkin::Step::value`].
+    pub value: String,
+
+    /// Line of the [`Step`] in its `.feature` file.
+    ///
+    /// [`Step`]: gherkin::Step
+    pub line: usize,
+
+    /// Column of the [`Step`] in its `.feature` file.
+    ///
+    /// [`Step`]: gherkin::Step
+    pub col: usize,
+
+    /// [`gherkin::Step::docstring`].
+    pub docstring: Option<String>,
+
+    /// [`gherkin::Step::table`].
+    pub table: Option<gherkin::Table>,
+
+    /// Indicator whether the [`Step`] is a [`Background`] one.
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Step`]: gherkin::Step
+    pub is_background: bool,
+
+    /// [`Status`] of the [`Step`].
+    ///
+    /// [`Step`]: gherkin::Step
+    pub status: Status,
+
+    /// Error message of the [`Step`], if it has failed.
+    ///
+    /// [`Step`]: gherkin::Step
+    pub error: Option<String>,
+
+    /// Reason of the [`Step`] being skipped, if it's skipped not because of
+    /// being undefined.
+    ///
+    /// [`Step`]: gherkin::Step
+    pub skip_reason: Option<String>,
+
+    /// Duration of the [`Step`] execution.
+    ///
+    /// [`Step`]: gherkin::Step
+    #[cfg(feature = "timestamps")]
+    pub duration: Duration,
+}
+
+impl StepResult {
+    /// Creates a new [`StepResult`] of the given [`Step`] with the provided
+    /// [`Status`], but without any error.
+    ///
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn new(
+        step: &gherkin::Step,
+        is_background: bool,
+        status: Status,
+    ) -> Self {
+        Self {
+            keyword: step.keyword.clone(),
+            value: step.value.clone(),
+            line: step.position.line,
+            col: step.position.col,
+            docstring: step.docstring.clone(),
+            table: step.table.clone(),
+            is_background,
+            status,
+            error: None,
+            skip_reason: None,
+            #[cfg(feature = "timestamps")]
+            duration: Duration::ZERO,
+        }
+    }
+}
+
+/// Result of an executed [`Scenario`].
+///
+/// In case the [`Scenario`] is retried, each of its attempts has its own
+/// [`ScenarioResult`].
+///
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Clone, Debug)]
+pub struct ScenarioResult {
+    /// [`gherkin::Scenario::keyword`].
+    pub keyword: String,
+
+    /// [`gherkin::Scenario::name`].
+    pub name: String,
+
+    /// [`gherkin::Rule::name`], if the [`Scenario`] belongs to a [`Rule`].
+    ///
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    pub rule: Option<String>,
+
+    /// Path to the `.feature` file of the [`Scenario`], if present.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub path: Option<PathBuf>,
+
+    /// Line of the [`Scenario`] in its `.feature` file.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub line: usize,
+
+    /// Column of the [`Scenario`] in its `.feature` file.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub col: usize,
+
+    /// Tags of the [`Scenario`], including the inherited [`Feature`] and
+    /// [`Rule`] ones.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    pub tags: Vec<String>,
+
+    /// [`StepResult`]s of the [`Scenario`], in their execution order.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub steps: Vec<StepResult>,
+
+    /// Error messages of the failed hooks of the [`Scenario`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub hook_errors: Vec<(HookType, String)>,
+
+    /// [`Retries`] of the [`Scenario`], if it's retried.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub retries: Option<Retries>,
+
+    /// Indicator whether this [`ScenarioResult`] is going to be superseded by
+    /// the next retry attempt of the same [`Scenario`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub is_retried: bool,
+
+    /// Duration of the [`Scenario`] execution.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[cfg(feature = "timestamps")]
+    pub duration: Duration,
+}
+
+impl ScenarioResult {
+    /// Creates a new empty [`ScenarioResult`] of the given [`Scenario`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn new(
+        feature: &gherkin::Feature,
+        rule: Option<&gherkin::Rule>,
+        scenario: &gherkin::Scenario,
+        retries: Option<Retries>,
+    ) -> Self {
+        Self {
+            keyword: scenario.keyword.clone(),
+            name: scenario.name.clone(),
+            rule: rule.map(|r| r.name.clone()),
+            path: feature.path.clone(),
+            line: scenario.position.line,
+            col: scenario.position.col,
+            // The order `Feature` -> `Rule` -> `Scenario` matters here.
+            tags: feature
+                .tags
+                .iter()
+                .chain(rule.iter().flat_map(|r| &r.tags))
+                .chain(&scenario.tags)
+                .cloned()
+                .collect(),
+            steps: Vec::new(),
+            hook_errors: Vec::new(),
+            retries,
+            is_retried: false,
+            #[cfg(feature = "timestamps")]
+            duration: Duration::ZERO,
+        }
+    }
+
+    /// Returns [`Status`] of the [`Scenario`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn status(&self) -> Status {
+        if !self.hook_errors.is_empty()
+            || self.steps.iter().any(|s| s.status == Status::Failed)
+        {
+            Status::Failed
+        } else if self.steps.iter().any(|s| s.status == Status::Pending) {
+            Status::Pending
+        } else if self.steps.iter().any(|s| s.status == Status::Skipped) {
+            Status::Skipped
+        } else {
+            Status::Passed
+        }
+    }
+
+    /// Indicates whether the [`Scenario`] is flaky, having passed only after
+    /// being retried.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn is_flaky(&self) -> bool {
+        !self.is_retried
+            && self.retries.is_some_and(|r| r.current > 0)
+            && self.status() == Status::Passed
+    }
+}
+
+/// Result of an executed [`Feature`].
+///
+/// [`Feature`]: gherkin::Feature
+#[derive(Clone, Debug)]
+pub struct FeatureResult {
+    /// [`gherkin::Feature::name`].
+    pub name: String,
+
+    /// Path to the `.feature` file, if present.
+    pub path: Option<PathBuf>,
+
+    /// [`ScenarioResult`]s of the [`Feature`] (including its [`Rule`]s), in
+    /// their finishing order.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    pub scenarios: Vec<ScenarioResult>,
+
+    /// Duration of the [`Feature`] execution.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    #[cfg(feature = "timestamps")]
+    pub duration: Duration,
+}
+
+impl FeatureResult {
+    /// Creates a new empty [`FeatureResult`] of the given [`Feature`].
+    ///
+    /// [`Feature`]: gherkin::Feature
+    #[must_use]
+    pub fn new(feature: &gherkin::Feature) -> Self {
+        Self {
+            name: feature.name.clone(),
+            path: feature.path.clone(),
+            scenarios: Vec::new(),
+            #[cfg(feature = "timestamps")]
+            duration: Duration::ZERO,
+        }
+    }
+
+    /// Returns number of [`Scenario`]s having the given [`Status`], not
+    /// counting the retried attempts.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn count(&self, status: Status) -> usize {
+        self.scenarios
+            .iter()
+            .filter(|sc| !sc.is_retried && sc.status() == status)
+            .count()
+    }
+
+    /// Returns number of passed [`Scenario`]s.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn passed(&self) -> usize {
+        self.count(Status::Passed)
+    }
+
+    /// Returns number of skipped [`Scenario`]s.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn skipped(&self) -> usize {
+        self.count(Status::Skipped)
+    }
+
+    /// Returns number of pending [`Scenario`]s.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn pending(&self) -> usize {
+        self.count(Status::Pending)
+    }
+
+    /// Returns number of failed [`Scenario`]s.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn failed(&self) -> usize {
+        self.count(Status::Failed)
+    }
+}
+
+/// Key identifying a [`Scenario`] inside a [`Collector`].
+///
+/// [`Scenario`]: gherkin::Scenario
+type ScenarioKey = (
+    Arc<gherkin::Feature>,
+    Option<Arc<gherkin::Rule>>,
+    Arc<gherkin::Scenario>,
+);
+
+/// Folder of [`event`]s into [`ScenarioResult`]s and [`FeatureResult`]s.
+///
+/// Tracks every [`Scenario`] and [`Feature`] separately, so is fed with the
+/// [`event`]s in the order a [`Runner`] emits them.
+///
+/// [`Feature`]: gherkin::Feature
+/// [`Runner`]: crate::Runner
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Collector {
+    /// [`FeatureResult`]s of the currently executed [`Feature`]s.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    features: HashMap<Arc<gherkin::Feature>, FeatureResult>,
+
+    /// [`ScenarioResult`]s of the currently executed [`Scenario`]s.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    scenarios: HashMap<ScenarioKey, ScenarioResult>,
+
+    /// [`SystemTime`]s when the currently executed [`Feature`]s, [`Scenario`]s
+    /// and [`Step`]s have started.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[cfg(feature = "timestamps")]
+    started: HashMap<(ScenarioKey, Option<Arc<gherkin::Step>>), SystemTime>,
+
+    /// [`SystemTime`]s when the currently executed [`Feature`]s have started.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    #[cfg(feature = "timestamps")]
+    features_started: HashMap<Arc<gherkin::Feature>, SystemTime>,
+}
+
+impl Collector {
+    /// Creates a new empty [`Collector`].
+    #[must_use]
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Folds the given [`event::Cucumber`] into this [`Collector`].
+    ///
+    /// `Finished` [`event`]s are not folded, as they're formed out of the
+    /// [`Collector::finish_scenario()`] and [`Collector::finish_feature()`]
+    /// results.
+    pub(crate) fn handle_event<W>(&mut self, ev: &Event<event::Cucumber<W>>) {
+        use event::{Cucumber, Feature, Rule};
+
+        match &ev.value {
+            Cucumber::Started
+            | Cucumber::ParsingFinished { .. }
+            | Cucumber::DuplicateSteps(_)
+            | Cucumber::UnusedSteps(_)
+            | Cucumber::Aborted
+            | Cucumber::Finished
+            | Cucumber::Feature(
+                _,
+                Feature::Finished(_)
+                | Feature::Rule(_, Rule::Started | Rule::Finished),
+            ) => {}
+            Cucumber::Feature(f, Feature::Started) => {
+                drop(
+                    self.features.insert(Arc::clone(f), FeatureResult::new(f)),
+                );
+                #[cfg(feature = "timestamps")]
+                {
+                    _ = self.features_started.insert(Arc::clone(f), ev.at);
+                }
+            }
+            Cucumber::Feature(f, Feature::Rule(r, Rule::Scenario(sc, ev_))) => {
+                let key = (Arc::clone(f), Some(Arc::clone(r)), Arc::clone(sc));
+                self.handle_scenario(
+                    key,
+                    ev_,
+                    #[cfg(feature = "timestamps")]
+                    ev.at,
+                );
+            }
+            Cucumber::Feature(f, Feature::Scenario(sc, ev_)) => {
+                let key = (Arc::clone(f), None, Arc::clone(sc));
+                self.handle_scenario(
+                    key,
+                    ev_,
+                    #[cfg(feature = "timestamps")]
+                    ev.at,
+                );
+            }
+        }
+    }
+
+    /// Forms the [`ScenarioResult`] of the finished [`Scenario`] out of the
+    /// folded [`event`]s.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub(crate) fn finish_scenario(
+        &mut self,
+        feature: &Arc<gherkin::Feature>,
+        rule: Option<&Arc<gherkin::Rule>>,
+        scenario: &Arc<gherkin::Scenario>,
+        retries: Option<Retries>,
+    ) -> ScenarioResult {
+        let key = (Arc::clone(feature), rule.cloned(), Arc::clone(scenario));
+        #[cfg(feature = "timestamps")]
+        let started = self.started.remove(&(key.clone(), None));
+        #[allow(unused_mut)] // with `timestamps` feature only
+        let mut res = self.scenarios.remove(&key).unwrap_or_else(|| {
+            ScenarioResult::new(
+                feature,
+                rule.map(AsRef::as_ref),
+                scenario,
+                retries,
+            )
+        });
+        #[cfg(feature = "timestamps")]
+        if let Some(started) = started {
+            res.duration = started.elapsed().unwrap_or_default();
+        }
+        if let Some(feature) = self.features.get_mut(feature) {
+            feature.scenarios.push(res.clone());
+        }
+        res
+    }
+
+    /// Forms the [`FeatureResult`] of the finished [`Feature`] out of the
+    /// folded [`event`]s.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    pub(crate) fn finish_feature(
+        &mut self,
+        feature: &Arc<gherkin::Feature>,
+    ) -> FeatureResult {
+        #[allow(unused_mut)] // with `timestamps` feature only
+        let mut res = self
+            .features
+            .remove(feature)
+            .unwrap_or_else(|| FeatureResult::new(feature));
+        #[cfg(feature = "timestamps")]
+        if let Some(started) = self.features_started.remove(feature) {
+            res.duration = started.elapsed().unwrap_or_default();
+        }
+        res
+    }
+
+    /// Folds the given [`event::RetryableScenario`] into this [`Collector`].
+    fn handle_scenario<W>(
+        &mut self,
+        key: ScenarioKey,
+        ev: &event::RetryableScenario<W>,
+        #[cfg(feature = "timestamps")] at: SystemTime,
+    ) {
+        use event::{Hook, Scenario, Step};
+
+        let will_retry = ev.retries.is_some_and(|r| r.left > 0);
+
+        match &ev.event {
+            Scenario::Started => {
+                let res = ScenarioResult::new(
+                    &key.0,
+                    key.1.as_deref(),
+                    &key.2,
+                    ev.retries,
+                );
+                #[cfg(feature = "timestamps")]
+                {
+                    _ = self.started.insert((key.clone(), None), at);
+                }
+                drop(self.scenarios.insert(key, res));
+            }
+            Scenario::Log(_)
+            | Scenario::Hook(_, Hook::Started | Hook::Passed)
+            | Scenario::Finished(_) => {}
+            Scenario::Hook(ty, Hook::Failed(_, info)) => {
+                if let Some(res) = self.scenarios.get_mut(&key) {
+                    res.hook_errors.push((*ty, coerce_error(info).into()));
+                    res.is_retried |= will_retry;
+                }
+            }
+            Scenario::Background(st, step_ev) | Scenario::Step(st, step_ev) => {
+                let is_background =
+                    matches!(ev.event, Scenario::Background(..));
+                let (status, error, skip_reason) = match step_ev {
+                    Step::Started => {
+                        #[cfg(feature = "timestamps")]
+                        {
+                            _ = self
+                                .started
+                                .insert((key, Some(Arc::clone(st))), at);
+                        }
+                        return;
+                    }
+                    Step::Passed(..) => (Status::Passed, None, None),
+                    Step::Skipped => (Status::Skipped, None, None),
+                    Step::SkippedWithReason(_, reason) => {
+                        (Status::Skipped, None, Some(reason.clone()))
+                    }
+                    Step::Pending(_) => (Status::Pending, None, None),
+                    Step::Failed(_, _, _, err) => {
+                        if let Some(res) = self.scenarios.get_mut(&key) {
+                            res.is_retried |= will_retry
+                                && !matches!(err, event::StepError::NotFound);
+                        }
+                        (Status::Failed, Some(err.to_string()), None)
+                    }
+                };
+                #[cfg(feature = "timestamps")]
+                let duration = self
+                    .started
+                    .remove(&(key.clone(), Some(Arc::clone(st))))
+                    .and_then(|started| at.duration_since(started).ok())
+                    .unwrap_or_default();
+                if let Some(res) = self.scenarios.get_mut(&key) {
+                    res.steps.push(StepResult {
+                        error,
+                        skip_reason,
+                        #[cfg(feature = "timestamps")]
+                        duration,
+                        ..StepResult::new(st, is_background, status)
+                    });
+                }
+            }
+        }
+    }
+}
@@ -11,19 +11,30 @@
 //! Default [`Runner`] implementation.
 
 use std::{
+    any::Any,
+    backtrace::{Backtrace, BacktraceStatus},
+    cell::{Cell, RefCell},
     cmp,
-    collections::HashMap,
-    fmt, iter, mem,
+    collections::{hash_map::RandomState, HashMap, HashSet},
+    fmt,
+    hash::{BuildHasher as _, Hasher as _},
+    iter, mem,
     ops::ControlFlow,
     panic::{self, AssertUnwindSafe},
+    rc::Rc,
+    str::FromStr,
     sync::{
-        atomic::{AtomicBool, AtomicU64, Ordering},
+        atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+        mpsc::{self as std_mpsc, RecvTimeoutError},
         Arc,
     },
-    thread,
+    task, thread,
     time::{Duration, Instant},
 };
 
+#[cfg(feature = "ctrlc")]
+use std::process;
+
 #[cfg(feature = "tracing")]
 use crossbeam_utils::atomic::AtomicCell;
 use derive_more::{Display, FromStr};
@@ -34,22 +45,27 @@ use futures::{
     lock::Mutex,
     pin_mut,
     stream::{self, LocalBoxStream},
-    FutureExt as _, Stream, StreamExt as _, TryFutureExt as _,
+    Future, FutureExt as _, Stream, StreamExt as _, TryFutureExt as _,
     TryStreamExt as _,
 };
-use gherkin::tagexpr::TagOperation;
+use gherkin::{tagexpr::TagOperation, StepType};
 use itertools::Itertools as _;
+#[cfg(feature = "ctrlc")]
+use once_cell::sync::Lazy;
 use regex::{CaptureLocations, Regex};
 
 #[cfg(feature = "tracing")]
 use crate::tracing::{Collector as TracingCollector, SpanCloseWaiter};
+#[cfg(feature = "ctrlc")]
+use crate::writer::Outcome;
 use crate::{
     event::{self, HookType, Info, Retries},
     feature::Ext as _,
     future::{select_with_biased_first, FutureExt as _},
-    parser, step,
+    parser, result,
+    step::{self, HashableRegex, Resolution},
     tag::Ext as _,
-    Event, Runner, Step, World,
+    Event, Runner, World,
 };
 
 /// CLI options of a [`Basic`] [`Runner`].
@@ -90,6 +106,116 @@ pub struct Cli {
     /// Tag expression to filter retried scenarios.
     #[arg(long, value_name = "tagexpr", global = true)]
     pub retry_tag_filter: Option<TagOperation>,
+
+    /// Maximum duration of a single step, after which it fails.
+    ///
+    /// Only steps yielding to the async runtime can be interrupted, while
+    /// blocking ones are failed once they return.
+    /// Duration is represented in a human-readable format like `1min30s`.
+    #[arg(
+        long,
+        value_name = "duration",
+        value_parser = humantime::parse_duration,
+        global = true,
+    )]
+    pub step_timeout: Option<Duration>,
+
+    /// Resolve steps of all scenarios to their definitions without executing
+    /// them, failing on undefined and ambiguous ones.
+    #[arg(long, global = true)]
+    pub dry_run: bool,
+
+    /// Order to run scenarios in.
+    ///
+    /// `defined` runs them in order they're defined in `.feature` files
+    /// (sorted by their paths), while `random` shuffles them with the given
+    /// seed (or a random one, which is printed in the summary to reproduce
+    /// the order).
+    #[arg(long, value_name = "defined|random[:seed]", global = true)]
+    pub order: Option<Order>,
+
+    /// Number of times to run all scenarios, to detect flaky ones.
+    ///
+    /// With `--fail-fast` the remaining repetitions are skipped on the first
+    /// failure.
+    #[arg(long, value_name = "int", global = true)]
+    pub repeat: Option<usize>,
+
+    /// Report step definitions not matched by any step once all scenarios
+    /// are finished.
+    ///
+    /// May be combined with `--dry-run` to find them quickly.
+    #[arg(long, global = true)]
+    pub report_unused_steps: bool,
+}
+
+/// Order to run [`Scenario`]s in.
+///
+/// Concurrently run [`Scenario`]s are only started in this order, so consider
+/// running them with `--concurrency=1` to reproduce an order-dependent failure.
+///
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Order {
+    /// Running [`Scenario`]s in order they're defined in `.feature` files
+    /// (sorted by their paths).
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[default]
+    Defined,
+
+    /// Running [`Scenario`]s shuffled with the given seed, or a random one, if
+    /// it's [`None`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    Random(Option<u64>),
+}
+
+impl FromStr for Order {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.to_ascii_lowercase();
+        match s.split_once(':') {
+            None if s == "defined" => Some(Self::Defined),
+            None if s == "random" => Some(Self::Random(None)),
+            Some(("random", seed)) => {
+                seed.parse().ok().map(Some).map(Self::Random)
+            }
+            None | Some(_) => None,
+        }
+        .ok_or("possible options: defined, random, random:<seed>")
+    }
+}
+
+/// Deterministic shuffler of [`Scenario`]s in the [`Order::Random`].
+///
+/// Implements the [SplitMix64] generator, so the same seed always produces the
+/// same order, regardless of the platform or dependencies' versions.
+///
+/// [`Scenario`]: gherkin::Scenario
+/// [SplitMix64]: https://prng.di.unimi.it/splitmix64.c
+#[derive(Clone, Copy, Debug)]
+struct Shuffler(u64);
+
+impl Shuffler {
+    /// Returns the next pseudo-random number.
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Shuffles the given `items` in place (with the Fisher-Yates algorithm).
+    fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let bound = u64::try_from(i + 1).unwrap_or(u64::MAX);
+            let j = usize::try_from(self.next() % bound).unwrap_or(i);
+            items.swap(i, j);
+        }
+    }
 }
 
 /// Type determining whether [`Scenario`]s should run concurrently or
@@ -109,6 +235,23 @@ pub enum ScenarioType {
     Concurrent,
 }
 
+/// Type determining whether [`Step`]s should be executed or only resolved to
+/// their definitions.
+///
+/// [`Step`]: gherkin::Step
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Execution {
+    /// Execute [`Step`]s.
+    ///
+    /// [`Step`]: gherkin::Step
+    Run,
+
+    /// Only resolve [`Step`]s to their definitions, without executing them.
+    ///
+    /// [`Step`]: gherkin::Step
+    DryRun,
+}
+
 /// Options for retrying [`Scenario`]s.
 ///
 /// [`Scenario`]: gherkin::Scenario
@@ -303,6 +446,282 @@ pub type AfterHookFn<World> = for<'a> fn(
     Option<&'a mut World>,
 ) -> LocalBoxFuture<'a, ()>;
 
+/// Alias for [`FnOnce`] executed once after all [`Scenario`]s are finished.
+///
+/// [`Scenario`]: gherkin::Scenario
+pub type AfterAllFn = Box<dyn FnOnce()>;
+
+/// Handle to request a cooperative stop of a [`Basic`] [`Runner`] execution
+/// with (on receiving `SIGINT`, for example).
+///
+/// Once a stop is requested, no new [`Scenario`]s are started, while the
+/// already running ones finish their current [`Step`], skip the remaining ones
+/// (as [`event::Step::SkippedWithReason`], so they're not reported as
+/// undefined) and execute their after hooks. This way all the [`Writer`]s are
+/// still able to output their summaries and reports.
+///
+/// With the `ctrlc` feature enabled, `StopHandle::ctrl_c()` returns the one
+/// requested on each `SIGINT` (see also `Cucumber::handle_ctrl_c()`).
+/// Otherwise, it's up to the caller to invoke [`StopHandle::request()`] from a
+/// signal handler (using the [`ctrlc`][1] crate, for example):
+/// ```rust
+/// # use std::process;
+/// #
+/// # use cucumber::{
+/// #     runner::basic::StopHandle, writer::Outcome, StatsWriter as _, World,
+/// # };
+/// #
+/// # #[derive(Debug, Default, World)]
+/// # struct MyWorld;
+/// #
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let stop = StopHandle::default();
+/// let on_ctrlc = {
+///     let stop = stop.clone();
+///     move || {
+///         // Second Ctrl-C forces an immediate exit.
+///         if stop.request() > 0 {
+///             process::exit(Outcome::Aborted.exit_code().into());
+///         }
+///     }
+/// };
+/// # drop(on_ctrlc);
+/// // ctrlc::set_handler(on_ctrlc).unwrap();
+///
+/// let writer = MyWorld::cucumber()
+///     .stop_handle(stop)
+///     .run("tests/features/readme")
+///     .await;
+///
+/// if writer.execution_aborted() {
+///     process::exit(Outcome::Aborted.exit_code().into());
+/// }
+/// # }
+/// ```
+///
+/// [1]: https://docs.rs/ctrlc
+/// [`Scenario`]: gherkin::Scenario
+/// [`Step`]: gherkin::Step
+/// [`Writer`]: crate::Writer
+#[derive(Clone, Debug, Default)]
+pub struct StopHandle(Arc<AtomicUsize>);
+
+impl StopHandle {
+    /// Requests a stop of execution, returning the number of the previous
+    /// requests (so a repeated one may be treated as a forced exit).
+    #[allow(clippy::must_use_candidate)] // requesting is the main effect
+    pub fn request(&self) -> usize {
+        self.0.fetch_add(1, Ordering::SeqCst)
+    }
+
+    /// Indicates whether a stop of execution has been requested.
+    #[must_use]
+    pub fn is_requested(&self) -> bool {
+        self.0.load(Ordering::SeqCst) > 0
+    }
+
+    /// Returns the process-wide [`StopHandle`] requested on each `SIGINT`
+    /// (Ctrl-C), installing its handler on the first call.
+    ///
+    /// A repeated `SIGINT` exits the process immediately with the
+    /// [`Outcome::Aborted`] exit code, without waiting for the running
+    /// [`Scenario`]s to finish.
+    ///
+    /// # Panics
+    ///
+    /// If another `SIGINT` handler has been already installed via the
+    /// [`ctrlc`][1] crate.
+    ///
+    /// [1]: https://docs.rs/ctrlc
+    /// [`Outcome::Aborted`]: crate::writer::Outcome::Aborted
+    /// [`Scenario`]: gherkin::Scenario
+    #[allow(clippy::exit)] // forced exit on a repeated `SIGINT` is the point
+    #[cfg(feature = "ctrlc")]
+    #[must_use]
+    pub fn ctrl_c() -> Self {
+        static HANDLE: Lazy<StopHandle> = Lazy::new(|| {
+            let handle = StopHandle::default();
+            let on_sigint = handle.clone();
+            ctrlc::set_handler(move || {
+                if on_sigint.request() > 0 {
+                    process::exit(Outcome::Aborted.exit_code().into());
+                }
+            })
+            .unwrap_or_else(|e| panic!("Failed to set `SIGINT` handler: {e}"));
+            handle
+        });
+
+        HANDLE.clone()
+    }
+}
+
+/// Options for pooling [`World`]s between [`Scenario`]s.
+///
+/// Once a [`Scenario`] is finished, its [`World`] is put into the pool, and
+/// the next [`Scenario`] takes it from there, calling [`World::reset()`]
+/// instead of constructing a new one with the [`World::new()`]. New [`World`]s
+/// are constructed only when the pool is empty, so their number never exceeds
+/// the number of concurrently running [`Scenario`]s.
+///
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PoolOptions {
+    /// Maximum number of idle [`World`]s kept in the pool, with the excessive
+    /// ones being dropped.
+    ///
+    /// If [`None`], then bounded by the number of concurrently executed
+    /// [`Scenario`]s only.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub size: Option<usize>,
+
+    /// Indicates whether [`World`]s of failed [`Scenario`]s should be dropped
+    /// instead of being returned to the pool, as their invariants may be
+    /// broken.
+    ///
+    /// __NOTE__: [`World`]s returned to the pool are not attached to the
+    ///           failure [`event`]s, so [`Writer`]s don't output them.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Writer`]: crate::Writer
+    pub discard_failed: bool,
+}
+
+impl Default for PoolOptions {
+    fn default() -> Self {
+        Self {
+            size: None,
+            discard_failed: true,
+        }
+    }
+}
+
+/// Pool of idle [`World`]s, reused by [`Scenario`]s.
+///
+/// [`Scenario`]: gherkin::Scenario
+struct WorldPool<W> {
+    /// Idle [`World`]s.
+    worlds: RefCell<Vec<W>>,
+
+    /// [`PoolOptions`] of this [`WorldPool`].
+    options: PoolOptions,
+}
+
+impl<W> WorldPool<W> {
+    /// Creates a new empty [`WorldPool`].
+    const fn new(options: PoolOptions) -> Self {
+        Self {
+            worlds: RefCell::new(Vec::new()),
+            options,
+        }
+    }
+
+    /// Takes an idle [`World`] out of this [`WorldPool`], if there is any.
+    fn take(&self) -> Option<W> {
+        self.worlds.borrow_mut().pop()
+    }
+
+    /// Indicates whether the [`World`] of a finished [`Scenario`] should be
+    /// returned into this [`WorldPool`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    const fn reuses(&self, is_failed: IsFailed) -> bool {
+        !is_failed || !self.options.discard_failed
+    }
+
+    /// Returns the [`World`] of a finished [`Scenario`] into this
+    /// [`WorldPool`], unless it's full already.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    fn release(&self, world: W) {
+        let mut worlds = self.worlds.borrow_mut();
+        if self.options.size.map_or(true, |size| worlds.len() < size) {
+            worlds.push(world);
+        }
+    }
+}
+
+/// [`World`]s shared between [`Scenario`]s of the same [`Feature`].
+///
+/// [`Feature`]: gherkin::Feature
+/// [`Scenario`]: gherkin::Scenario
+struct SharedWorlds<W> {
+    /// Indicates whether all [`Feature`]s share their [`World`]s, not only the
+    /// ones tagged with `@serial_world`.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    all: bool,
+
+    /// [`SharedWorld`]s of the currently running [`Feature`]s.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    worlds: RefCell<HashMap<Arc<gherkin::Feature>, Rc<Mutex<SharedWorld<W>>>>>,
+}
+
+impl<W> SharedWorlds<W> {
+    /// Creates a new empty [`SharedWorlds`].
+    fn new(all: bool) -> Self {
+        Self {
+            all,
+            worlds: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the [`SharedWorld`] of the provided [`Feature`], if it shares
+    /// one between its [`Scenario`]s.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    fn get(
+        &self,
+        feature: &Arc<gherkin::Feature>,
+    ) -> Option<Rc<Mutex<SharedWorld<W>>>> {
+        (self.all || feature.tags.iter().any(|t| t == "serial_world")).then(
+            || {
+                let mut worlds = self.worlds.borrow_mut();
+                Rc::clone(worlds.entry(Arc::clone(feature)).or_default())
+            },
+        )
+    }
+
+    /// Drops the [`SharedWorld`] of the finished [`Feature`], if any.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    fn remove(&self, feature: &gherkin::Feature) {
+        drop(self.worlds.borrow_mut().remove(feature));
+    }
+}
+
+/// [`World`] shared between [`Scenario`]s of the same [`Feature`].
+///
+/// [`Feature`]: gherkin::Feature
+/// [`Scenario`]: gherkin::Scenario
+struct SharedWorld<W> {
+    /// [`World`] left by the previous [`Scenario`], if it has been constructed
+    /// already.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    world: Option<W>,
+
+    /// Indicates whether a previous [`Scenario`] has failed, so the [`World`]
+    /// may be left in a corrupted state.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    poisoned: bool,
+}
+
+// Implemented manually to omit redundant `W: Default` trait bound, imposed by
+// `#[derive(Default)]`.
+impl<W> Default for SharedWorld<W> {
+    fn default() -> Self {
+        Self {
+            world: None,
+            poisoned: false,
+        }
+    }
+}
+
 /// Alias for a failed [`Scenario`].
 ///
 /// [`Scenario`]: gherkin::Scenario
@@ -313,6 +732,18 @@ type IsFailed = bool;
 /// [`Scenario`]: gherkin::Scenario
 type IsRetried = bool;
 
+/// Alias for a [`Step`] definition of a [`step::Collection`] ([`None`]
+/// [`StepType`] means a generic one).
+///
+/// [`Step`]: gherkin::Step
+type StepDefinition = (Option<StepType>, HashableRegex, Option<step::Location>);
+
+/// Alias for [`Step`] definitions matched by [`Step`]s of a [`StepType`]
+/// during a run.
+///
+/// [`Step`]: gherkin::Step
+type UsedSteps = HashSet<(StepType, HashableRegex, Option<step::Location>)>;
+
 /// Default [`Runner`] implementation which follows [_order guarantees_][1] from
 /// the [`Runner`] trait docs.
 ///
@@ -384,6 +815,58 @@ pub struct Basic<
     /// Indicates whether execution should be stopped after the first failure.
     fail_fast: bool,
 
+    /// [`StopHandle`] to request a cooperative stop of execution with.
+    stop: StopHandle,
+
+    /// Optional [`PoolOptions`] for reusing [`World`]s between [`Scenario`]s.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pool: Option<PoolOptions>,
+
+    /// Optional [`Duration`] after which a running [`Step`] is considered
+    /// failed.
+    ///
+    /// [`Step`]: gherkin::Step
+    step_timeout: Option<Duration>,
+
+    /// [`Execution`] of [`Step`]s.
+    ///
+    /// [`Step`]: gherkin::Step
+    execution: Execution,
+
+    /// [`Order`] to run [`Scenario`]s in.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    order: Order,
+
+    /// Number of times to run all [`Scenario`]s.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    repeat: Option<usize>,
+
+    /// Indicates whether [`Scenario`]s of every [`Feature`] should share a
+    /// single [`World`], not only of the ones tagged with `@serial_world`.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    world_per_feature: bool,
+
+    /// Indicates whether [`Step`] definitions not matched by any [`Step`]
+    /// should be reported once all [`Scenario`]s are finished.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    report_unused_steps: bool,
+
+    /// Optional [`AfterAllFn`] executed once after all [`Scenario`]s are
+    /// finished.
+    ///
+    /// Shared between clones of this [`Basic`] [`Runner`], so is executed only
+    /// by the one run first.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    after_all: Rc<Cell<Option<AfterAllFn>>>,
+
     #[cfg(feature = "tracing")]
     /// [`TracingCollector`] for [`event::Scenario::Log`]s forwarding.
     pub(crate) logs_collector: Arc<AtomicCell<Box<Option<TracingCollector>>>>,
@@ -413,6 +896,15 @@ impl<World, F: Clone, B: Clone, A: Clone> Clone for Basic<World, F, B, A> {
             before_hook: self.before_hook.clone(),
             after_hook: self.after_hook.clone(),
             fail_fast: self.fail_fast,
+            stop: self.stop.clone(),
+            pool: self.pool,
+            step_timeout: self.step_timeout,
+            execution: self.execution,
+            order: self.order,
+            repeat: self.repeat,
+            world_per_feature: self.world_per_feature,
+            report_unused_steps: self.report_unused_steps,
+            after_all: Rc::clone(&self.after_all),
             #[cfg(feature = "tracing")]
             logs_collector: Arc::clone(&self.logs_collector),
         }
@@ -430,6 +922,14 @@ impl<World, F, B, A> fmt::Debug for Basic<World, F, B, A> {
             .field("retry_filter", &self.retry_filter)
             .field("steps", &self.steps)
             .field("fail_fast", &self.fail_fast)
+            .field("stop", &self.stop)
+            .field("pool", &self.pool)
+            .field("step_timeout", &self.step_timeout)
+            .field("execution", &self.execution)
+            .field("order", &self.order)
+            .field("repeat", &self.repeat)
+            .field("world_per_feature", &self.world_per_feature)
+            .field("report_unused_steps", &self.report_unused_steps)
             .finish_non_exhaustive()
     }
 }
@@ -442,7 +942,7 @@ impl<World> Default for Basic<World> {
                 .iter()
                 .chain(rule.iter().flat_map(|r| &r.tags))
                 .chain(&feature.tags)
-                .find(|tag| *tag == "serial")
+                .find(|tag| *tag == "serial" || *tag == "serial_world")
                 .map_or(ScenarioType::Concurrent, |_| ScenarioType::Serial)
         };
 
@@ -457,6 +957,15 @@ impl<World> Default for Basic<World> {
             before_hook: None,
             after_hook: None,
             fail_fast: false,
+            stop: StopHandle::default(),
+            pool: None,
+            step_timeout: None,
+            execution: Execution::Run,
+            order: Order::Defined,
+            repeat: None,
+            world_per_feature: false,
+            report_unused_steps: false,
+            after_all: Rc::default(),
             #[cfg(feature = "tracing")]
             logs_collector: Arc::new(AtomicCell::new(Box::new(None))),
         }
@@ -497,6 +1006,26 @@ impl<World, Which, Before, After> Basic<World, Which, Before, After> {
         self
     }
 
+    /// If `timeout` is [`Some`], then [`Step`]s running longer than the
+    /// specified [`Duration`] are failed with a [`StepError::Timeout`].
+    ///
+    /// __NOTE__: Only [`Step`]s yielding to the async runtime can be
+    ///           interrupted, while a blocking [`Step`] is failed only once it
+    ///           returns, as [`Step`]s are run on the current thread (their
+    ///           [`World`]s are not required to be [`Send`]). Consider using
+    ///           async I/O with its own timeouts in such [`Step`]s.
+    ///
+    /// [`Step`]: gherkin::Step
+    /// [`StepError::Timeout`]: event::StepError::Timeout
+    #[must_use]
+    pub fn step_timeout(
+        mut self,
+        timeout: impl Into<Option<Duration>>,
+    ) -> Self {
+        self.step_timeout = timeout.into();
+        self
+    }
+
     /// If `filter` is [`Some`], then failed [`Scenario`]s will be retried only
     /// if they're matching the specified `tag_expression`.
     ///
@@ -510,18 +1039,140 @@ impl<World, Which, Before, After> Basic<World, Which, Before, After> {
         self
     }
 
-    /// Makes stop running tests on the first failure.
-    ///
-    /// __NOTE__: All the already started [`Scenario`]s at the moment of failure
-    ///           will be finished.
+    /// Makes stop running tests on the first failure.
+    ///
+    /// __NOTE__: All the already started [`Scenario`]s at the moment of failure
+    ///           will be finished.
+    ///
+    /// __NOTE__: Retried [`Scenario`]s are considered as failed, only in case
+    ///           they exhaust all retry attempts and still fail.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub const fn fail_fast(mut self) -> Self {
+        self.fail_fast = true;
+        self
+    }
+
+    /// Makes only resolve [`Step`]s of all [`Scenario`]s to their definitions,
+    /// without constructing any [`World`] or executing any [`Step`] or hook.
+    ///
+    /// Resolved [`Step`]s are reported as [`SkippedWithReason`], while
+    /// undefined and ambiguous ones are reported exactly as in a regular run
+    /// ([`Skipped`] and [`Failed`] respectively). Undefined [`Step`]s fail the
+    /// execution (see [`event::Cucumber::ParsingFinished`]).
+    ///
+    /// [`Failed`]: event::Step::Failed
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Skipped`]: event::Step::Skipped
+    /// [`SkippedWithReason`]: event::Step::SkippedWithReason
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub const fn dry_run(mut self) -> Self {
+        self.execution = Execution::DryRun;
+        self
+    }
+
+    /// Sets the [`Order`] to run [`Scenario`]s in.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub const fn order(mut self, order: Order) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// If `times` is [`Some`], then all [`Scenario`]s will be run the specified
+    /// number of times, reusing the already parsed [`Feature`]s.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn repeat(mut self, times: impl Into<Option<usize>>) -> Self {
+        self.repeat = times.into();
+        self
+    }
+
+    /// Makes [`Scenario`]s of every [`Feature`] share a single [`World`],
+    /// constructed by the first one and reused by the next ones, instead of
+    /// constructing a new [`World`] for each [`Scenario`].
+    ///
+    /// Without this, only [`Feature`]s tagged with `@serial_world` share their
+    /// [`World`]s (and their [`Scenario`]s are [`Serial`] by default, so run in
+    /// their order in the [`Feature`]).
+    ///
+    /// [`Scenario`]s sharing a [`World`] are run one by one, but may be run in
+    /// any order, unless they're [`Serial`] or `--concurrency=1` is used. Once
+    /// any of them fails, the shared [`World`] is considered poisoned, so the
+    /// next ones are failed with a [`StepError::WorldPoisoned`] instead of
+    /// running against a possibly corrupted [`World`].
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Serial`]: ScenarioType::Serial
+    /// [`StepError::WorldPoisoned`]: event::StepError::WorldPoisoned
+    #[must_use]
+    pub const fn world_per_feature(mut self) -> Self {
+        self.world_per_feature = true;
+        self
+    }
+
+    /// Makes report [`Step`] definitions not matched by any [`Step`] once all
+    /// [`Scenario`]s are finished, via an [`event::Cucumber::UnusedSteps`].
+    ///
+    /// Aliases of the same [`Step`] definition are considered used once any of
+    /// them is matched. [`Scenario`]s not run (because of a `--fail-fast`, for
+    /// example) don't use any [`Step`] definitions, so consider combining this
+    /// with a [`Basic::dry_run()`] to quickly check all of them.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub const fn report_unused_steps(mut self) -> Self {
+        self.report_unused_steps = true;
+        self
+    }
+
+    /// Sets the [`StopHandle`] to request a cooperative stop of execution with.
+    ///
+    /// __NOTE__: All the already started [`Scenario`]s at the moment of the
+    ///           request will be finished, skipping their remaining
+    ///           [`Step`]s.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[allow(clippy::missing_const_for_fn)] // false positive: drop in const
+    #[must_use]
+    pub fn stop_handle(mut self, handle: StopHandle) -> Self {
+        self.stop = handle;
+        self
+    }
+
+    /// If `options` is [`Some`], then [`World`]s of finished [`Scenario`]s are
+    /// pooled and [reset][1] for reuse by the next [`Scenario`]s, instead of
+    /// constructing a new [`World`] for each one.
+    ///
+    /// [1]: World::reset
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn pool_worlds(
+        mut self,
+        options: impl Into<Option<PoolOptions>>,
+    ) -> Self {
+        self.pool = options.into();
+        self
+    }
+
+    /// Sets a function to be executed once after all [`Scenario`]s are
+    /// finished (to shut down a server started for tests, for example).
     ///
-    /// __NOTE__: Retried [`Scenario`]s are considered as failed, only in case
-    ///           they exhaust all retry attempts and still fail.
+    /// It's executed even if the execution is aborted by a panic, as long as
+    /// the stream of [`Runner`]'s events is dropped.
     ///
     /// [`Scenario`]: gherkin::Scenario
     #[must_use]
-    pub const fn fail_fast(mut self) -> Self {
-        self.fail_fast = true;
+    pub fn after_all(self, func: impl FnOnce() + 'static) -> Self {
+        self.after_all.set(Some(Box::new(func)));
         self
     }
 
@@ -552,6 +1203,15 @@ impl<World, Which, Before, After> Basic<World, Which, Before, After> {
             before_hook,
             after_hook,
             fail_fast,
+            stop,
+            pool,
+            step_timeout,
+            execution,
+            order,
+            repeat,
+            world_per_feature,
+            report_unused_steps,
+            after_all,
             #[cfg(feature = "tracing")]
             logs_collector,
             ..
@@ -567,6 +1227,15 @@ impl<World, Which, Before, After> Basic<World, Which, Before, After> {
             before_hook,
             after_hook,
             fail_fast,
+            stop,
+            pool,
+            step_timeout,
+            execution,
+            order,
+            repeat,
+            world_per_feature,
+            report_unused_steps,
+            after_all,
             #[cfg(feature = "tracing")]
             logs_collector,
         }
@@ -618,6 +1287,15 @@ impl<World, Which, Before, After> Basic<World, Which, Before, After> {
             retry_options,
             after_hook,
             fail_fast,
+            stop,
+            pool,
+            step_timeout,
+            execution,
+            order,
+            repeat,
+            world_per_feature,
+            report_unused_steps,
+            after_all,
             #[cfg(feature = "tracing")]
             logs_collector,
             ..
@@ -633,6 +1311,15 @@ impl<World, Which, Before, After> Basic<World, Which, Before, After> {
             before_hook: Some(func),
             after_hook,
             fail_fast,
+            stop,
+            pool,
+            step_timeout,
+            execution,
+            order,
+            repeat,
+            world_per_feature,
+            report_unused_steps,
+            after_all,
             #[cfg(feature = "tracing")]
             logs_collector,
         }
@@ -671,6 +1358,15 @@ impl<World, Which, Before, After> Basic<World, Which, Before, After> {
             retry_options,
             before_hook,
             fail_fast,
+            stop,
+            pool,
+            step_timeout,
+            execution,
+            order,
+            repeat,
+            world_per_feature,
+            report_unused_steps,
+            after_all,
             #[cfg(feature = "tracing")]
             logs_collector,
             ..
@@ -686,6 +1382,15 @@ impl<World, Which, Before, After> Basic<World, Which, Before, After> {
             before_hook,
             after_hook: Some(func),
             fail_fast,
+            stop,
+            pool,
+            step_timeout,
+            execution,
+            order,
+            repeat,
+            world_per_feature,
+            report_unused_steps,
+            after_all,
             #[cfg(feature = "tracing")]
             logs_collector,
         }
@@ -694,6 +1399,7 @@ impl<World, Which, Before, After> Basic<World, Which, Before, After> {
     /// Sets the given [`Collection`] of [`Step`]s to this [`Runner`].
     ///
     /// [`Collection`]: step::Collection
+    /// [`Step`]: crate::Step
     #[allow(clippy::missing_const_for_fn)] // false positive: drop in const
     #[must_use]
     pub fn steps(mut self, steps: step::Collection<World>) -> Self {
@@ -701,11 +1407,57 @@ impl<World, Which, Before, After> Basic<World, Which, Before, After> {
         self
     }
 
+    /// Registers [`Step`]s and hooks of the given [`step::Provider`] in this
+    /// [`Runner`].
+    ///
+    /// [`Step`]: crate::Step
+    #[must_use]
+    pub fn provide<P>(mut self, provider: &P) -> Self
+    where
+        P: step::Provider<World> + ?Sized,
+    {
+        self.steps = provider.register(mem::take(&mut self.steps));
+        self
+    }
+
+    /// Makes [`Step`] [`Regex`]es matching only a part of a [`Step`] text to
+    /// be considered as matches, if `allow` is `true`.
+    ///
+    /// See [`step::Collection::partial_matches()`] for details.
+    ///
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn partial_step_matches(mut self, allow: bool) -> Self {
+        self.steps = mem::take(&mut self.steps).partial_matches(allow);
+        self
+    }
+
+    /// Makes [`Step`]s with the same [`Regex`]es as the already registered ones
+    /// to replace them, if `allow` is `true`.
+    ///
+    /// Affects only [`Step`]s registered after this call (via
+    /// [`step::Provider`]s, for example). See
+    /// [`step::Collection::allow_overrides()`] for details.
+    ///
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn allow_step_overrides(mut self, allow: bool) -> Self {
+        self.steps = mem::take(&mut self.steps).allow_overrides(allow);
+        self
+    }
+
     /// Adds a [Given] [`Step`] matching the given `regex`.
     ///
     /// [Given]: https://cucumber.io/docs/gherkin/reference#given
+    /// [`Step`]: crate::Step
     #[must_use]
-    pub fn given(mut self, regex: Regex, step: Step<World>) -> Self {
+    pub fn given<S>(mut self, regex: Regex, step: S) -> Self
+    where
+        S: for<'a> Fn(&'a mut World, step::Context) -> LocalBoxFuture<'a, ()>
+            + Send
+            + Sync
+            + 'static,
+    {
         self.steps = mem::take(&mut self.steps).given(None, regex, step);
         self
     }
@@ -713,8 +1465,15 @@ impl<World, Which, Before, After> Basic<World, Which, Before, After> {
     /// Adds a [When] [`Step`] matching the given `regex`.
     ///
     /// [When]: https://cucumber.io/docs/gherkin/reference#given
+    /// [`Step`]: crate::Step
     #[must_use]
-    pub fn when(mut self, regex: Regex, step: Step<World>) -> Self {
+    pub fn when<S>(mut self, regex: Regex, step: S) -> Self
+    where
+        S: for<'a> Fn(&'a mut World, step::Context) -> LocalBoxFuture<'a, ()>
+            + Send
+            + Sync
+            + 'static,
+    {
         self.steps = mem::take(&mut self.steps).when(None, regex, step);
         self
     }
@@ -722,8 +1481,15 @@ impl<World, Which, Before, After> Basic<World, Which, Before, After> {
     /// Adds a [Then] [`Step`] matching the given `regex`.
     ///
     /// [Then]: https://cucumber.io/docs/gherkin/reference#then
+    /// [`Step`]: crate::Step
     #[must_use]
-    pub fn then(mut self, regex: Regex, step: Step<World>) -> Self {
+    pub fn then<S>(mut self, regex: Regex, step: S) -> Self
+    where
+        S: for<'a> Fn(&'a mut World, step::Context) -> LocalBoxFuture<'a, ()>
+            + Send
+            + Sync
+            + 'static,
+    {
         self.steps = mem::take(&mut self.steps).then(None, regex, step);
         self
     }
@@ -776,6 +1542,15 @@ where
             before_hook,
             after_hook,
             fail_fast,
+            stop,
+            pool,
+            step_timeout,
+            execution,
+            order,
+            repeat,
+            world_per_feature,
+            report_unused_steps,
+            after_all,
             ..
         } = self;
 
@@ -784,8 +1559,25 @@ where
         cli.retry_tag_filter = cli.retry_tag_filter.or(retry_filter);
         let fail_fast = cli.fail_fast || fail_fast;
         let concurrency = cli.concurrency.or(max_concurrent_scenarios);
+        let step_timeout = cli.step_timeout.or(step_timeout);
+        let execution = if cli.dry_run {
+            Execution::DryRun
+        } else {
+            execution
+        };
+        cli.dry_run = execution == Execution::DryRun;
+        let report_unused_steps =
+            cli.report_unused_steps || report_unused_steps;
+        cli.order = Some(cli.order.unwrap_or(order));
+        if let Some(Order::Random(seed)) = &mut cli.order {
+            _ = seed.get_or_insert_with(|| {
+                RandomState::new().build_hasher().finish()
+            });
+        }
 
-        let buffer = Features::default();
+        cli.repeat = cli.repeat.or(repeat);
+
+        let buffer = Features::new(cli.repeat.unwrap_or(1));
         let (sender, receiver) = mpsc::unbounded();
 
         let insert = insert_features(
@@ -805,13 +1597,25 @@ where
             before_hook,
             after_hook,
             fail_fast,
+            stop,
+            pool,
+            step_timeout,
+            execution,
+            world_per_feature,
+            report_unused_steps,
             #[cfg(feature = "tracing")]
             logs_collector,
         );
 
+        let after_all = AfterAllGuard(after_all.take());
+
         stream::select(
             receiver.map(Either::Left),
             future::join(insert, execute)
+                .map(move |r| {
+                    drop(after_all);
+                    r
+                })
                 .into_stream()
                 .map(Either::Right),
         )
@@ -825,6 +1629,53 @@ where
     }
 }
 
+/// Returns a [`Future`] resolving once the provided [`Duration`] elapses.
+///
+/// Dropping the returned [`Future`] stops the spawned timer thread right away.
+///
+/// TODO: Replace `thread::spawn` with async runtime agnostic sleep, once it's
+///       available.
+fn sleep(dur: Duration) -> impl Future<Output = ()> + Send {
+    let (cancel, cancelled) = std_mpsc::channel::<()>();
+    let (sender, receiver) = oneshot::channel();
+    drop(thread::spawn(move || {
+        if cancelled.recv_timeout(dur) == Err(RecvTimeoutError::Timeout) {
+            _ = sender.send(());
+        }
+    }));
+    async move {
+        _ = receiver.await.ok();
+        drop(cancel);
+    }
+}
+
+/// Guard executing the wrapped [`AfterAllFn`] once dropped.
+struct AfterAllGuard(Option<AfterAllFn>);
+
+impl Drop for AfterAllGuard {
+    fn drop(&mut self) {
+        if let Some(func) = self.0.take() {
+            func();
+        }
+    }
+}
+
+/// Guard restoring the original panic hook, replaced by [`execute()`], once
+/// dropped.
+struct PanicHookGuard(Option<Box<dyn FnOnce()>>);
+
+impl Drop for PanicHookGuard {
+    fn drop(&mut self) {
+        // Panic hook cannot be modified while panicking.
+        if thread::panicking() {
+            return;
+        }
+        if let Some(restore) = self.0.take() {
+            restore();
+        }
+    }
+}
+
 /// Stores [`Feature`]s for later use by [`execute()`].
 ///
 /// [`Feature`]: gherkin::Feature
@@ -850,17 +1701,32 @@ async fn insert_features<W, S, F>(
     let mut scenarios = 0;
     let mut steps = 0;
     let mut parser_errors = 0;
+    let mut filtered_scenarios = 0;
+
+    // Shuffled `Feature`s can be inserted only once all of them are parsed.
+    let seed = match cli.order {
+        Some(Order::Random(seed)) => seed,
+        Some(Order::Defined) | None => None,
+    };
+    let mut shuffler = seed.map(Shuffler);
+    let mut to_shuffle = Vec::new();
 
     pin_mut!(features_stream);
     while let Some(feat) = features_stream.next().await {
         match feat {
-            Ok(f) => {
+            Ok(mut f) => {
+                filtered_scenarios += parser::basic::take_deselected(&mut f);
+
                 features += 1;
                 rules += f.rules.len();
                 scenarios += f.count_scenarios();
                 steps += f.count_steps();
 
-                into.insert(f, &which_scenario, &retries, &cli).await;
+                if shuffler.is_some() {
+                    to_shuffle.push(f);
+                } else {
+                    into.insert(f, &which_scenario, &retries, &cli, None).await;
+                }
             }
             Err(e) => {
                 parser_errors += 1;
@@ -874,6 +1740,14 @@ async fn insert_features<W, S, F>(
         }
     }
 
+    if let Some(sh) = shuffler.as_mut() {
+        sh.shuffle(&mut to_shuffle);
+    }
+    for f in to_shuffle {
+        into.insert(f, &which_scenario, &retries, &cli, shuffler.as_mut())
+            .await;
+    }
+
     drop(sender.unbounded_send(Ok(Event::new(
         event::Cucumber::ParsingFinished {
             features,
@@ -881,6 +1755,10 @@ async fn insert_features<W, S, F>(
             scenarios,
             steps,
             parser_errors,
+            // Other `Scenario`s are filtered out before reaching a `Runner`.
+            filtered_scenarios,
+            dry_run: cli.dry_run,
+            seed,
         },
     ))));
 
@@ -909,6 +1787,12 @@ async fn execute<W, Before, After>(
     before_hook: Option<Before>,
     after_hook: Option<After>,
     fail_fast: bool,
+    stop: StopHandle,
+    pool: Option<PoolOptions>,
+    step_timeout: Option<Duration>,
+    execution: Execution,
+    world_per_feature: bool,
+    report_unused_steps: bool,
     #[cfg(feature = "tracing")] mut logs_collector: Option<TracingCollector>,
 ) where
     W: World,
@@ -931,13 +1815,27 @@ async fn execute<W, Before, After>(
     // Those panic hook shenanigans are done to avoid console messages like
     // "thread 'main' panicked at ..."
     //
-    // 1. We obtain the current panic hook and replace it with an empty one.
+    // 1. We obtain the current panic hook and replace it with the one only
+    //    recording the panic location and backtrace for `panic_details()`.
     // 2. We run tests, which can panic. In that case we pass all panic info
     //    down the line to the Writer, which will print it at a right time.
     // 3. We restore original panic hook, because suppressing all panics doesn't
-    //    sound like a very good idea.
+    //    sound like a very good idea. This is done by a guard, so the hook is
+    //    restored even if the execution is dropped before finishing.
     let hook = panic::take_hook();
-    panic::set_hook(Box::new(|_| {}));
+    let _hook_guard = PanicHookGuard(Some(Box::new(|| panic::set_hook(hook))));
+    panic::set_hook(Box::new(|info| {
+        let location = info.location().map(|l| event::PanicLocation {
+            file: l.file().to_owned(),
+            line: l.line(),
+            column: l.column(),
+        });
+        let backtrace = Backtrace::capture();
+        let backtrace = (backtrace.status() == BacktraceStatus::Captured)
+            .then(|| Arc::new(backtrace));
+        LAST_PANIC
+            .with(|last| *last.borrow_mut() = Some((location, backtrace)));
+    }));
 
     let (finished_sender, finished_receiver) = mpsc::unbounded();
     let mut storage = FinishedRulesAndFeatures::new(finished_receiver);
@@ -948,9 +1846,21 @@ async fn execute<W, Before, After>(
         event_sender,
         finished_sender,
         features.clone(),
+        stop.clone(),
+        StepLogs::new(),
+        pool.map(WorldPool::new),
+        step_timeout,
+        execution,
+        SharedWorlds::new(world_per_feature),
+        report_unused_steps.then(RefCell::default),
     );
 
     executor.send_event(event::Cucumber::Started);
+    if !executor.collection.duplicates().is_empty() {
+        executor.send_event(event::Cucumber::DuplicateSteps(
+            executor.collection.duplicates().to_vec(),
+        ));
+    }
 
     // TODO: Replace with `ControlFlow::map_break()` once stabilized:
     //       https://github.com/rust-lang/rust/issues/75744
@@ -967,26 +1877,27 @@ async fn execute<W, Before, After>(
     let mut started_scenarios = ControlFlow::Continue(max_concurrent_scenarios);
     let mut run_scenarios = stream::FuturesUnordered::new();
     loop {
-        let (runnable, sleep) =
+        if stop.is_requested() {
+            started_scenarios = ControlFlow::Break(());
+        }
+
+        let (runnable, sleep_for) =
             features.get(map_break(started_scenarios)).await;
         if run_scenarios.is_empty() && runnable.is_empty() {
             if features.is_finished(started_scenarios.is_break()).await {
-                break;
+                // Failing fast or a requested stop skip the remaining
+                // repetitions too.
+                if started_scenarios.is_break() || !features.repeat().await {
+                    break;
+                }
+                continue;
             }
 
             // To avoid busy-polling of `Features::get()`, in case there are no
-            // scenarios that are running or scheduled for execution, we spawn a
-            // thread, that sleeps for minimal deadline of all retried
-            // scenarios.
-            // TODO: Replace `thread::spawn` with async runtime agnostic sleep,
-            //       once it's available.
-            if let Some(dur) = sleep {
-                let (sender, receiver) = oneshot::channel();
-                drop(thread::spawn(move || {
-                    thread::sleep(dur);
-                    sender.send(())
-                }));
-                _ = receiver.await.ok();
+            // scenarios that are running or scheduled for execution, we sleep
+            // for minimal deadline of all retried scenarios.
+            if let Some(dur) = sleep_for {
+                sleep(dur).await;
             }
 
             continue;
@@ -1013,10 +1924,12 @@ async fn execute<W, Before, After>(
                     }
                 }
             };
-            #[cfg(feature = "tracing")]
-            pin_mut!(forward_logs);
             #[cfg(not(feature = "tracing"))]
-            let forward_logs = future::pending();
+            let forward_logs = future::pending::<()>();
+            let forward_logs =
+                future::join(forward_logs, executor.forward_step_logs_live())
+                    .map(|_| None);
+            pin_mut!(forward_logs);
 
             if let ControlFlow::Continue(Some(sc)) = &mut started_scenarios {
                 *sc -= runnable.len();
@@ -1063,8 +1976,9 @@ async fn execute<W, Before, After>(
                     executor.send_event(f);
                 }
             }
-            if let Some(f) = storage.feature_scenario_finished(feat, retried) {
-                executor.send_event(f);
+            if storage.feature_scenario_finished(&feat, retried) {
+                executor.shared_worlds.remove(&feat);
+                executor.send_event(executor.feature_finished(feat));
             }
             #[cfg(feature = "tracing")]
             {
@@ -1081,13 +1995,20 @@ async fn execute<W, Before, After>(
         }
     }
 
-    // This is done in case of `fail_fast: true`, when not all `Scenario`s might
-    // be executed.
-    executor.send_all_events(storage.finish_all_rules_and_features());
+    // This is done in case of `fail_fast: true` or a requested stop, when not
+    // all `Scenario`s might be executed.
+    let (rules, feats) = storage.finish_all_rules_and_features();
+    executor.send_all_events(
+        rules.chain(feats.map(|f| executor.feature_finished(f))),
+    );
 
+    if let Some(unused) = executor.unused_steps() {
+        executor.send_event(event::Cucumber::UnusedSteps(unused));
+    }
+    if stop.is_requested() {
+        executor.send_event(event::Cucumber::Aborted);
+    }
     executor.send_event(event::Cucumber::Finished);
-
-    panic::set_hook(hook);
 }
 
 /// Runs [`Scenario`]s and notifies about their state of completion.
@@ -1129,6 +2050,46 @@ struct Executor<W, Before, After> {
     ///
     /// [`Scenario`]: gherkin::Scenario
     storage: Features,
+
+    /// [`StopHandle`] checked before running each [`Step`].
+    ///
+    /// [`Step`]: gherkin::Step
+    stop: StopHandle,
+
+    /// [`StepLogs`] of the currently running [`Scenario`]s.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    step_logs: StepLogs,
+
+    /// [`WorldPool`] to reuse [`World`]s from, if pooling is enabled.
+    world_pool: Option<WorldPool<W>>,
+
+    /// [`Duration`] after which a running [`Step`] is failed, if any.
+    ///
+    /// [`Step`]: gherkin::Step
+    step_timeout: Option<Duration>,
+
+    /// [`Execution`] of [`Step`]s.
+    ///
+    /// [`Step`]: gherkin::Step
+    execution: Execution,
+
+    /// [`SharedWorlds`] of [`Feature`]s sharing a single [`World`] between
+    /// their [`Scenario`]s.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    shared_worlds: SharedWorlds<W>,
+
+    /// [`UsedSteps`] matched so far, if reporting of unused [`Step`]
+    /// definitions is enabled.
+    ///
+    /// [`Step`]: gherkin::Step
+    used_steps: Option<RefCell<UsedSteps>>,
+
+    /// [`result::Collector`] aggregating the [`result::ScenarioResult`]s and
+    /// [`result::FeatureResult`]s passed along with the `Finished` events.
+    results: RefCell<result::Collector>,
 }
 
 impl<W: World, Before, After> Executor<W, Before, After>
@@ -1150,7 +2111,8 @@ where
         ) -> LocalBoxFuture<'a, ()>,
 {
     /// Creates a new [`Executor`].
-    const fn new(
+    #[allow(clippy::too_many_arguments)]
+    fn new(
         collection: step::Collection<W>,
         before_hook: Option<Before>,
         after_hook: Option<After>,
@@ -1159,6 +2121,13 @@ where
         >,
         finished_sender: FinishedFeaturesSender,
         storage: Features,
+        stop: StopHandle,
+        step_logs: StepLogs,
+        world_pool: Option<WorldPool<W>>,
+        step_timeout: Option<Duration>,
+        execution: Execution,
+        shared_worlds: SharedWorlds<W>,
+        used_steps: Option<RefCell<UsedSteps>>,
     ) -> Self {
         Self {
             collection,
@@ -1167,6 +2136,85 @@ where
             event_sender,
             finished_sender,
             storage,
+            stop,
+            step_logs,
+            world_pool,
+            step_timeout,
+            execution,
+            shared_worlds,
+            used_steps,
+            results: RefCell::new(result::Collector::new()),
+        }
+    }
+
+    /// Records the [`Step`] definition matching the given [`Step`] as a used
+    /// one, if reporting of unused [`Step`] definitions is enabled.
+    ///
+    /// [`Step`]: gherkin::Step
+    fn use_step(
+        &self,
+        step: &gherkin::Step,
+        regex: &HashableRegex,
+        loc: Option<step::Location>,
+    ) {
+        if let Some(used) = &self.used_steps {
+            _ = used.borrow_mut().insert((step.ty, regex.clone(), loc));
+        }
+    }
+
+    /// Returns [`Step`] definitions not matched by any [`Step`], if reporting
+    /// of unused [`Step`] definitions is enabled.
+    ///
+    /// [`Step`]: gherkin::Step
+    fn unused_steps(&self) -> Option<Vec<StepDefinition>> {
+        let used = self.used_steps.as_ref()?.borrow();
+        // Aliases of the same definition share its `Location`.
+        let used_locs = used
+            .iter()
+            .filter_map(|(ty, _, loc)| loc.map(|l| (*ty, l)))
+            .collect::<HashSet<_>>();
+        let is_used = |ty, re: &HashableRegex, loc: Option<step::Location>| {
+            loc.is_some_and(|l| used_locs.contains(&(ty, l)))
+                || used.contains(&(ty, re.clone(), loc))
+        };
+        Some(
+            self.collection
+                .definitions()
+                .filter(|(ty, re, loc)| {
+                    // Generic definitions may be used by any `StepType`.
+                    !ty.map_or_else(
+                        || {
+                            [StepType::Given, StepType::When, StepType::Then]
+                                .into_iter()
+                                .any(|t| is_used(t, re, *loc))
+                        },
+                        |ty| is_used(ty, re, *loc),
+                    )
+                })
+                .map(|(ty, re, loc)| (ty, re.clone(), loc))
+                .collect(),
+        )
+    }
+
+    /// Takes a [`World`] out of the [`WorldPool`] and [resets][1] it, or
+    /// constructs a new one, if there are no idle [`World`]s.
+    ///
+    /// [1]: World::reset
+    async fn init_world(&self) -> thread::Result<Result<W, W::Error>> {
+        let idle = self.world_pool.as_ref().and_then(WorldPool::take);
+        if let Some(mut world) = idle {
+            AssertUnwindSafe(clearing_last_panic(async {
+                world.reset().await;
+                Ok(world)
+            }))
+            .catch_unwind()
+            .then_yield()
+            .await
+        } else {
+            AssertUnwindSafe(clearing_last_panic(W::new()))
+                .catch_unwind()
+                .then_yield()
+                .await
         }
     }
 
@@ -1179,7 +2227,11 @@ where
     /// [`Feature`]: gherkin::Feature
     /// [`Rule`]: gherkin::Rule
     /// [`Scenario`]: gherkin::Scenario
-    #[allow(clippy::too_many_arguments, clippy::too_many_lines)]
+    #[allow( // intended: shared `World` is locked until `Scenario` finishes
+        clippy::significant_drop_tightening,
+        clippy::too_many_arguments,
+        clippy::too_many_lines
+    )]
     async fn run_scenario(
         &self,
         id: ScenarioId,
@@ -1190,6 +2242,24 @@ where
         retries: Option<RetryOptions>,
         #[cfg(feature = "tracing")] waiter: Option<&SpanCloseWaiter>,
     ) {
+        if self.execution == Execution::DryRun {
+            self.resolve_scenario(id, feature, rule, &scenario);
+            return;
+        }
+
+        // Held until this `Scenario` finishes, so the ones sharing a `World`
+        // are run one by one.
+        let shared = self.shared_worlds.get(&feature);
+        let mut shared = match &shared {
+            Some(sh) => Some(sh.lock().await),
+            None => None,
+        };
+        if shared.as_ref().is_some_and(|sh| sh.poisoned) {
+            self.poisoned_scenario(id, feature, rule, &scenario);
+            return;
+        }
+        let shared_world = shared.as_mut().and_then(|sh| sh.world.take());
+
         let retry_num = retries.map(|r| r.retries);
         let ok = |e: fn(_) -> event::Scenario<W>| {
             let (f, r, s) = (&feature, &rule, &scenario);
@@ -1207,19 +2277,46 @@ where
                 event::Cucumber::scenario(f, r, s, event)
             }
         };
+        let ok_loc = |e: fn(_, _) -> event::Scenario<W>| {
+            let (f, r, s) = (&feature, &rule, &scenario);
+            move |step, loc| {
+                let (f, r, s) = (Arc::clone(f), r.clone(), Arc::clone(s));
+                let event = e(step, loc).with_retries(retry_num);
+                event::Cucumber::scenario(f, r, s, event)
+            }
+        };
+
+        let ok_reason = |e: fn(_, _, _) -> event::Scenario<W>| {
+            let (f, r, s) = (&feature, &rule, &scenario);
+            move |step, loc, reason| {
+                let (f, r, s) = (Arc::clone(f), r.clone(), Arc::clone(s));
+                let event = e(step, loc, reason).with_retries(retry_num);
+                event::Cucumber::scenario(f, r, s, event)
+            }
+        };
 
-        let compose = |started, passed, skipped| {
-            (ok(started), ok_capt(passed), ok(skipped))
+        let compose = |started, passed, skipped, pending, skipped_reason| {
+            (
+                ok(started),
+                ok_capt(passed),
+                ok(skipped),
+                ok_loc(pending),
+                ok_reason(skipped_reason),
+            )
         };
         let into_bg_step_ev = compose(
             event::Scenario::background_step_started,
             event::Scenario::background_step_passed,
             event::Scenario::background_step_skipped,
+            event::Scenario::background_step_pending,
+            event::Scenario::background_step_skipped_with_reason,
         );
         let into_step_ev = compose(
             event::Scenario::step_started,
             event::Scenario::step_passed,
             event::Scenario::step_skipped,
+            event::Scenario::step_pending,
+            event::Scenario::step_skipped_with_reason,
         );
 
         self.send_event(event::Cucumber::scenario(
@@ -1228,14 +2325,26 @@ where
             Arc::clone(&scenario),
             event::Scenario::Started.with_retries(retry_num),
         ));
+        drop(self.step_logs.scenarios.borrow_mut().insert(
+            id,
+            (
+                Arc::clone(&feature),
+                rule.clone(),
+                Arc::clone(&scenario),
+                retry_num,
+            ),
+        ));
+
+        // Dropped once the `Scenario` finishes, so isn't shared between its
+        // retries.
+        let scratch = step::Scratch::default();
 
         let is_failed = async {
             let mut result = async {
                 let before_hook = self
                     .run_before_hook(
-                        &feature,
-                        rule.as_ref(),
-                        &scenario,
+                        (&feature, rule.as_ref(), &scenario),
+                        shared_world,
                         retry_num,
                         id,
                         #[cfg(feature = "tracing")]
@@ -1255,10 +2364,11 @@ where
                     .try_fold(before_hook, |world, bg_step| {
                         self.run_step(
                             world,
+                            (&feature, rule.as_ref(), &scenario),
                             bg_step,
                             true,
                             into_bg_step_ev,
-                            id,
+                            (id, &scratch),
                             #[cfg(feature = "tracing")]
                             waiter,
                         )
@@ -1285,10 +2395,11 @@ where
                     .try_fold(feature_background, |world, bg_step| {
                         self.run_step(
                             world,
+                            (&feature, rule.as_ref(), &scenario),
                             bg_step,
                             true,
                             into_bg_step_ev,
-                            id,
+                            (id, &scratch),
                             #[cfg(feature = "tracing")]
                             waiter,
                         )
@@ -1301,10 +2412,11 @@ where
                     .try_fold(rule_background, |world, step| {
                         self.run_step(
                             world,
+                            (&feature, rule.as_ref(), &scenario),
                             step,
                             false,
                             into_step_ev,
-                            id,
+                            (id, &scratch),
                             #[cfg(feature = "tracing")]
                             waiter,
                         )
@@ -1350,6 +2462,21 @@ where
             };
             let is_failed = scenario_failed || after_hook_error.is_some();
 
+            // Reused `World` is never shared with `Writer`s via events, so it
+            // can't be observed by them while the next `Scenario` mutates it.
+            let (world, reused_world) = match (&mut shared, &self.world_pool) {
+                (Some(sh), _) => {
+                    sh.poisoned = is_failed;
+                    if is_failed {
+                        (world, None)
+                    } else {
+                        (None, world)
+                    }
+                }
+                (None, Some(pool)) if pool.reuses(is_failed) => (None, world),
+                (None, _) => (world, None),
+            };
+
             if let Some(exec_error) = result.err() {
                 self.emit_failed_events(
                     Arc::clone(&feature),
@@ -1371,6 +2498,18 @@ where
                 retry_num,
             );
 
+            let reused_world =
+                reused_world.and_then(|w| Arc::try_unwrap(w).ok());
+            match (shared.as_mut(), &self.world_pool) {
+                (Some(sh), _) => sh.world = reused_world,
+                (None, Some(pool)) => {
+                    if let Some(reused) = reused_world {
+                        pool.release(reused);
+                    }
+                }
+                (None, None) => {}
+            }
+
             is_failed
         };
         #[cfg(feature = "tracing")]
@@ -1380,45 +2519,211 @@ where
             let is_failed = tracing::Instrument::instrument(is_failed, span);
             (is_failed, span_id)
         };
-        let is_failed = is_failed.then_yield().await;
+        let is_failed = is_failed.then_yield().await;
+
+        #[cfg(feature = "tracing")]
+        if let Some((waiter, span_id)) = waiter.zip(span_id) {
+            waiter.wait_for_span_close(span_id).then_yield().await;
+        }
+
+        self.send_event(event::Cucumber::scenario(
+            Arc::clone(&feature),
+            rule.clone(),
+            Arc::clone(&scenario),
+            event::Scenario::Finished(self.scenario_result(
+                &feature,
+                rule.as_ref(),
+                &scenario,
+                retry_num,
+            ))
+            .with_retries(retry_num),
+        ));
+        drop(self.step_logs.scenarios.borrow_mut().remove(&id));
+
+        let next_try = retries
+            .filter(|_| is_failed)
+            .and_then(RetryOptions::next_try);
+        if let Some(next_try) = next_try {
+            self.storage
+                .insert_retried_scenario(
+                    Arc::clone(&feature),
+                    rule.clone(),
+                    scenario,
+                    scenario_ty,
+                    Some(next_try),
+                )
+                .await;
+        }
+
+        self.scenario_finished(
+            id,
+            feature,
+            rule,
+            is_failed,
+            next_try.is_some(),
+        );
+    }
+
+    /// Resolves all [`Step`]s of a [`Scenario`] (including [`Background`]
+    /// ones) to their definitions, without constructing a [`World`] or
+    /// executing anything.
+    ///
+    /// # Events
+    ///
+    /// - Emits all [`Scenario`] events, reporting resolved [`Step`]s as
+    ///   [`SkippedWithReason`], while undefined and ambiguous ones exactly as
+    ///   a regular run does ([`Skipped`] and [`Failed`] respectively).
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Failed`]: event::Step::Failed
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Skipped`]: event::Step::Skipped
+    /// [`SkippedWithReason`]: event::Step::SkippedWithReason
+    /// [`Step`]: gherkin::Step
+    fn resolve_scenario(
+        &self,
+        id: ScenarioId,
+        feature: Arc<gherkin::Feature>,
+        rule: Option<Arc<gherkin::Rule>>,
+        scenario: &Arc<gherkin::Scenario>,
+    ) {
+        let send = |ev: event::Scenario<W>| {
+            self.send_event(event::Cucumber::scenario(
+                Arc::clone(&feature),
+                rule.clone(),
+                Arc::clone(scenario),
+                ev.with_retries(None),
+            ));
+        };
+
+        send(event::Scenario::Started);
+
+        let backgrounds = feature
+            .background
+            .iter()
+            .chain(rule.as_ref().and_then(|r| r.background.as_ref()))
+            .flat_map(|b| b.steps.iter().map(|s| (s, true)));
+        let steps = scenario.steps.iter().map(|s| (s, false));
+
+        let mut is_failed = false;
+        for (step, is_background) in backgrounds.chain(steps) {
+            let step = Arc::new(step.clone());
+            let into_ev = |ev| {
+                let step = Arc::clone(&step);
+                if is_background {
+                    event::Scenario::Background(step, ev)
+                } else {
+                    event::Scenario::Step(step, ev)
+                }
+            };
+
+            send(into_ev(event::Step::Started));
+            send(into_ev(match self.collection.resolve(&step) {
+                Resolution::Literal(re, loc)
+                | Resolution::Regex(re, loc, _) => {
+                    self.use_step(&step, &re, loc);
+                    if self.collection.is_wip(step.ty, &re, loc) {
+                        event::Step::Pending(loc)
+                    } else {
+                        let reason = "dry run".to_owned();
+                        event::Step::SkippedWithReason(loc, reason)
+                    }
+                }
+                Resolution::Undefined(partial) => {
+                    if !partial.is_empty() {
+                        let msg =
+                            step::describe_partial_matches(&step, &partial);
+                        send(event::Scenario::Log(format!("{msg}\n")));
+                    }
+                    event::Step::Skipped
+                }
+                Resolution::Ambiguous(e) => {
+                    is_failed = true;
+                    let e = event::StepError::AmbiguousMatch(e);
+                    event::Step::Failed(None, None, None, e)
+                }
+            }));
+        }
+
+        send(event::Scenario::Finished(self.scenario_result(
+            &feature,
+            rule.as_ref(),
+            scenario,
+            None,
+        )));
+        self.scenario_finished(id, feature, rule, is_failed, false);
+    }
+
+    /// Fails a [`Scenario`] sharing a poisoned [`World`] without running it.
+    ///
+    /// # Events
+    ///
+    /// - Emits [`Scenario`] events, reporting its first [`Step`] (including
+    ///   [`Background`] ones) as [`Failed`] with a
+    ///   [`StepError::WorldPoisoned`].
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Failed`]: event::Step::Failed
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    /// [`StepError::WorldPoisoned`]: event::StepError::WorldPoisoned
+    fn poisoned_scenario(
+        &self,
+        id: ScenarioId,
+        feature: Arc<gherkin::Feature>,
+        rule: Option<Arc<gherkin::Rule>>,
+        scenario: &Arc<gherkin::Scenario>,
+    ) {
+        let send = |ev: event::Scenario<W>| {
+            self.send_event(event::Cucumber::scenario(
+                Arc::clone(&feature),
+                rule.clone(),
+                Arc::clone(scenario),
+                ev.with_retries(None),
+            ));
+        };
 
-        #[cfg(feature = "tracing")]
-        if let Some((waiter, span_id)) = waiter.zip(span_id) {
-            waiter.wait_for_span_close(span_id).then_yield().await;
-        }
+        send(event::Scenario::Started);
 
-        self.send_event(event::Cucumber::scenario(
-            Arc::clone(&feature),
-            rule.clone(),
-            Arc::clone(&scenario),
-            event::Scenario::Finished.with_retries(retry_num),
-        ));
+        let background = feature
+            .background
+            .iter()
+            .chain(rule.as_ref().and_then(|r| r.background.as_ref()))
+            .find_map(|b| b.steps.first());
+        let first = background
+            .map(|s| (s, true))
+            .or_else(|| scenario.steps.first().map(|s| (s, false)));
+        if let Some((step, is_background)) = first {
+            let step = Arc::new(step.clone());
+            let into_ev = |ev| {
+                let step = Arc::clone(&step);
+                if is_background {
+                    event::Scenario::Background(step, ev)
+                } else {
+                    event::Scenario::Step(step, ev)
+                }
+            };
 
-        let next_try = retries
-            .filter(|_| is_failed)
-            .and_then(RetryOptions::next_try);
-        if let Some(next_try) = next_try {
-            self.storage
-                .insert_retried_scenario(
-                    Arc::clone(&feature),
-                    rule.clone(),
-                    scenario,
-                    scenario_ty,
-                    Some(next_try),
-                )
-                .await;
+            send(into_ev(event::Step::Started));
+            send(into_ev(event::Step::Failed(
+                None,
+                None,
+                None,
+                event::StepError::WorldPoisoned,
+            )));
         }
 
-        self.scenario_finished(
-            id,
-            feature,
-            rule,
-            is_failed,
-            next_try.is_some(),
-        );
+        send(event::Scenario::Finished(self.scenario_result(
+            &feature,
+            rule.as_ref(),
+            scenario,
+            None,
+        )));
+        self.scenario_finished(id, feature, rule, true, false);
     }
 
-    /// Executes [`HookType::Before`], if present.
+    /// Executes [`HookType::Before`], if present, along with the
+    /// [`World::before_scenario()`].
     ///
     /// # Events
     ///
@@ -1428,17 +2733,25 @@ where
     /// [`Hook::Failed`]: event::Hook::Failed
     async fn run_before_hook(
         &self,
-        feature: &Arc<gherkin::Feature>,
-        rule: Option<&Arc<gherkin::Rule>>,
-        scenario: &Arc<gherkin::Scenario>,
+        (feature, rule, scenario): (
+            &Arc<gherkin::Feature>,
+            Option<&Arc<gherkin::Rule>>,
+            &Arc<gherkin::Scenario>,
+        ),
+        shared_world: Option<W>,
         retries: Option<Retries>,
         scenario_id: ScenarioId,
         #[cfg(feature = "tracing")] waiter: Option<&SpanCloseWaiter>,
     ) -> Result<Option<W>, ExecutionFailure<W>> {
+        // `World::before_scenario()` should be executed on a shared `World`
+        // too, while a new one executes it on construction by the first
+        // `Step`.
+        let has_world = shared_world.is_some();
         let init_world = async {
-            AssertUnwindSafe(async { W::new().await })
-                .catch_unwind()
-                .then_yield()
+            if let Some(world) = shared_world {
+                return Ok(world);
+            }
+            self.init_world()
                 .await
                 .map_err(Info::from)
                 .and_then(|r| {
@@ -1451,7 +2764,12 @@ where
                 .map_err(|info| (info, None))
         };
 
-        if let Some(hook) = self.before_hook.as_ref() {
+        let mut hooks = self
+            .collection
+            .before_hooks(feature, rule.map(AsRef::as_ref), scenario)
+            .peekable();
+
+        if has_world || self.before_hook.is_some() || hooks.peek().is_some() {
             self.send_event(event::Cucumber::scenario(
                 Arc::clone(feature),
                 rule.map(Arc::clone),
@@ -1462,13 +2780,14 @@ where
 
             let fut = init_world.and_then(|mut world| async {
                 let fut = async {
-                    (hook)(
-                        feature.as_ref(),
-                        rule.as_ref().map(AsRef::as_ref),
-                        scenario.as_ref(),
-                        &mut world,
-                    )
-                    .await;
+                    let rule = rule.as_ref().map(AsRef::as_ref);
+                    if let Some(hook) = self.before_hook.as_ref() {
+                        (hook)(feature, rule, scenario, &mut world).await;
+                    }
+                    for hook in hooks {
+                        (hook)(feature, rule, scenario, &mut world).await;
+                    }
+                    world.before_scenario(feature, rule, scenario).await;
                 };
                 match AssertUnwindSafe(fut).catch_unwind().await {
                     Ok(()) => Ok(world),
@@ -1519,6 +2838,9 @@ where
 
     /// Runs a [`Step`].
     ///
+    /// Constructs a new [`World`] (executing [`World::before_scenario()`] on
+    /// it), if there is no one yet.
+    ///
     /// # Events
     ///
     /// - Emits all the [`Step`] events, except [`Step::Failed`]. See
@@ -1526,13 +2848,25 @@ where
     ///
     /// [`Step`]: gherkin::Step
     /// [`Step::Failed`]: event::Step::Failed
-    async fn run_step<St, Ps, Sk>(
+    #[allow(clippy::too_many_arguments, clippy::too_many_lines)]
+    async fn run_step<St, Ps, Sk, Pe, Sr>(
         &self,
         world_opt: Option<W>,
+        scenario: (
+            &Arc<gherkin::Feature>,
+            Option<&Arc<gherkin::Rule>>,
+            &Arc<gherkin::Scenario>,
+        ),
         step: Arc<gherkin::Step>,
         is_background: bool,
-        (started, passed, skipped): (St, Ps, Sk),
-        scenario_id: ScenarioId,
+        (started, passed, skipped, pending, skipped_reason): (
+            St,
+            Ps,
+            Sk,
+            Pe,
+            Sr,
+        ),
+        (scenario_id, scratch): (ScenarioId, &step::Scratch),
         #[cfg(feature = "tracing")] waiter: Option<&SpanCloseWaiter>,
     ) -> Result<W, ExecutionFailure<W>>
     where
@@ -1543,14 +2877,63 @@ where
             Option<step::Location>,
         ) -> event::Cucumber<W>,
         Sk: FnOnce(Arc<gherkin::Step>) -> event::Cucumber<W>,
+        Pe: FnOnce(
+            Arc<gherkin::Step>,
+            Option<step::Location>,
+        ) -> event::Cucumber<W>,
+        Sr: FnOnce(
+            Arc<gherkin::Step>,
+            Option<step::Location>,
+            String,
+        ) -> event::Cucumber<W>,
     {
         self.send_event(started(Arc::clone(&step)));
 
+        // `Step`s left after a requested stop are neither undefined nor
+        // skipped by themselves, so are reported with the reason.
+        if self.stop.is_requested() {
+            let reason = "execution stopped".to_owned();
+            self.send_event(skipped_reason(step, None, reason));
+            return Err(ExecutionFailure::StepSkipped(world_opt));
+        }
+
         let run = async {
+            let (feature, rule, scenario) = scenario;
+            let found = self.collection.lookup(&step);
+            if let Ok(Ok((_, _, loc, ctx))) = &found {
+                self.use_step(&step, ctx.regex(), *loc);
+            }
             let (step_fn, captures, loc, ctx) =
-                match self.collection.find(&step) {
-                    Ok(Some(f)) => f,
-                    Ok(None) => return Ok((None, None, world_opt)),
+                match found {
+                    Ok(Ok((_, _, loc, ctx))) if ctx.is_wip() => {
+                        let e = event::StepError::Pending;
+                        return Err((e, None, loc, world_opt));
+                    }
+                    Ok(Ok((step_fn, captures, loc, ctx))) => {
+                        let ctx = ctx
+                            .with_log_sender(
+                                scenario_id,
+                                self.step_logs.sender.clone(),
+                            )
+                            .with_scratch(scratch.clone())
+                            .with_scenario((
+                                Arc::clone(feature),
+                                rule.cloned(),
+                                Arc::clone(scenario),
+                            ));
+                        (step_fn, captures, loc, ctx)
+                    }
+                    Ok(Err(partial)) => {
+                        if !partial.is_empty() {
+                            let msg =
+                                step::describe_partial_matches(&step, &partial);
+                            drop(self.step_logs.sender.unbounded_send((
+                                scenario_id,
+                                format!("{msg}\n"),
+                            )));
+                        }
+                        return Ok((None, None, world_opt));
+                    }
                     Err(e) => {
                         let e = event::StepError::AmbiguousMatch(e);
                         return Err((e, None, None, world_opt));
@@ -1560,32 +2943,75 @@ where
             let mut world = if let Some(w) = world_opt {
                 w
             } else {
-                match AssertUnwindSafe(async { W::new().await })
-                    .catch_unwind()
-                    .then_yield()
-                    .await
-                {
+                let mut w = match self.init_world().await {
                     Ok(Ok(w)) => w,
                     Ok(Err(e)) => {
-                        let e = event::StepError::Panic(coerce_into_info(
-                            format!("failed to initialize `World`: {e}"),
-                        ));
+                        let e = event::StepError::Panic(
+                            event::PanicDetails::new(coerce_into_info(
+                                format!("failed to initialize `World`: {e}"),
+                            )),
+                        );
                         return Err((e, None, loc, None));
                     }
                     Err(e) => {
-                        let e = event::StepError::Panic(e.into());
+                        let e = event::StepError::Panic(panic_details(e));
                         return Err((e, None, loc, None));
                     }
+                };
+                if let Err(e) = AssertUnwindSafe(clearing_last_panic(
+                    w.before_scenario(feature, rule.map(|r| &**r), scenario),
+                ))
+                .catch_unwind()
+                .await
+                {
+                    let e = event::StepError::Panic(panic_details(e));
+                    return Err((e, None, loc, Some(w)));
                 }
+                w
             };
 
-            match AssertUnwindSafe(async { step_fn(&mut world, ctx).await })
-                .catch_unwind()
+            let joined_threads = ctx.joined_threads();
+            let step_fut = AssertUnwindSafe(clearing_last_panic(async {
+                step_fn(&mut world, ctx).await;
+            }))
+            .catch_unwind()
+            .map_err(panic_details);
+            let res = if let Some(timeout) = self.step_timeout {
+                let started_at = Instant::now();
+                pin_mut!(step_fut);
+                match future::select(step_fut, sleep(timeout).boxed()).await {
+                    // A blocking step can't be interrupted, so it's failed once
+                    // it returns, if it has exceeded the timeout meanwhile.
+                    Either::Left((_, _)) if started_at.elapsed() > timeout => {
+                        None
+                    }
+                    Either::Left((res, _)) => Some(res),
+                    Either::Right(((), _)) => None,
+                }
+            } else {
+                Some(step_fut.await)
+            };
+            // Joined threads are awaited even if the step itself has panicked
+            // or timed out, so they never outlive it. Their panics have
+            // happened on other threads, so have no recorded location.
+            let joined = joined_threads
+                .join()
                 .await
-            {
-                Ok(()) => Ok((Some(captures), loc, Some(world))),
-                Err(e) => {
-                    let e = event::StepError::Panic(e.into());
+                .map_err(|e| event::PanicDetails::new(e.into()));
+            match res.map(|r| r.and(joined)) {
+                Some(Ok(())) => Ok((Some(captures), loc, Some(world))),
+                Some(Err(details)) if details.payload.is::<step::Pending>() => {
+                    let e = event::StepError::Pending;
+                    Err((e, Some(captures), loc, Some(world)))
+                }
+                Some(Err(details)) => {
+                    let e = event::StepError::Panic(details);
+                    Err((e, Some(captures), loc, Some(world)))
+                }
+                None => {
+                    let e = event::StepError::Timeout(
+                        self.step_timeout.unwrap_or_default(),
+                    );
                     Err((e, Some(captures), loc, Some(world)))
                 }
             }
@@ -1604,9 +3030,6 @@ where
         if let Some((waiter, id)) = waiter.zip(span_id) {
             waiter.wait_for_span_close(id).then_yield().await;
         }
-        #[cfg(not(feature = "tracing"))]
-        let _ = scenario_id;
-
         match result {
             Ok((Some(captures), loc, Some(world))) => {
                 self.send_event(passed(step, captures, loc));
@@ -1616,6 +3039,21 @@ where
                 self.send_event(skipped(step));
                 Err(ExecutionFailure::StepSkipped(world))
             }
+            Err((event::StepError::Pending, _, loc, world)) => {
+                self.send_event(pending(step, loc));
+                Err(ExecutionFailure::StepSkipped(world))
+            }
+            Err((event::StepError::Panic(details), _, loc, world))
+                if details.payload.is::<step::Skip>() =>
+            {
+                let reason = details
+                    .payload
+                    .downcast_ref::<step::Skip>()
+                    .map(|skip| skip.reason.clone())
+                    .unwrap_or_default();
+                self.send_event(skipped_reason(step, loc, reason));
+                Err(ExecutionFailure::StepSkipped(world))
+            }
             Err((err, captures, loc, world)) => {
                 Err(ExecutionFailure::StepPanicked {
                     world,
@@ -1720,7 +3158,8 @@ where
         }
     }
 
-    /// Executes the [`HookType::After`], if present.
+    /// Executes the [`HookType::After`], if present, along with the
+    /// [`World::after_scenario()`].
     ///
     /// Doesn't emit any events, see [`Self::emit_failed_events()`] for more
     /// details.
@@ -1738,16 +3177,24 @@ where
         (Option<W>, Option<AfterHookEventsMeta>),
         (Option<W>, AfterHookEventsMeta, Info),
     > {
-        if let Some(hook) = self.after_hook.as_ref() {
+        let mut hooks = self
+            .collection
+            .after_hooks(feature, rule.map(AsRef::as_ref), scenario)
+            .peekable();
+
+        let has_hooks = self.after_hook.is_some() || hooks.peek().is_some();
+        if has_hooks || world.is_some() {
             let fut = async {
-                (hook)(
-                    feature.as_ref(),
-                    rule.as_ref().map(AsRef::as_ref),
-                    scenario.as_ref(),
-                    &ev,
-                    world.as_mut(),
-                )
-                .await;
+                let rule = rule.as_ref().map(AsRef::as_ref);
+                if let Some(w) = world.as_mut() {
+                    w.after_scenario(feature, rule, scenario, &ev).await;
+                }
+                for hook in hooks {
+                    (hook)(feature, rule, scenario, &ev, world.as_mut()).await;
+                }
+                if let Some(hook) = self.after_hook.as_ref() {
+                    (hook)(feature, rule, scenario, &ev, world.as_mut()).await;
+                }
             };
 
             let started = event::Metadata::new(());
@@ -1774,7 +3221,9 @@ where
             let meta = AfterHookEventsMeta { started, finished };
 
             match res {
-                Ok(()) => Ok((world, Some(meta))),
+                // No events are emitted for the `World::after_scenario()`
+                // alone, unless it fails.
+                Ok(()) => Ok((world, has_hooks.then_some(meta))),
                 Err(info) => Err((world, meta, info.into())),
             }
         } else {
@@ -1797,11 +3246,10 @@ where
         err: Option<Info>,
         retries: Option<Retries>,
     ) {
-        debug_assert_eq!(
-            self.after_hook.is_some(),
-            meta.is_some(),
-            "`AfterHookEventsMeta` is not passed, despite `self.after_hook` \
-             being set",
+        debug_assert!(
+            err.is_none() || meta.is_some(),
+            "`AfterHookEventsMeta` is not passed, despite `HookType::After` \
+             being failed",
         );
 
         if let Some(meta) = meta {
@@ -1857,13 +3305,47 @@ where
         );
     }
 
+    /// Forms the [`result::ScenarioResult`] of the finished [`Scenario`] out
+    /// of its already sent events.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    fn scenario_result(
+        &self,
+        feature: &Arc<gherkin::Feature>,
+        rule: Option<&Arc<gherkin::Rule>>,
+        scenario: &Arc<gherkin::Scenario>,
+        retries: Option<Retries>,
+    ) -> Arc<result::ScenarioResult> {
+        Arc::new(
+            self.results
+                .borrow_mut()
+                .finish_scenario(feature, rule, scenario, retries),
+        )
+    }
+
+    /// Forms the [`Feature::Finished`] event out of the already sent events of
+    /// the given [`Feature`].
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Feature::Finished`]: event::Feature::Finished
+    fn feature_finished(
+        &self,
+        feature: Arc<gherkin::Feature>,
+    ) -> event::Cucumber<W> {
+        let res = self.results.borrow_mut().finish_feature(&feature);
+        event::Cucumber::feature_finished(feature, Arc::new(res))
+    }
+
     /// Notifies with the given [`Cucumber`] event.
     ///
     /// [`Cucumber`]: event::Cucumber
     fn send_event(&self, event: event::Cucumber<W>) {
+        self.forward_step_logs();
+        let event = Event::new(event);
+        self.results.borrow_mut().handle_event(&event);
         // If the receiver end is dropped, then no one listens for events,
         // so we can just ignore it.
-        drop(self.event_sender.unbounded_send(Ok(Event::new(event))));
+        drop(self.event_sender.unbounded_send(Ok(event)));
     }
 
     /// Notifies with the given [`Cucumber`] event along with its [`Metadata`].
@@ -1875,9 +3357,12 @@ where
         event: event::Cucumber<W>,
         meta: event::Metadata,
     ) {
+        self.forward_step_logs();
+        let event = meta.wrap(event);
+        self.results.borrow_mut().handle_event(&event);
         // If the receiver end is dropped, then no one listens for events,
         // so we can just ignore it.
-        drop(self.event_sender.unbounded_send(Ok(meta.wrap(event))));
+        drop(self.event_sender.unbounded_send(Ok(event)));
     }
 
     /// Notifies with the given [`Cucumber`] events.
@@ -1887,14 +3372,129 @@ where
         &self,
         events: impl IntoIterator<Item = event::Cucumber<W>>,
     ) {
+        self.forward_step_logs();
         for v in events {
+            let event = Event::new(v);
+            self.results.borrow_mut().handle_event(&event);
             // If the receiver end is dropped, then no one listens for events,
             // so we can just stop from here.
-            if self.event_sender.unbounded_send(Ok(Event::new(v))).is_err() {
+            if self.event_sender.unbounded_send(Ok(event)).is_err() {
                 break;
             }
         }
     }
+
+    /// Notifies with all the pending messages logged via
+    /// [`step::Context::log()`], so they always precede the next event of
+    /// their [`Scenario`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    fn forward_step_logs(&self) {
+        while let Some(log) = self.step_logs.try_next() {
+            if let Some(ev) = self.step_logs.to_event(log) {
+                // If the receiver end is dropped, then no one listens for
+                // events, so we can just ignore it.
+                drop(self.event_sender.unbounded_send(Ok(Event::new(ev))));
+            }
+        }
+    }
+
+    /// Notifies with the messages logged via [`step::Context::log()`] as soon
+    /// as they're sent, while [`Step`]s are still running.
+    ///
+    /// Never resolves.
+    ///
+    /// [`Step`]: gherkin::Step
+    async fn forward_step_logs_live(&self) {
+        loop {
+            let Some(log) =
+                future::poll_fn(|cx| self.step_logs.poll_next(cx)).await
+            else {
+                // `StepLogs::sender` is never dropped, so this is unreachable
+                // in practice.
+                return future::pending().await;
+            };
+            if let Some(ev) = self.step_logs.to_event(log) {
+                // If the receiver end is dropped, then no one listens for
+                // events, so we can just ignore it.
+                drop(self.event_sender.unbounded_send(Ok(Event::new(ev))));
+            }
+        }
+    }
+}
+
+/// [`Scenario`] identity, which the messages logged via [`step::Context::log()`]
+/// are forwarded to.
+///
+/// [`Scenario`]: gherkin::Scenario
+type LogRoute = (
+    Arc<gherkin::Feature>,
+    Option<Arc<gherkin::Rule>>,
+    Arc<gherkin::Scenario>,
+    Option<Retries>,
+);
+
+/// Forwarder of the messages logged via [`step::Context::log()`] as
+/// [`event::Scenario::Log`]s of the currently running [`Scenario`]s.
+///
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Debug)]
+struct StepLogs {
+    /// [`step::LogSender`] handed to every [`step::Context`].
+    sender: step::LogSender,
+
+    /// Receiver of the messages sent via the [`StepLogs::sender`].
+    receiver: RefCell<mpsc::UnboundedReceiver<(ScenarioId, String)>>,
+
+    /// [`LogRoute`]s of the currently running [`Scenario`]s.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    scenarios: RefCell<HashMap<ScenarioId, LogRoute>>,
+}
+
+impl StepLogs {
+    /// Creates new [`StepLogs`] without any running [`Scenario`]s.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    fn new() -> Self {
+        let (sender, receiver) = mpsc::unbounded();
+        Self {
+            sender,
+            receiver: RefCell::new(receiver),
+            scenarios: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the next pending logged message, if any.
+    fn try_next(&self) -> Option<(ScenarioId, String)> {
+        self.receiver.borrow_mut().try_recv().ok()
+    }
+
+    /// Polls for the next logged message.
+    fn poll_next(
+        &self,
+        cx: &mut task::Context<'_>,
+    ) -> task::Poll<Option<(ScenarioId, String)>> {
+        self.receiver.borrow_mut().poll_next_unpin(cx)
+    }
+
+    /// Converts the given logged message into an [`event::Scenario::Log`], if
+    /// its [`Scenario`] is still running.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    fn to_event<W>(
+        &self,
+        (id, msg): (ScenarioId, String),
+    ) -> Option<event::Cucumber<W>> {
+        self.scenarios.borrow().get(&id).map(|(f, r, s, retries)| {
+            event::Cucumber::scenario(
+                Arc::clone(f),
+                r.clone(),
+                Arc::clone(s),
+                event::Scenario::Log(msg).with_retries(*retries),
+            )
+        })
+    }
 }
 
 /// ID of a [`Scenario`], uniquely identifying it.
@@ -2013,49 +3613,52 @@ impl FinishedRulesAndFeatures {
         })
     }
 
-    /// Marks [`Feature`]'s [`Scenario`] as finished and returns
-    /// [`Feature::Finished`] event if no [`Scenario`]s left.
+    /// Marks [`Feature`]'s [`Scenario`] as finished and returns whether no
+    /// [`Scenario`]s left, so the [`Feature`] is finished too.
     ///
     /// [`Feature`]: gherkin::Feature
-    /// [`Feature::Finished`]: event::Feature::Finished
     /// [`Scenario`]: gherkin::Scenario
-    fn feature_scenario_finished<W>(
+    fn feature_scenario_finished(
         &mut self,
-        feature: Arc<gherkin::Feature>,
+        feature: &Arc<gherkin::Feature>,
         is_retried: bool,
-    ) -> Option<event::Cucumber<W>> {
+    ) -> bool {
         if is_retried {
-            return None;
+            return false;
         }
 
         let finished_scenarios = self
             .features_scenarios_count
-            .get_mut(&feature)
+            .get_mut(feature)
             .unwrap_or_else(|| panic!("No Feature {}", feature.name));
         *finished_scenarios += 1;
         let scenarios = feature.count_scenarios();
-        (scenarios == *finished_scenarios).then(|| {
-            _ = self.features_scenarios_count.remove(&feature);
-            event::Cucumber::feature_finished(feature)
-        })
+        let is_finished = scenarios == *finished_scenarios;
+        if is_finished {
+            _ = self.features_scenarios_count.remove(feature);
+        }
+        is_finished
     }
 
     /// Marks all the unfinished [`Rule`]s and [`Feature`]s as finished, and
-    /// returns all the appropriate finished events.
+    /// returns all the appropriate [`Rule::Finished`] events along with the
+    /// finished [`Feature`]s.
     ///
     /// [`Feature`]: gherkin::Feature
     /// [`Rule`]: gherkin::Rule
+    /// [`Rule::Finished`]: event::Rule::Finished
     fn finish_all_rules_and_features<W>(
         &mut self,
-    ) -> impl Iterator<Item = event::Cucumber<W>> + '_ {
-        self.rule_scenarios_count
-            .drain()
-            .map(|((feat, rule), _)| event::Cucumber::rule_finished(feat, rule))
-            .chain(
-                self.features_scenarios_count
-                    .drain()
-                    .map(|(feat, _)| event::Cucumber::feature_finished(feat)),
-            )
+    ) -> (
+        impl Iterator<Item = event::Cucumber<W>> + '_,
+        impl Iterator<Item = Arc<gherkin::Feature>> + '_,
+    ) {
+        (
+            self.rule_scenarios_count.drain().map(|((feat, rule), _)| {
+                event::Cucumber::rule_finished(feat, rule)
+            }),
+            self.features_scenarios_count.drain().map(|(feat, _)| feat),
+        )
     }
 
     /// Marks [`Scenario`]s as started and returns [`Rule::Started`] and
@@ -2121,6 +3724,45 @@ impl FinishedRulesAndFeatures {
     }
 }
 
+/// [`Scenario`] stored in [`Features`].
+///
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Clone, Debug)]
+enum StoredScenario {
+    /// Index of the [`Scenario`] in its [`Rule`] or [`Feature`], so it's not
+    /// cloned out of them until taken for execution.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    Index(usize),
+
+    /// Already taken [`Scenario`] (a retried one, for example).
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    Taken(Arc<gherkin::Scenario>),
+}
+
+impl StoredScenario {
+    /// Takes this [`StoredScenario`] out of the provided [`Rule`] or
+    /// [`Feature`] it belongs to.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    fn take(
+        self,
+        feature: &gherkin::Feature,
+        rule: Option<&gherkin::Rule>,
+    ) -> Arc<gherkin::Scenario> {
+        match self {
+            Self::Index(i) => Arc::new(
+                rule.map_or(&feature.scenarios, |r| &r.scenarios)[i].clone(),
+            ),
+            Self::Taken(scenario) => scenario,
+        }
+    }
+}
+
 /// [`Scenario`]s storage.
 ///
 /// [`Scenario`]: gherkin::Scenario
@@ -2130,7 +3772,7 @@ type Scenarios = HashMap<
         ScenarioId,
         Arc<gherkin::Feature>,
         Option<Arc<gherkin::Rule>>,
-        Arc<gherkin::Scenario>,
+        StoredScenario,
         Option<RetryOptionsWithDeadline>,
     )>,
 >;
@@ -2142,7 +3784,7 @@ type InsertedScenarios = HashMap<
         ScenarioId,
         Arc<gherkin::Feature>,
         Option<Arc<gherkin::Rule>>,
-        Arc<gherkin::Scenario>,
+        StoredScenario,
         Option<RetryOptions>,
     )>,
 >;
@@ -2160,9 +3802,31 @@ struct Features {
     ///
     /// [`Feature`]: gherkin::Feature
     finished: Arc<AtomicBool>,
+
+    /// Number of repetitions of all the [`Scenario`]s left to run.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    repeats_left: Arc<AtomicUsize>,
+
+    /// Initially inserted [`Scenario`]s, to be inserted again on each
+    /// repetition.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    repeated: Arc<Mutex<InsertedScenarios>>,
 }
 
 impl Features {
+    /// Creates a new [`Features`] storage, running all the inserted
+    /// [`Scenario`]s the provided number of `times`.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    fn new(times: usize) -> Self {
+        Self {
+            repeats_left: Arc::new(AtomicUsize::new(times.saturating_sub(1))),
+            ..Self::default()
+        }
+    }
+
     /// Splits [`Feature`] into [`Scenario`]s, sorts by [`ScenarioType`] and
     /// stores them.
     ///
@@ -2174,6 +3838,7 @@ impl Features {
         which_scenario: &Which,
         retry: &RetryOptionsFn,
         cli: &Cli,
+        shuffler: Option<&mut Shuffler>,
     ) where
         Which: Fn(
                 &gherkin::Feature,
@@ -2182,33 +3847,104 @@ impl Features {
             ) -> ScenarioType
             + 'static,
     {
-        let local = feature
+        // All the `Scenario`s share the same `Feature` and `Rule`s, so they're
+        // allocated only once, regardless of the number of `Scenario`s (which
+        // may be huge for expanded `Examples`), while each `Scenario` itself is
+        // cloned out of them only once it's taken for execution.
+        let feature = Arc::new(feature);
+        let rules = feature
+            .rules
+            .iter()
+            .map(|r| Arc::new(r.clone()))
+            .collect::<Vec<_>>();
+
+        let mut scenarios = feature
             .scenarios
             .iter()
-            .map(|s| (&feature, None, s))
-            .chain(feature.rules.iter().flat_map(|r| {
+            .enumerate()
+            .map(|(i, s)| (None, i, s))
+            .chain(rules.iter().flat_map(|r| {
                 r.scenarios
                     .iter()
-                    .map(|s| (&feature, Some(r), s))
-                    .collect::<Vec<_>>()
+                    .enumerate()
+                    .map(move |(i, s)| (Some(r), i, s))
             }))
-            .map(|(feat, rule, scenario)| {
-                let retries = retry(feat, rule, scenario, cli);
+            .collect::<Vec<_>>();
+        if let Some(sh) = shuffler {
+            sh.shuffle(&mut scenarios);
+        }
+
+        let local = scenarios
+            .into_iter()
+            .map(|(rule, i, scenario)| {
+                let rule_ref = rule.map(AsRef::as_ref);
+                let which = which_scenario(&feature, rule_ref, scenario);
+                let retries = retry(&feature, rule_ref, scenario, cli);
                 (
-                    ScenarioId::new(),
-                    Arc::new(feat.clone()),
-                    rule.map(|r| Arc::new(r.clone())),
-                    Arc::new(scenario.clone()),
-                    retries,
+                    which,
+                    (
+                        ScenarioId::new(),
+                        Arc::clone(&feature),
+                        rule.map(Arc::clone),
+                        StoredScenario::Index(i),
+                        retries,
+                    ),
                 )
             })
-            .into_group_map_by(|(_, f, r, s, _)| {
-                which_scenario(f, r.as_ref().map(AsRef::as_ref), s)
-            });
+            .into_group_map();
+
+        if self.repeats_left.load(Ordering::SeqCst) > 0 {
+            let mut repeated = self.repeated.lock().await;
+            for (which, values) in &local {
+                repeated.entry(*which).or_default().extend(values.clone());
+            }
+        }
 
         self.insert_scenarios(local).await;
     }
 
+    /// Inserts the initially inserted [`Scenario`]s once again, if there are
+    /// repetitions left.
+    ///
+    /// Returns `false` if there are no repetitions left.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    async fn repeat(&self) -> bool {
+        if self
+            .repeats_left
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |left| {
+                left.checked_sub(1)
+            })
+            .is_err()
+        {
+            return false;
+        }
+
+        let scenarios = self
+            .repeated
+            .lock()
+            .await
+            .iter()
+            .map(|(which, values)| {
+                let values = values
+                    .iter()
+                    .map(|(_, f, r, s, ret)| {
+                        (
+                            ScenarioId::new(),
+                            Arc::clone(f),
+                            r.clone(),
+                            s.clone(),
+                            *ret,
+                        )
+                    })
+                    .collect();
+                (*which, values)
+            })
+            .collect();
+        self.insert_scenarios(scenarios).await;
+        true
+    }
+
     /// Inserts the provided retried [`Scenario`] into this [`Features`]
     /// storage.
     ///
@@ -2224,7 +3960,13 @@ impl Features {
         self.insert_scenarios(
             iter::once((
                 scenario_ty,
-                vec![(ScenarioId::new(), feature, rule, scenario, retries)],
+                vec![(
+                    ScenarioId::new(),
+                    feature,
+                    rule,
+                    StoredScenario::Taken(scenario),
+                    retries,
+                )],
             ))
             .collect(),
         )
@@ -2366,7 +4108,13 @@ impl Features {
                     drain(storage, Concurrent, max_concurrent_scenarios)
                 })
             })
-            .unwrap_or_default();
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(id, f, r, s, ty, ret)| {
+                let s = s.take(&f, r.as_deref());
+                (id, f, r, s, ty, ret)
+            })
+            .collect();
 
         (scenarios, min_dur)
     }
@@ -2391,8 +4139,42 @@ impl Features {
     }
 }
 
+thread_local! {
+    /// Location and [`Backtrace`] of the last panic happened on this thread,
+    /// recorded by the panic hook of [`execute()`].
+    static LAST_PANIC: RefCell<
+        Option<(Option<event::PanicLocation>, Option<Arc<Backtrace>>)>,
+    > = const { RefCell::new(None) };
+}
+
+/// Wraps the given `fut` to clear the [`LAST_PANIC`] before each of its polls,
+/// so the recorded panic (if any) is always the one of its last poll, and never
+/// a stale one, caught elsewhere before.
+async fn clearing_last_panic<F: Future>(fut: F) -> F::Output {
+    pin_mut!(fut);
+    future::poll_fn(|cx| {
+        LAST_PANIC.with(|last| *last.borrow_mut() = None);
+        fut.as_mut().poll(cx)
+    })
+    .await
+}
+
+/// Creates [`event::PanicDetails`] out of the given caught panic `payload`,
+/// along with its location and [`Backtrace`] recorded by the panic hook of
+/// [`execute()`].
+fn panic_details(payload: Box<dyn Any + Send>) -> event::PanicDetails {
+    let (location, backtrace) = LAST_PANIC
+        .with(|last| last.borrow_mut().take())
+        .unwrap_or_default();
+    event::PanicDetails {
+        payload: payload.into(),
+        location,
+        backtrace,
+    }
+}
+
 /// Coerces the given `value` into a type-erased [`Info`].
-fn coerce_into_info<T: std::any::Any + Send + 'static>(val: T) -> Info {
+fn coerce_into_info<T: Any + Send + 'static>(val: T) -> Info {
     Arc::new(val)
 }
 
@@ -2417,7 +4199,7 @@ enum ExecutionFailure<World> {
         meta: event::Metadata,
     },
 
-    /// [`Step`] was skipped.
+    /// [`Step`] was skipped or pending.
     ///
     /// [`Step`]: gherkin::Step.
     StepSkipped(Option<World>),
@@ -2550,6 +4332,11 @@ Feature: only scenarios
                 retry: None,
                 retry_after: None,
                 retry_tag_filter: None,
+                step_timeout: None,
+                dry_run: false,
+                order: None,
+                repeat: None,
+                report_unused_steps: false,
             };
             let f = gherkin::Feature::parse(FEATURE, GherkinEnv::default())
                 .expect("failed to parse feature");
@@ -2608,6 +4395,11 @@ Feature: only scenarios
                 retry: Some(7),
                 retry_after: None,
                 retry_tag_filter: None,
+                step_timeout: None,
+                dry_run: false,
+                order: None,
+                repeat: None,
+                report_unused_steps: false,
             };
             let f = gherkin::Feature::parse(FEATURE, GherkinEnv::default())
                 .expect("failed to parse feature");
@@ -2672,6 +4464,11 @@ Feature: only scenarios
                 retry: Some(7),
                 retry_after: Some(parse_duration("5s").unwrap()),
                 retry_tag_filter: None,
+                step_timeout: None,
+                dry_run: false,
+                order: None,
+                repeat: None,
+                report_unused_steps: false,
             };
             let f = gherkin::Feature::parse(FEATURE, GherkinEnv::default())
                 .expect("failed to parse feature");
@@ -2736,6 +4533,11 @@ Feature: only scenarios
                 retry: Some(7),
                 retry_after: None,
                 retry_tag_filter: Some("@retry".parse().unwrap()),
+                step_timeout: None,
+                dry_run: false,
+                order: None,
+                repeat: None,
+                report_unused_steps: false,
             };
             let f = gherkin::Feature::parse(FEATURE, GherkinEnv::default())
                 .expect("failed to parse feature");
@@ -2794,6 +4596,11 @@ Feature: only scenarios
                 retry: Some(7),
                 retry_after: Some(parse_duration("5s").unwrap()),
                 retry_tag_filter: Some("@retry".parse().unwrap()),
+                step_timeout: None,
+                dry_run: false,
+                order: None,
+                repeat: None,
+                report_unused_steps: false,
             };
             let f = gherkin::Feature::parse(FEATURE, GherkinEnv::default())
                 .expect("failed to parse feature");
@@ -2901,6 +4708,11 @@ Feature: only scenarios
                 retry: None,
                 retry_after: None,
                 retry_tag_filter: None,
+                step_timeout: None,
+                dry_run: false,
+                order: None,
+                repeat: None,
+                report_unused_steps: false,
             };
             let f = gherkin::Feature::parse(FEATURE, GherkinEnv::default())
                 .expect("failed to parse feature");
@@ -3059,6 +4871,11 @@ Feature: only scenarios
                 retry: Some(7),
                 retry_after: Some(parse_duration("5s").unwrap()),
                 retry_tag_filter: Some("@retry".parse().unwrap()),
+                step_timeout: None,
+                dry_run: false,
+                order: None,
+                repeat: None,
+                report_unused_steps: false,
             };
             let f = gherkin::Feature::parse(FEATURE, GherkinEnv::default())
                 .expect("failed to parse feature");
@@ -3286,6 +5103,11 @@ Feature: only scenarios
                 retry: None,
                 retry_after: None,
                 retry_tag_filter: None,
+                step_timeout: None,
+                dry_run: false,
+                order: None,
+                repeat: None,
+                report_unused_steps: false,
             };
             let f = gherkin::Feature::parse(FEATURE, GherkinEnv::default())
                 .unwrap_or_else(|e| panic!("failed to parse feature: {e}"));
@@ -3500,6 +5322,11 @@ Feature: only scenarios
                 retry: Some(7),
                 retry_after: Some(parse_duration("5s").unwrap()),
                 retry_tag_filter: Some("@retry".parse().unwrap()),
+                step_timeout: None,
+                dry_run: false,
+                order: None,
+                repeat: None,
+                report_unused_steps: false,
             };
             let f = gherkin::Feature::parse(FEATURE, GherkinEnv::default())
                 .expect("failed to parse feature");
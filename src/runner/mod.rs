@@ -21,7 +21,7 @@ use futures::Stream;
 use crate::{event, parser, Event};
 
 #[doc(inline)]
-pub use self::basic::{Basic, ScenarioType};
+pub use self::basic::{Basic, ScenarioType, StopHandle};
 
 /// Executor of [`Parser`] output producing [`Cucumber`] events for [`Writer`].
 ///
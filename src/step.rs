@@ -14,49 +14,212 @@
 //! [`Step`]: gherkin::Step
 
 use std::{
+    any::{self, Any, TypeId},
+    borrow::Borrow,
     cmp::Ordering,
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     fmt,
     hash::{Hash, Hasher},
-    iter,
+    iter, mem,
+    panic::{self, AssertUnwindSafe},
+    str::FromStr,
+    sync::{Arc, Mutex, PoisonError},
+    thread,
 };
 
-use derive_more::{Deref, DerefMut, Display, Error};
-use futures::future::LocalBoxFuture;
-use gherkin::StepType;
-use regex::Regex;
+use derive_more::{Deref, Display, Error};
+use futures::{
+    channel::{mpsc, oneshot},
+    future::{self, LocalBoxFuture},
+};
+use gherkin::{tagexpr::TagOperation, StepType};
+use itertools::Itertools as _;
+use linked_hash_map::LinkedHashMap;
+use once_cell::sync::OnceCell;
+use regex::{Regex, RegexSet};
+use sealed::sealed;
+
+use crate::{
+    diagnostics::debug, event, runner::basic::ScenarioId, tag::Ext as _,
+};
 
 /// Alias for a [`gherkin::Step`] function that returns a [`LocalBoxFuture`].
 pub type Step<World> =
     for<'a> fn(&'a mut World, Context) -> LocalBoxFuture<'a, ()>;
 
-/// Alias for a [`Step`] with [`regex::CaptureLocations`], [`Location`] and
+/// Alias for a [`Step`] function of any kind, stored in a [`Collection`]:
+/// either a plain [`fn`] or a closure capturing its environment (like a
+/// configuration loaded on startup).
+pub type StepFn<World> = Arc<
+    dyn for<'a> Fn(&'a mut World, Context) -> LocalBoxFuture<'a, ()>
+        + Send
+        + Sync,
+>;
+
+/// Alias for a [`StepFn`] with [`regex::CaptureLocations`], [`Location`] and
 /// [`Context`] returned by [`Collection::find()`].
 pub type WithContext<'me, World> = (
-    &'me Step<World>,
+    &'me StepFn<World>,
     regex::CaptureLocations,
     Option<Location>,
     Context,
 );
 
-/// Collection of [`Step`]s.
+/// Alias for a [`Collection`] hook executed on each [`Scenario`] before running
+/// all its [`Step`]s, including [`Background`] ones.
+///
+/// [`Background`]: gherkin::Background
+/// [`Scenario`]: gherkin::Scenario
+/// [`Step`]: gherkin::Step
+pub type BeforeHook<World> = for<'a> fn(
+    &'a gherkin::Feature,
+    Option<&'a gherkin::Rule>,
+    &'a gherkin::Scenario,
+    &'a mut World,
+) -> LocalBoxFuture<'a, ()>;
+
+/// Alias for a [`Collection`] hook executed on each [`Scenario`] after running
+/// all its [`Step`]s.
+///
+/// [`Scenario`]: gherkin::Scenario
+/// [`Step`]: gherkin::Step
+pub type AfterHook<World> = for<'a> fn(
+    &'a gherkin::Feature,
+    Option<&'a gherkin::Rule>,
+    &'a gherkin::Scenario,
+    &'a event::ScenarioFinished,
+    Option<&'a mut World>,
+) -> LocalBoxFuture<'a, ()>;
+
+/// Provider of reusable [`Step`]s and hooks, registering them into a
+/// [`Collection`].
+///
+/// Allows to publish a library of [`Step`]s as a separate crate, not coupled
+/// with any concrete [`World`]: a [`Provider`] is usually implemented for any
+/// `World` implementing some capability trait, defined by the library itself.
+///
+/// ```rust
+/// # use cucumber::step;
+/// # use futures::{future::LocalBoxFuture, FutureExt as _};
+/// # use regex::Regex;
+/// #
+/// /// Capability required by the provided `Step`s.
+/// pub trait HasCounter {
+///     fn counter(&mut self) -> &mut usize;
+/// }
+///
+/// /// `Step`s library.
+/// pub struct CounterSteps;
+///
+/// impl<W: HasCounter + 'static> step::Provider<W> for CounterSteps {
+///     fn register(&self, steps: step::Collection<W>) -> step::Collection<W> {
+///         steps.given(None, Regex::new("^a counter$").unwrap(), increment)
+///     }
+/// }
+///
+/// fn increment<W: HasCounter>(
+///     world: &mut W,
+///     _: step::Context,
+/// ) -> LocalBoxFuture<'_, ()> {
+///     async move { *world.counter() += 1 }.boxed_local()
+/// }
+/// ```
+///
+/// [`World`]: crate::World
+pub trait Provider<World> {
+    /// Registers the provided [`Step`]s and hooks into the given
+    /// [`Collection`].
+    #[must_use]
+    fn register(&self, steps: Collection<World>) -> Collection<World>;
+}
+
+impl<World> Provider<World> for Collection<World> {
+    fn register(&self, steps: Self) -> Self {
+        steps.append(self.clone())
+    }
+}
+
+/// Collection of [`Step`]s and hooks accompanying them.
+///
+/// Every [`Step`] has to match with exactly 1 [`Regex`], unless one of them is
+/// a literal definition of the exact [`Step`] text (like `"a step"` in
+/// `#[given("a step")]`), which takes precedence over the others. [`Step`]s
+/// are stored and tried in their registration order, so the candidates of an
+/// ambiguous match are always reported in the same order.
+///
+/// Candidate [`Regex`]es of the [`Step`]s registered via `#[given]`, `#[when]`,
+/// `#[then]` and `#[any]` attributes are found in a single pass with a
+/// [`RegexSet`], which is built from their string representations. Any other
+/// [`Regex`]es are checked one by one, as may have flags set via a
+/// [`RegexBuilder`], which are not reflected in their string representations.
+///
+/// Anchored versions of all the [`Regex`]es are precompiled once they're
+/// registered, used for checking whether they match the whole
+/// [`gherkin::Step`] text, when their leftmost-first match doesn't (because of
+/// lazy quantifiers or alternations, for example).
 ///
-/// Every [`Step`] has to match with exactly 1 [`Regex`].
+/// Hooks are stored in their registration order, optionally scoped by a
+/// [tag expression][1], so a reusable step library is able to ship the setup
+/// and teardown its [`Step`]s require.
+///
+/// [1]: https://cucumber.io/docs/cucumber/api#tag-expressions
+/// [`RegexBuilder`]: regex::RegexBuilder
 pub struct Collection<World> {
     /// Collection of [Given] [`Step`]s.
     ///
     /// [Given]: https://cucumber.io/docs/gherkin/reference#given
-    given: HashMap<(HashableRegex, Option<Location>), Step<World>>,
+    given: LinkedHashMap<(HashableRegex, Option<Location>), StepFn<World>>,
 
     /// Collection of [When] [`Step`]s.
     ///
     /// [When]: https://cucumber.io/docs/gherkin/reference#when
-    when: HashMap<(HashableRegex, Option<Location>), Step<World>>,
+    when: LinkedHashMap<(HashableRegex, Option<Location>), StepFn<World>>,
 
     /// Collection of [Then] [`Step`]s.
     ///
     /// [Then]: https://cucumber.io/docs/gherkin/reference#then
-    then: HashMap<(HashableRegex, Option<Location>), Step<World>>,
+    then: LinkedHashMap<(HashableRegex, Option<Location>), StepFn<World>>,
+
+    /// Keys of the work-in-progress [`Step`]s, reported as [pending][1]
+    /// instead of being executed.
+    ///
+    /// [1]: event::Step::Pending
+    wip: HashSet<(StepType, HashableRegex, Option<Location>)>,
+
+    /// Keys of the generic [`Step`]s, matching [`gherkin::Step`]s of any
+    /// [`StepType`], so stored in the collections of all of them.
+    generic: HashSet<(HashableRegex, Option<Location>)>,
+
+    /// Keys of the [`Step`]s whose [`Regex`]es are known to be built out of
+    /// their string representations only, so may be found via a [`RegexSet`].
+    plain: HashSet<(HashableRegex, Option<Location>)>,
+
+    /// Precompiled anchored versions of the [`Step`]s [`Regex`]es (if they may
+    /// be built).
+    anchored: HashMap<(HashableRegex, Option<Location>), Option<Regex>>,
+
+    /// [`BeforeHook`]s ordered by their priority (the highest first) and then
+    /// by their registration order, optionally scoped by a [`TagOperation`].
+    before: Vec<(i32, Option<TagOperation>, BeforeHook<World>)>,
+
+    /// [`AfterHook`]s ordered by their priority (the highest first) and then
+    /// by their registration order, optionally scoped by a [`TagOperation`].
+    after: Vec<(i32, Option<TagOperation>, AfterHook<World>)>,
+
+    /// Indicator whether a [`Regex`] matching only a part of a
+    /// [`gherkin::Step`] text is considered as a match.
+    partial_matches: bool,
+
+    /// Indicator whether a [`Step`] with the same [`Regex`] as an already
+    /// present one replaces it, instead of being reported as a duplicate.
+    allow_overrides: bool,
+
+    /// [`Step`]s defined more than once, along with all their [`Location`]s.
+    duplicates: Vec<Duplicate>,
+
+    /// Lazily built [`Prefilters`] of the [`Step`]s, reset on any [`Step`]
+    /// addition.
+    prefilters: Prefilters,
 }
 
 impl<World> fmt::Debug for Collection<World> {
@@ -68,7 +231,7 @@ impl<World> fmt::Debug for Collection<World> {
                     .given
                     .iter()
                     .map(|(re, step)| (re, format!("{step:p}")))
-                    .collect::<HashMap<_, _>>(),
+                    .collect::<Vec<_>>(),
             )
             .field(
                 "when",
@@ -76,7 +239,7 @@ impl<World> fmt::Debug for Collection<World> {
                     .when
                     .iter()
                     .map(|(re, step)| (re, format!("{step:p}")))
-                    .collect::<HashMap<_, _>>(),
+                    .collect::<Vec<_>>(),
             )
             .field(
                 "then",
@@ -84,9 +247,32 @@ impl<World> fmt::Debug for Collection<World> {
                     .then
                     .iter()
                     .map(|(re, step)| (re, format!("{step:p}")))
-                    .collect::<HashMap<_, _>>(),
+                    .collect::<Vec<_>>(),
             )
-            .finish()
+            .field("wip", &self.wip)
+            .field("generic", &self.generic)
+            .field("plain", &self.plain)
+            .field("anchored", &self.anchored)
+            .field("partial_matches", &self.partial_matches)
+            .field("allow_overrides", &self.allow_overrides)
+            .field("duplicates", &self.duplicates)
+            .field(
+                "before",
+                &self
+                    .before
+                    .iter()
+                    .map(|(prio, tags, hook)| (prio, tags, format!("{hook:p}")))
+                    .collect::<Vec<_>>(),
+            )
+            .field(
+                "after",
+                &self
+                    .after
+                    .iter()
+                    .map(|(prio, tags, hook)| (prio, tags, format!("{hook:p}")))
+                    .collect::<Vec<_>>(),
+            )
+            .finish_non_exhaustive()
     }
 }
 
@@ -98,6 +284,16 @@ impl<World> Clone for Collection<World> {
             given: self.given.clone(),
             when: self.when.clone(),
             then: self.then.clone(),
+            wip: self.wip.clone(),
+            generic: self.generic.clone(),
+            plain: self.plain.clone(),
+            anchored: self.anchored.clone(),
+            before: self.before.clone(),
+            after: self.after.clone(),
+            partial_matches: self.partial_matches,
+            allow_overrides: self.allow_overrides,
+            duplicates: self.duplicates.clone(),
+            prefilters: self.prefilters.clone(),
         }
     }
 }
@@ -107,9 +303,19 @@ impl<World> Clone for Collection<World> {
 impl<World> Default for Collection<World> {
     fn default() -> Self {
         Self {
-            given: HashMap::new(),
-            when: HashMap::new(),
-            then: HashMap::new(),
+            given: LinkedHashMap::new(),
+            when: LinkedHashMap::new(),
+            then: LinkedHashMap::new(),
+            wip: HashSet::new(),
+            generic: HashSet::new(),
+            plain: HashSet::new(),
+            anchored: HashMap::new(),
+            before: Vec::new(),
+            after: Vec::new(),
+            partial_matches: false,
+            allow_overrides: false,
+            duplicates: Vec::new(),
+            prefilters: Prefilters::default(),
         }
     }
 }
@@ -129,12 +335,60 @@ impl<World> Collection<World> {
         mut self,
         loc: Option<Location>,
         regex: Regex,
-        step: Step<World>,
+        step: impl for<'a> Fn(&'a mut World, Context) -> LocalBoxFuture<'a, ()>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        self.insert(StepType::Given, loc, regex, Arc::new(step), false);
+        self
+    }
+
+    /// Adds a work-in-progress [Given] [`Step`] matching the given `regex`.
+    ///
+    /// The matched [`gherkin::Step`]s are reported as [pending][1] instead of
+    /// being executed, until the [`Step`] is registered via
+    /// [`Collection::given()`] instead.
+    ///
+    /// [1]: event::Step::Pending
+    /// [Given]: https://cucumber.io/docs/gherkin/reference#given
+    #[must_use]
+    pub fn given_wip(
+        mut self,
+        loc: Option<Location>,
+        regex: Regex,
+        step: impl for<'a> Fn(&'a mut World, Context) -> LocalBoxFuture<'a, ()>
+            + Send
+            + Sync
+            + 'static,
     ) -> Self {
-        _ = self.given.insert((regex.into(), loc), step);
+        self.insert(StepType::Given, loc, regex, Arc::new(step), true);
         self
     }
 
+    /// Adds a [Given] [`Step`] matching any of the given `regexes` (aliases).
+    ///
+    /// If the [`Location`] is specified, the aliases are treated as a single
+    /// definition, so matching several of them is not an ambiguity.
+    ///
+    /// [Given]: https://cucumber.io/docs/gherkin/reference#given
+    #[must_use]
+    pub fn given_aliases(
+        self,
+        loc: Option<Location>,
+        regexes: impl IntoIterator<Item = Regex>,
+        step: impl for<'a> Fn(&'a mut World, Context) -> LocalBoxFuture<'a, ()>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        let step: StepFn<World> = Arc::new(step);
+        regexes.into_iter().fold(self, |mut steps, regex| {
+            steps.insert(StepType::Given, loc, regex, Arc::clone(&step), false);
+            steps
+        })
+    }
+
     /// Adds a [When] [`Step`] matching the given `regex`.
     ///
     /// [When]: https://cucumber.io/docs/gherkin/reference#when
@@ -143,12 +397,60 @@ impl<World> Collection<World> {
         mut self,
         loc: Option<Location>,
         regex: Regex,
-        step: Step<World>,
+        step: impl for<'a> Fn(&'a mut World, Context) -> LocalBoxFuture<'a, ()>
+            + Send
+            + Sync
+            + 'static,
     ) -> Self {
-        _ = self.when.insert((regex.into(), loc), step);
+        self.insert(StepType::When, loc, regex, Arc::new(step), false);
         self
     }
 
+    /// Adds a work-in-progress [When] [`Step`] matching the given `regex`.
+    ///
+    /// The matched [`gherkin::Step`]s are reported as [pending][1] instead of
+    /// being executed, until the [`Step`] is registered via
+    /// [`Collection::when()`] instead.
+    ///
+    /// [1]: event::Step::Pending
+    /// [When]: https://cucumber.io/docs/gherkin/reference#when
+    #[must_use]
+    pub fn when_wip(
+        mut self,
+        loc: Option<Location>,
+        regex: Regex,
+        step: impl for<'a> Fn(&'a mut World, Context) -> LocalBoxFuture<'a, ()>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        self.insert(StepType::When, loc, regex, Arc::new(step), true);
+        self
+    }
+
+    /// Adds a [When] [`Step`] matching any of the given `regexes` (aliases).
+    ///
+    /// If the [`Location`] is specified, the aliases are treated as a single
+    /// definition, so matching several of them is not an ambiguity.
+    ///
+    /// [When]: https://cucumber.io/docs/gherkin/reference#when
+    #[must_use]
+    pub fn when_aliases(
+        self,
+        loc: Option<Location>,
+        regexes: impl IntoIterator<Item = Regex>,
+        step: impl for<'a> Fn(&'a mut World, Context) -> LocalBoxFuture<'a, ()>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        let step: StepFn<World> = Arc::new(step);
+        regexes.into_iter().fold(self, |mut steps, regex| {
+            steps.insert(StepType::When, loc, regex, Arc::clone(&step), false);
+            steps
+        })
+    }
+
     /// Adds a [Then] [`Step`] matching the given `regex`.
     ///
     /// [Then]: https://cucumber.io/docs/gherkin/reference#then
@@ -157,86 +459,1187 @@ impl<World> Collection<World> {
         mut self,
         loc: Option<Location>,
         regex: Regex,
-        step: Step<World>,
+        step: impl for<'a> Fn(&'a mut World, Context) -> LocalBoxFuture<'a, ()>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        self.insert(StepType::Then, loc, regex, Arc::new(step), false);
+        self
+    }
+
+    /// Adds a work-in-progress [Then] [`Step`] matching the given `regex`.
+    ///
+    /// The matched [`gherkin::Step`]s are reported as [pending][1] instead of
+    /// being executed, until the [`Step`] is registered via
+    /// [`Collection::then()`] instead.
+    ///
+    /// [1]: event::Step::Pending
+    /// [Then]: https://cucumber.io/docs/gherkin/reference#then
+    #[must_use]
+    pub fn then_wip(
+        mut self,
+        loc: Option<Location>,
+        regex: Regex,
+        step: impl for<'a> Fn(&'a mut World, Context) -> LocalBoxFuture<'a, ()>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        self.insert(StepType::Then, loc, regex, Arc::new(step), true);
+        self
+    }
+
+    /// Adds a [Then] [`Step`] matching any of the given `regexes` (aliases).
+    ///
+    /// If the [`Location`] is specified, the aliases are treated as a single
+    /// definition, so matching several of them is not an ambiguity.
+    ///
+    /// [Then]: https://cucumber.io/docs/gherkin/reference#then
+    #[must_use]
+    pub fn then_aliases(
+        self,
+        loc: Option<Location>,
+        regexes: impl IntoIterator<Item = Regex>,
+        step: impl for<'a> Fn(&'a mut World, Context) -> LocalBoxFuture<'a, ()>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        let step: StepFn<World> = Arc::new(step);
+        regexes.into_iter().fold(self, |mut steps, regex| {
+            steps.insert(StepType::Then, loc, regex, Arc::clone(&step), false);
+            steps
+        })
+    }
+
+    /// Adds a generic [`Step`] matching the given `regex`, which matches
+    /// [`gherkin::Step`]s of any [`StepType`].
+    ///
+    /// Generic [`Step`]s are considered only if no [Given], [When] or [Then]
+    /// [`Step`] of the matched [`gherkin::Step`]'s type matches it.
+    ///
+    /// [Given]: https://cucumber.io/docs/gherkin/reference#given
+    /// [Then]: https://cucumber.io/docs/gherkin/reference#then
+    /// [When]: https://cucumber.io/docs/gherkin/reference#when
+    #[must_use]
+    pub fn any(
+        mut self,
+        loc: Option<Location>,
+        regex: Regex,
+        step: impl for<'a> Fn(&'a mut World, Context) -> LocalBoxFuture<'a, ()>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        self.insert_generic(loc, regex, Arc::new(step), false);
+        self
+    }
+
+    /// Adds a work-in-progress generic [`Step`] matching the given `regex`.
+    ///
+    /// The matched [`gherkin::Step`]s are reported as [pending][1] instead of
+    /// being executed, until the [`Step`] is registered via
+    /// [`Collection::any()`] instead.
+    ///
+    /// [1]: event::Step::Pending
+    #[must_use]
+    pub fn any_wip(
+        mut self,
+        loc: Option<Location>,
+        regex: Regex,
+        step: impl for<'a> Fn(&'a mut World, Context) -> LocalBoxFuture<'a, ()>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        self.insert_generic(loc, regex, Arc::new(step), true);
+        self
+    }
+
+    /// Adds a generic [`Step`] matching any of the given `regexes` (aliases).
+    ///
+    /// If the [`Location`] is specified, the aliases are treated as a single
+    /// definition, so matching several of them is not an ambiguity.
+    #[must_use]
+    pub fn any_aliases(
+        self,
+        loc: Option<Location>,
+        regexes: impl IntoIterator<Item = Regex>,
+        step: impl for<'a> Fn(&'a mut World, Context) -> LocalBoxFuture<'a, ()>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        let step: StepFn<World> = Arc::new(step);
+        regexes.into_iter().fold(self, |mut steps, regex| {
+            steps.insert_generic(loc, regex, Arc::clone(&step), false);
+            steps
+        })
+    }
+
+    /// Adds a [`BeforeHook`], executed on each [`Scenario`] matching the given
+    /// [`TagOperation`] (or on every [`Scenario`], if it's [`None`]).
+    ///
+    /// [`BeforeHook`]s are executed in their registration order, after the
+    /// [`Cucumber::before()`] hook, if any, and before the
+    /// [`World::before_scenario()`]. Use [`Collection::before_with_priority()`]
+    /// to override the registration order.
+    ///
+    /// [`Cucumber::before()`]: crate::Cucumber::before()
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`World::before_scenario()`]: crate::World::before_scenario()
+    #[must_use]
+    pub fn before(
+        self,
+        tags: Option<TagOperation>,
+        hook: BeforeHook<World>,
+    ) -> Self {
+        self.before_with_priority(0, tags, hook)
+    }
+
+    /// Adds a [`BeforeHook`] with the given `priority`, executed on each
+    /// [`Scenario`] matching the given [`TagOperation`] (or on every
+    /// [`Scenario`], if it's [`None`]).
+    ///
+    /// [`BeforeHook`]s with a higher `priority` are executed earlier, while the
+    /// ones with the same `priority` are executed in their registration order.
+    /// [`Collection::before()`] registers [`BeforeHook`]s with `0` priority.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn before_with_priority(
+        mut self,
+        priority: i32,
+        tags: Option<TagOperation>,
+        hook: BeforeHook<World>,
+    ) -> Self {
+        insert_hook(&mut self.before, (priority, tags, hook));
+        self
+    }
+
+    /// Adds an [`AfterHook`], executed on each [`Scenario`] matching the given
+    /// [`TagOperation`] (or on every [`Scenario`], if it's [`None`]).
+    ///
+    /// [`AfterHook`]s are executed in the reversed registration order, after
+    /// the [`World::after_scenario()`] and before the [`Cucumber::after()`]
+    /// hook, if any. Use [`Collection::after_with_priority()`] to override the
+    /// registration order.
+    ///
+    /// [`Cucumber::after()`]: crate::Cucumber::after()
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`World::after_scenario()`]: crate::World::after_scenario()
+    #[must_use]
+    pub fn after(
+        self,
+        tags: Option<TagOperation>,
+        hook: AfterHook<World>,
     ) -> Self {
-        _ = self.then.insert((regex.into(), loc), step);
+        self.after_with_priority(0, tags, hook)
+    }
+
+    /// Adds an [`AfterHook`] with the given `priority`, executed on each
+    /// [`Scenario`] matching the given [`TagOperation`] (or on every
+    /// [`Scenario`], if it's [`None`]).
+    ///
+    /// `priority` has the same meaning as in
+    /// [`Collection::before_with_priority()`], but [`AfterHook`]s are executed
+    /// in the reversed order: the ones with a higher `priority` are executed
+    /// later, and the ones with the same `priority` in the reversed
+    /// registration order. [`Collection::after()`] registers [`AfterHook`]s
+    /// with `0` priority.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn after_with_priority(
+        mut self,
+        priority: i32,
+        tags: Option<TagOperation>,
+        hook: AfterHook<World>,
+    ) -> Self {
+        insert_hook(&mut self.after, (priority, tags, hook));
+        self
+    }
+
+    /// Makes [`Regex`]es matching only a part of a [`gherkin::Step`] text
+    /// (like `I have (\d+) cukes` matching `I have 5 cukes and 3 bananas`)
+    /// to be considered as matches, if `allow` is `true`.
+    ///
+    /// By default, a [`Regex`] has to match the whole [`gherkin::Step`] text,
+    /// as if it were anchored with `^` and `$`. If the leftmost-first match of
+    /// a [`Regex`] is shorter than the whole text (because of lazy quantifiers
+    /// or alternations, for example), its anchored version built out of its
+    /// string representation is checked instead, so the flags set via a
+    /// [`RegexBuilder`] should be specified inline (like `(?i)`) for such
+    /// [`Regex`]es.
+    ///
+    /// [`RegexBuilder`]: regex::RegexBuilder
+    #[must_use]
+    pub const fn partial_matches(mut self, allow: bool) -> Self {
+        self.partial_matches = allow;
+        self
+    }
+
+    /// Makes a [`Step`] with the same [`Regex`] as an already present one (of
+    /// the same [`StepType`]) to replace it, if `allow` is `true`.
+    ///
+    /// By default, such duplicates are kept and reported via an
+    /// [`event::Cucumber::DuplicateSteps`] once a run starts, as they either
+    /// silently overwrite each other, or are ambiguous (if defined in different
+    /// places), so usually indicate a mistake.
+    #[must_use]
+    pub const fn allow_overrides(mut self, allow: bool) -> Self {
+        self.allow_overrides = allow;
+        self
+    }
+
+    /// Merges the `other` [`Collection`] into this one.
+    ///
+    /// [`Step`]s of the `other` [`Collection`] take precedence over the same
+    /// ones of this [`Collection`], while its hooks are placed after the hooks
+    /// of this [`Collection`] having the same priority, so [`BeforeHook`]s are
+    /// executed in the merge order, and [`AfterHook`]s in the reversed one.
+    ///
+    /// [`Step`]s of the `other` [`Collection`] having the same [`Regex`]es as
+    /// the ones defined elsewhere in this [`Collection`] are reported as
+    /// duplicates, unless [`Collection::allow_overrides()`] is used.
+    #[must_use]
+    pub fn append(mut self, other: Self) -> Self {
+        let Self {
+            given,
+            when,
+            then,
+            wip,
+            generic,
+            mut plain,
+            anchored,
+            before,
+            after,
+            duplicates,
+            ..
+        } = other;
+        self.generic.extend(generic);
+        self.anchored.extend(anchored);
+        for dup in duplicates {
+            record_duplicate(&mut self.duplicates, dup);
+        }
+        for (ty, steps) in [
+            (StepType::Given, given),
+            (StepType::When, when),
+            (StepType::Then, then),
+        ] {
+            for ((re, loc), step) in steps {
+                _ = self.wip.remove(&(ty, re.clone(), loc));
+                let key = (re, loc);
+                if plain.remove(&key) {
+                    _ = self.plain.insert(key.clone());
+                } else {
+                    _ = self.plain.remove(&key);
+                }
+                self.insert_step(ty, key.0, loc, step);
+            }
+        }
+        self.wip.extend(wip);
+        for hook in before {
+            insert_hook(&mut self.before, hook);
+        }
+        for hook in after {
+            insert_hook(&mut self.after, hook);
+        }
+        self.prefilters = Prefilters::default();
+        self
+    }
+
+    /// Marks all the [`Step`]s of this [`Collection`] as having [`Regex`]es
+    /// built out of their string representations only, so they may be found
+    /// via a [`RegexSet`].
+    ///
+    /// Should be used only for the [`Step`]s registered via attributes, as
+    /// their [`Regex`]es are always built with a [`Regex::new()`].
+    #[must_use]
+    pub(crate) fn with_plain_regexes(mut self) -> Self {
+        self.plain.extend(
+            self.given
+                .keys()
+                .chain(self.when.keys())
+                .chain(self.then.keys())
+                .cloned(),
+        );
+        self.prefilters = Prefilters::default();
         self
     }
 
+    /// Inserts the given [`Step`] of the given [`StepType`], marking it as a
+    /// work-in-progress one, if `is_wip` is `true`, or unmarking otherwise.
+    fn insert(
+        &mut self,
+        ty: StepType,
+        loc: Option<Location>,
+        regex: Regex,
+        step: StepFn<World>,
+        is_wip: bool,
+    ) {
+        let regex = HashableRegex::from(regex);
+        _ = self.plain.remove(&(regex.clone(), loc));
+        _ = self
+            .anchored
+            .entry((regex.clone(), loc))
+            .or_insert_with(|| anchor(&regex));
+        self.insert_step(ty, regex.clone(), loc, step);
+
+        let key = (ty, regex, loc);
+        if is_wip {
+            _ = self.wip.insert(key);
+        } else {
+            _ = self.wip.remove(&key);
+        }
+        self.prefilters = Prefilters::default();
+    }
+
+    /// Inserts the given generic [`Step`] for all the [`StepType`]s, marking it
+    /// as a work-in-progress one, if `is_wip` is `true`.
+    fn insert_generic(
+        &mut self,
+        loc: Option<Location>,
+        regex: Regex,
+        step: StepFn<World>,
+        is_wip: bool,
+    ) {
+        let regex = HashableRegex::from(regex);
+        _ = self.generic.insert((regex.clone(), loc));
+
+        let (given, when) = (Arc::clone(&step), Arc::clone(&step));
+        self.insert(StepType::Given, loc, (*regex).clone(), given, is_wip);
+        self.insert(StepType::When, loc, (*regex).clone(), when, is_wip);
+        self.insert(StepType::Then, loc, (*regex).clone(), step, is_wip);
+    }
+
+    /// Inserts the given [`Step`] of the given [`StepType`], replacing or
+    /// reporting the ones with the same [`Regex`] (depending on whether
+    /// [`Collection::allow_overrides()`] is used).
+    ///
+    /// Re-inserting the same [`Step`] definition (having the same
+    /// [`Location`]) is not considered as a duplicate, as well as a generic
+    /// [`Step`] having the same [`Regex`] as a type-specific one.
+    fn insert_step(
+        &mut self,
+        ty: StepType,
+        regex: HashableRegex,
+        loc: Option<Location>,
+        step: StepFn<World>,
+    ) {
+        let steps = match ty {
+            StepType::Given => &mut self.given,
+            StepType::When => &mut self.when,
+            StepType::Then => &mut self.then,
+        };
+
+        let is_generic = self.generic.contains(&(regex.clone(), loc));
+        let duplicates = steps
+            .keys()
+            .filter(|(re, l)| {
+                *re == regex
+                    && (l.is_none() || *l != loc)
+                    && self.generic.contains(&(re.clone(), *l)) == is_generic
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        match (self.allow_overrides, duplicates.is_empty()) {
+            (true, _) => {
+                for (re, l) in duplicates {
+                    _ = self.wip.remove(&(ty, re.clone(), l));
+                    drop(steps.remove(&(re, l)));
+                }
+            }
+            (false, false) => {
+                let mut locations =
+                    duplicates.into_iter().map(|(_, l)| l).collect::<Vec<_>>();
+                locations.push(loc);
+                record_duplicate(
+                    &mut self.duplicates,
+                    Duplicate {
+                        ty: (!is_generic).then_some(ty),
+                        regex: regex.clone(),
+                        locations,
+                    },
+                );
+            }
+            (false, true) => {}
+        }
+
+        drop(steps.insert((regex, loc), step));
+    }
+
+    /// Returns the [`Step`]s defined more than once in this [`Collection`]
+    /// (with the same [`Regex`] and [`StepType`]), unless
+    /// [`Collection::allow_overrides()`] is used.
+    #[must_use]
+    pub fn duplicates(&self) -> &[Duplicate] {
+        &self.duplicates
+    }
+
+    /// Returns [`BeforeHook`]s applicable to the given [`Scenario`], in their
+    /// execution order.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub(crate) fn before_hooks<'s>(
+        &'s self,
+        feature: &'s gherkin::Feature,
+        rule: Option<&'s gherkin::Rule>,
+        scenario: &'s gherkin::Scenario,
+    ) -> impl Iterator<Item = &'s BeforeHook<World>> {
+        self.before
+            .iter()
+            .filter(move |(_, tags, _)| {
+                applies_to(tags.as_ref(), feature, rule, scenario)
+            })
+            .map(|(_, _, hook)| hook)
+    }
+
+    /// Returns [`AfterHook`]s applicable to the given [`Scenario`], in their
+    /// execution order (reversed to the [`BeforeHook`]s one).
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub(crate) fn after_hooks<'s>(
+        &'s self,
+        feature: &'s gherkin::Feature,
+        rule: Option<&'s gherkin::Rule>,
+        scenario: &'s gherkin::Scenario,
+    ) -> impl Iterator<Item = &'s AfterHook<World>> {
+        self.after
+            .iter()
+            .rev()
+            .filter(move |(_, tags, _)| {
+                applies_to(tags.as_ref(), feature, rule, scenario)
+            })
+            .map(|(_, _, hook)| hook)
+    }
+
+    /// Returns all the [`Step`]s of this [`Collection`] in their registration
+    /// order, along with their [`StepType`]s ([`None`] for generic ones) and
+    /// [`Location`]s.
+    pub fn definitions(
+        &self,
+    ) -> impl Iterator<Item = (Option<StepType>, &HashableRegex, Option<Location>)>
+    {
+        let is_generic = |(re, loc): &&(HashableRegex, Option<Location>)| {
+            self.generic.contains(&(re.clone(), *loc))
+        };
+        [
+            (StepType::Given, &self.given),
+            (StepType::When, &self.when),
+            (StepType::Then, &self.then),
+        ]
+        .into_iter()
+        .flat_map(move |(ty, steps)| {
+            steps
+                .keys()
+                .filter(move |k| !is_generic(k))
+                .map(move |(re, loc)| (Some(ty), re, *loc))
+        })
+        .chain(
+            self.given
+                .keys()
+                .filter(is_generic)
+                .map(|(re, loc)| (None, re, *loc)),
+        )
+    }
+
     /// Returns a [`Step`] function matching the given [`gherkin::Step`], if
     /// any.
     ///
+    /// The [`Step`] function is looked up by the definition the
+    /// [`gherkin::Step`] is [resolved][0] to, so it's always the same one a
+    /// [`Collection::resolve()`] reports.
+    ///
     /// # Errors
     ///
     /// If the given [`gherkin::Step`] matches multiple [`Regex`]es.
+    ///
+    /// [0]: Collection::resolve
     pub fn find(
         &self,
         step: &gherkin::Step,
     ) -> Result<Option<WithContext<'_, World>>, AmbiguousMatchError> {
-        let collection = match step.ty {
-            StepType::Given => &self.given,
-            StepType::When => &self.when,
-            StepType::Then => &self.then,
-        };
+        self.lookup(step).map(Result::ok)
+    }
 
-        let mut captures = collection
-            .iter()
-            .filter_map(|((re, loc), step_fn)| {
-                let mut captures = re.capture_locations();
-                let names = re.capture_names();
-                re.captures_read(&mut captures, &step.value)
-                    .map(|m| (re, loc, m, captures, names, step_fn))
-            })
-            .collect::<Vec<_>>();
+    /// Looks up a [`Step`] function matching the given [`gherkin::Step`] in the
+    /// same way as [`Collection::find()`] does, returning the [`Step`]s
+    /// matching only a part of its text, if there is no such function.
+    ///
+    /// # Errors
+    ///
+    /// If the given [`gherkin::Step`] matches multiple [`Regex`]es.
+    pub(crate) fn lookup(
+        &self,
+        step: &gherkin::Step,
+    ) -> Result<
+        Result<WithContext<'_, World>, PartialMatches>,
+        AmbiguousMatchError,
+    > {
+        let (key, captures, matches) = match self.resolution(step) {
+            Resolved::Defined(key, captures, matches) => {
+                (key, captures, matches)
+            }
+            Resolved::Ambiguous(e) => return Err(e),
+            Resolved::Undefined(partial) => return Ok(Err(partial)),
+        };
 
-        let (_, loc, whole_match, captures, names, step_fn) =
-            match captures.len() {
-                0 => return Ok(None),
-                // Instead of `.unwrap()` to avoid documenting `# Panics`.
-                1 => captures.pop().unwrap_or_else(|| unreachable!()),
-                _ => {
-                    return Err(AmbiguousMatchError {
-                        possible_matches: captures
-                            .into_iter()
-                            .map(|(re, loc, ..)| (re.clone(), *loc))
-                            .collect(),
-                    })
-                }
-            };
-
-        // PANIC: Slicing is OK here, as all indices are obtained from the
-        //        source string.
-        #[allow(clippy::string_slice)]
-        let matches = names
-            .map(|opt| opt.map(str::to_owned))
-            .zip(iter::once(whole_match.as_str().to_owned()).chain(
-                (1..captures.len()).map(|group_id| {
-                    captures
-                        .get(group_id)
-                        .map_or("", |(s, e)| &step.value[s..e])
-                        .to_owned()
-                }),
-            ))
-            .collect();
+        let step_fn = self.steps_of(step.ty).get(key).unwrap_or_else(|| {
+            unreachable!("`Step` has been resolved already")
+        });
+        let (regex, loc) = key.clone();
+        let is_wip = self.is_wip(step.ty, &regex, loc);
 
-        Ok(Some((
+        Ok(Ok((
             step_fn,
             captures,
-            *loc,
+            loc,
             Context {
                 step: step.clone(),
+                regex,
                 matches,
+                joined_threads: JoinedThreads::default(),
+                scratch: Scratch::default(),
+                log_sender: None,
+                scenario: None,
+                is_wip,
             },
         )))
     }
-}
-
-/// Name of a capturing group inside a [`regex`].
-pub type CaptureName = Option<String>;
 
-/// Context for a [`Step`] function execution.
-#[derive(Clone, Debug)]
-pub struct Context {
+    /// Resolves the given [`gherkin::Step`] to its [`Step`] function
+    /// definition, without executing anything.
+    ///
+    /// Both [`Collection::find()`] and a dry run of a [`Runner`] are built on
+    /// top of this method, so it may be used for checking that all the
+    /// [`gherkin::Step`]s are defined (in CI, for example) without running
+    /// them.
+    ///
+    /// [`Runner`]: crate::Runner
+    #[must_use]
+    pub fn resolve(&self, step: &gherkin::Step) -> Resolution {
+        match self.resolution(step) {
+            Resolved::Defined((re, loc), _, matches)
+                if re.captures_len() > 1 =>
+            {
+                Resolution::Regex(re.clone(), *loc, matches)
+            }
+            Resolved::Defined((re, loc), ..) => {
+                Resolution::Literal(re.clone(), *loc)
+            }
+            Resolved::Ambiguous(e) => Resolution::Ambiguous(e),
+            Resolved::Undefined(partial) => Resolution::Undefined(partial),
+        }
+    }
+
+    /// Indicates whether the [`Step`] with the given [`StepType`], [`Regex`]
+    /// and [`Location`] is a work-in-progress one.
+    pub(crate) fn is_wip(
+        &self,
+        ty: StepType,
+        regex: &HashableRegex,
+        loc: Option<Location>,
+    ) -> bool {
+        !self.wip.is_empty() && self.wip.contains(&(ty, regex.clone(), loc))
+    }
+
+    /// Returns the [`Step`]s of the given [`StepType`].
+    fn steps_of(
+        &self,
+        ty: StepType,
+    ) -> &LinkedHashMap<(HashableRegex, Option<Location>), StepFn<World>> {
+        match ty {
+            StepType::Given => &self.given,
+            StepType::When => &self.when,
+            StepType::Then => &self.then,
+        }
+    }
+
+    /// Resolves the given [`gherkin::Step`] to its [`Step`] function
+    /// definition, matching its [`Regex`] against the [`gherkin::Step`] text
+    /// once for both [`Collection::resolve()`] and [`Collection::find()`].
+    fn resolution(&self, step: &gherkin::Step) -> Resolved<'_> {
+        let collection = self.steps_of(step.ty);
+        let prefilter = match step.ty {
+            StepType::Given => &self.prefilters.given,
+            StepType::When => &self.prefilters.when,
+            StepType::Then => &self.prefilters.then,
+        };
+
+        let mut partial = Vec::new();
+        // Only checking candidates for a match avoids allocating
+        // `CaptureLocations` and `matches` for every one of them, as these are
+        // required for the single resolved `Step` only.
+        let mut candidates = Prefilter::candidates(
+            prefilter,
+            |key| self.plain.contains(key),
+            collection,
+            step,
+        )
+        .into_iter()
+        .filter(|(key, _)| {
+            let is_match = self.partial_matches
+                || is_whole_match(&key.0, self.anchored(key), &step.value);
+            if !is_match {
+                partial.push((**key).clone());
+            }
+            is_match
+        })
+        .collect::<Vec<_>>();
+
+        if candidates.is_empty() && !partial.is_empty() {
+            debug!("{}", describe_partial_matches(step, &partial));
+        }
+
+        // Generic `Step`s are considered only if no type-specific ones match.
+        let is_generic = |re: &HashableRegex, loc: &Option<Location>| {
+            self.generic.contains(&(re.clone(), *loc))
+        };
+        if !self.generic.is_empty()
+            && candidates.iter().any(|((re, loc), _)| !is_generic(re, loc))
+        {
+            candidates.retain(|((re, loc), _)| !is_generic(re, loc));
+        }
+
+        // Aliases of the same definition share its `Location`, so matching
+        // several of them is not an ambiguity.
+        if candidates.len() > 1
+            && candidates[0].0 .1.is_some()
+            && candidates.iter().map(|((_, loc), _)| loc).all_equal()
+        {
+            candidates.truncate(1);
+        }
+
+        // A literal definition of the exact `Step` text takes precedence over
+        // the matching `Regex`es.
+        if candidates.len() > 1 {
+            let literal = format!("^{}$", regex::escape(&step.value));
+            let is_literal = |re: &HashableRegex| re.as_str() == literal;
+            if candidates
+                .iter()
+                .filter(|((re, _), _)| is_literal(re))
+                .count()
+                == 1
+            {
+                candidates.retain(|((re, _), _)| is_literal(re));
+            }
+        }
+
+        debug!(
+            "resolving step \"{}{}\" against {} `{:?}` step definition(s): \
+             {} matched",
+            step.keyword,
+            step.value,
+            collection.len(),
+            step.ty,
+            candidates.len(),
+        );
+
+        match candidates.as_slice() {
+            [] => Resolved::Undefined(partial),
+            [(key, _)] => {
+                let (captures, whole_match) = self.captures(key, step);
+                let matches =
+                    capture_matches(&key.0, whole_match, &captures, step);
+                Resolved::Defined(key, captures, matches)
+            }
+            _ => Resolved::Ambiguous(AmbiguousMatchError {
+                possible_matches: candidates
+                    .into_iter()
+                    .map(|((re, loc), _)| (re.clone(), *loc))
+                    .collect(),
+            }),
+        }
+    }
+
+    /// Matches the [`Regex`] of the [`Step`] with the given key, already
+    /// [resolved][0] for the given [`gherkin::Step`], against its text.
+    ///
+    /// [0]: Collection::resolution
+    fn captures<'s>(
+        &self,
+        key: &(HashableRegex, Option<Location>),
+        step: &'s gherkin::Step,
+    ) -> (regex::CaptureLocations, regex::Match<'s>) {
+        let regex = &key.0;
+        let mut captures = regex.capture_locations();
+        let whole_match = if self.partial_matches {
+            regex.captures_read(&mut captures, &step.value)
+        } else {
+            whole_match(regex, self.anchored(key), &step.value, &mut captures)
+        }
+        .unwrap_or_else(|| unreachable!("`Regex` has matched already"));
+        (captures, whole_match)
+    }
+
+    /// Returns the precompiled anchored version of the [`Regex`] of the
+    /// [`Step`] with the given key, if it may be built.
+    fn anchored(
+        &self,
+        key: &(HashableRegex, Option<Location>),
+    ) -> Option<&Regex> {
+        self.anchored.get(key).and_then(Option::as_ref)
+    }
+}
+
+/// Builds the anchored with `^` and `$` version of the given [`Regex`] out of
+/// its string representation, if possible.
+///
+/// Flags set via a [`RegexBuilder`] are not reflected in the string
+/// representation, so the anchored version is consulted only when the
+/// leftmost-first match of the original [`Regex`] is shorter than the whole
+/// [`gherkin::Step`] text.
+///
+/// [`RegexBuilder`]: regex::RegexBuilder
+fn anchor(regex: &Regex) -> Option<Regex> {
+    Regex::new(&format!("^(?:{})$", regex.as_str())).ok()
+}
+
+/// Collects the named and numbered matches of the given [`Regex`] capturing
+/// groups (including the `whole_match`) in the [`gherkin::Step`] text.
+fn capture_matches(
+    regex: &Regex,
+    whole_match: regex::Match<'_>,
+    captures: &regex::CaptureLocations,
+    step: &gherkin::Step,
+) -> Vec<(CaptureName, String)> {
+    // PANIC: Slicing is OK here, as all indices are obtained from the
+    //        source string.
+    #[allow(clippy::string_slice)]
+    regex
+        .capture_names()
+        .map(|opt| opt.map(str::to_owned))
+        .zip(iter::once(whole_match.as_str().to_owned()).chain(
+            (1..captures.len()).map(|group_id| {
+                captures
+                    .get(group_id)
+                    .map_or("", |(s, e)| &step.value[s..e])
+                    .to_owned()
+            }),
+        ))
+        .collect()
+}
+
+/// Describes the given [`gherkin::Step`] being undefined, as it matches only a
+/// part of its text with the `partial` [`Regex`]es.
+pub(crate) fn describe_partial_matches(
+    step: &gherkin::Step,
+    partial: &[(HashableRegex, Option<Location>)],
+) -> String {
+    format!(
+        "Step \"{}{}\" is undefined, as it only partially matches: {}",
+        step.keyword,
+        step.value,
+        partial
+            .iter()
+            .map(|(re, loc)| loc.map_or_else(
+                || format!("`{re}`"),
+                |loc| format!("`{re}` --> {loc}"),
+            ))
+            .join(", "),
+    )
+}
+
+/// Checks whether the given [`Regex`] matches the whole `text`, as if it were
+/// anchored with `^` and `$`, without allocating any captures.
+fn is_whole_match(re: &Regex, anchored: Option<&Regex>, text: &str) -> bool {
+    match re.find(text) {
+        None => false,
+        Some(m) if m.range() == (0..text.len()) => true,
+        // The leftmost-first match may be shorter than the whole `text` even
+        // if the `anchored` `Regex` matches it (because of lazy quantifiers or
+        // alternations, for example).
+        Some(_) => anchored.is_some_and(|a| a.is_match(text)),
+    }
+}
+
+/// Matches the given [`Regex`] against the whole `text`, as if it were anchored
+/// with `^` and `$`, filling the provided [`regex::CaptureLocations`].
+fn whole_match<'t>(
+    re: &Regex,
+    anchored: Option<&Regex>,
+    text: &'t str,
+    captures: &mut regex::CaptureLocations,
+) -> Option<regex::Match<'t>> {
+    let m = re.captures_read(captures, text)?;
+    if m.range() == (0..text.len()) {
+        return Some(m);
+    }
+
+    // The leftmost-first match may be shorter than the whole `text` even if
+    // the `anchored` `Regex` matches it (because of lazy quantifiers or
+    // alternations, for example).
+    let anchored = anchored?;
+    *captures = anchored.capture_locations();
+    anchored.captures_read(captures, text)
+}
+
+/// [`Prefilter`]s of a [`Collection`] for each [`StepType`].
+#[derive(Clone, Debug, Default)]
+struct Prefilters {
+    /// [`Prefilter`] of [Given] [`Step`]s.
+    ///
+    /// [Given]: https://cucumber.io/docs/gherkin/reference#given
+    given: OnceCell<Option<Prefilter>>,
+
+    /// [`Prefilter`] of [When] [`Step`]s.
+    ///
+    /// [When]: https://cucumber.io/docs/gherkin/reference#when
+    when: OnceCell<Option<Prefilter>>,
+
+    /// [`Prefilter`] of [Then] [`Step`]s.
+    ///
+    /// [Then]: https://cucumber.io/docs/gherkin/reference#then
+    then: OnceCell<Option<Prefilter>>,
+}
+
+/// [`RegexSet`] of [`Step`]s [`Regex`]es, finding the ones matching a
+/// [`gherkin::Step`] in a single pass.
+#[derive(Clone, Debug)]
+struct Prefilter {
+    /// [`RegexSet`] of the [`Step`]s [`Regex`]es built out of their string
+    /// representations only.
+    set: RegexSet,
+
+    /// Indices in the [`RegexSet`] of the [`Step`]s [`Regex`]es, in the
+    /// registration order of the [`Step`]s.
+    ///
+    /// [`None`] means the [`Regex`] may have flags not reflected in its string
+    /// representation, so should be checked on its own.
+    indices: Vec<Option<usize>>,
+}
+
+impl Prefilter {
+    /// Builds a new [`Prefilter`] of the given [`Step`]s, including only the
+    /// ones considered `is_plain` into its [`RegexSet`].
+    ///
+    /// Returns [`None`] if the [`RegexSet`] cannot be built (exceeds its size
+    /// limit, for example), so the [`Regex`]es should be checked one by one.
+    fn new<World>(
+        steps: &LinkedHashMap<(HashableRegex, Option<Location>), StepFn<World>>,
+        is_plain: impl Fn(&(HashableRegex, Option<Location>)) -> bool,
+    ) -> Option<Self> {
+        let mut patterns = Vec::new();
+        let indices = steps
+            .keys()
+            .map(|key| {
+                is_plain(key).then(|| {
+                    patterns.push(key.0.as_str());
+                    patterns.len() - 1
+                })
+            })
+            .collect();
+        let set = RegexSet::new(patterns).ok()?;
+        Some(Self { set, indices })
+    }
+
+    /// Returns the [`Step`]s of the given `collection` whose [`Regex`]es match
+    /// the given [`gherkin::Step`], in their registration order.
+    ///
+    /// Lazily builds the [`Prefilter`], falling back to checking the
+    /// [`Regex`]es one by one if it cannot be built.
+    fn candidates<'c, World>(
+        prefilter: &'c OnceCell<Option<Self>>,
+        is_plain: impl Fn(&(HashableRegex, Option<Location>)) -> bool,
+        collection: &'c LinkedHashMap<
+            (HashableRegex, Option<Location>),
+            StepFn<World>,
+        >,
+        step: &gherkin::Step,
+    ) -> Vec<(&'c (HashableRegex, Option<Location>), &'c StepFn<World>)> {
+        let is_match = |re: &HashableRegex| re.is_match(&step.value);
+        prefilter
+            .get_or_init(|| Self::new(collection, is_plain))
+            .as_ref()
+            .map_or_else(
+                || {
+                    collection
+                        .iter()
+                        .filter(|((re, _), _)| is_match(re))
+                        .collect()
+                },
+                |p| {
+                    let matches = p.set.matches(&step.value);
+                    collection
+                        .iter()
+                        .zip(&p.indices)
+                        .filter(|(((re, _), _), i)| {
+                            i.map_or_else(
+                                || is_match(re),
+                                |i| matches.matched(i),
+                            )
+                        })
+                        .map(|(found, _)| found)
+                        .collect()
+                },
+            )
+    }
+}
+
+/// Result of resolving a [`gherkin::Step`] via [`Collection::resolve()`].
+#[derive(Clone, Debug)]
+pub enum Resolution {
+    /// [`gherkin::Step`] matches exactly one [`Step`] function, which
+    /// [`Regex`] has no capturing groups.
+    Literal(HashableRegex, Option<Location>),
+
+    /// [`gherkin::Step`] matches exactly one [`Step`] function, which
+    /// [`Regex`] has capturing groups.
+    ///
+    /// Contains all the [`Regex`] matches, including the whole one.
+    Regex(HashableRegex, Option<Location>, Vec<(CaptureName, String)>),
+
+    /// [`gherkin::Step`] matches multiple [`Step`] functions.
+    Ambiguous(AmbiguousMatchError),
+
+    /// [`gherkin::Step`] matches no [`Step`] function.
+    ///
+    /// Contains the [`Step`]s matching only a part of the [`gherkin::Step`]
+    /// text (if any), which are not considered as matches, unless
+    /// [`Collection::partial_matches()`] is used.
+    Undefined(PartialMatches),
+}
+
+/// [`Regex`]es and [`Location`]s of the [`Step`]s matching only a part of a
+/// [`gherkin::Step`] text.
+pub type PartialMatches = Vec<(HashableRegex, Option<Location>)>;
+
+/// [`Resolution`] of a [`gherkin::Step`] along with the [`Regex`] captures of
+/// the resolved [`Step`], so [`Collection::find()`] doesn't match it again.
+enum Resolved<'c> {
+    /// [`gherkin::Step`] matches exactly one [`Step`] function, with the given
+    /// key, captures and all the [`Regex`] matches (including the whole one).
+    Defined(
+        &'c (HashableRegex, Option<Location>),
+        regex::CaptureLocations,
+        Vec<(CaptureName, String)>,
+    ),
+
+    /// [`gherkin::Step`] matches multiple [`Step`] functions.
+    Ambiguous(AmbiguousMatchError),
+
+    /// [`gherkin::Step`] matches no [`Step`] function, while the given ones
+    /// match only a part of its text.
+    Undefined(PartialMatches),
+}
+
+/// [`Step`] defined more than once in a [`Collection`].
+#[derive(Clone, Debug)]
+pub struct Duplicate {
+    /// [`StepType`] of the duplicated [`Step`] ([`None`] for generic ones).
+    pub ty: Option<StepType>,
+
+    /// [`Regex`] shared by all the definitions.
+    pub regex: HashableRegex,
+
+    /// [`Location`]s of all the definitions, in their registration order.
+    pub locations: Vec<Option<Location>>,
+}
+
+/// Records the given [`Duplicate`] into the `duplicates`, merging it with an
+/// already recorded one of the same [`Step`].
+///
+/// Generic [`Step`]s are inserted for every [`StepType`], so are recorded only
+/// once.
+fn record_duplicate(duplicates: &mut Vec<Duplicate>, dup: Duplicate) {
+    if let Some(existing) = duplicates
+        .iter_mut()
+        .find(|d| d.ty == dup.ty && d.regex == dup.regex)
+    {
+        for loc in dup.locations {
+            if !existing.locations.contains(&loc) {
+                existing.locations.push(loc);
+            }
+        }
+    } else {
+        duplicates.push(dup);
+    }
+}
+
+/// Inserts the given `hook` after all the hooks with the same or a higher
+/// priority, keeping the `hooks` ordered by their priority and then by their
+/// registration order.
+fn insert_hook<H>(
+    hooks: &mut Vec<(i32, Option<TagOperation>, H)>,
+    hook: (i32, Option<TagOperation>, H),
+) {
+    let at = hooks
+        .iter()
+        .position(|(prio, ..)| *prio < hook.0)
+        .unwrap_or(hooks.len());
+    hooks.insert(at, hook);
+}
+
+/// Checks whether a hook scoped by the given [`TagOperation`] applies to the
+/// given [`Scenario`].
+///
+/// [`Scenario`]: gherkin::Scenario
+fn applies_to(
+    tags: Option<&TagOperation>,
+    feature: &gherkin::Feature,
+    rule: Option<&gherkin::Rule>,
+    scenario: &gherkin::Scenario,
+) -> bool {
+    tags.map_or(true, |op| {
+        // The order `Feature` -> `Rule` -> `Scenario` matters here.
+        op.eval(
+            feature
+                .tags
+                .iter()
+                .chain(rule.iter().flat_map(|r| &r.tags))
+                .chain(scenario.tags.iter()),
+        )
+    })
+}
+
+/// Name of a capturing group inside a [`regex`].
+pub type CaptureName = Option<String>;
+
+/// Extension of a [`gherkin::Step`] with helpers for its [Doc String][1] and
+/// [Data Table][2].
+///
+/// [1]: https://cucumber.io/docs/gherkin/reference#doc-strings
+/// [2]: https://cucumber.io/docs/gherkin/reference#data-tables
+#[sealed]
+pub trait Ext {
+    /// Returns the [Doc String][1] of this [`gherkin::Step`] with the common
+    /// leading whitespace of all its non-blank lines stripped.
+    ///
+    /// Only the exactly same leading whitespace characters are considered
+    /// common, so a line indented with a tab and a line indented with spaces
+    /// have no common indentation at all. Blank lines are emptied, while the
+    /// leading and trailing blank lines are omitted altogether, so the result
+    /// never ends with a newline.
+    ///
+    /// [1]: https://cucumber.io/docs/gherkin/reference#doc-strings
+    #[must_use]
+    fn docstring_dedented(&self) -> Option<String>;
+
+    /// Returns the [Doc String][1] of this [`gherkin::Step`] with everything
+    /// up to and including the `margin` character stripped from each line,
+    /// which starts with this character after its leading whitespace.
+    ///
+    /// Lines without the `margin` character are left as is, while the leading
+    /// and trailing blank lines are omitted the same way
+    /// [`Ext::docstring_dedented()`] does.
+    ///
+    /// [1]: https://cucumber.io/docs/gherkin/reference#doc-strings
+    #[must_use]
+    fn docstring_with_margin(&self, margin: char) -> Option<String>;
+
+    /// Returns the first row of the [Data Table][1] of this
+    /// [`gherkin::Step`], considered as its header.
+    ///
+    /// [1]: https://cucumber.io/docs/gherkin/reference#data-tables
+    #[must_use]
+    fn table_header(&self) -> Option<&[String]>;
+
+    /// Returns all the rows of the [Data Table][1] of this [`gherkin::Step`]
+    /// except its [header](Ext::table_header()), each one as a map keyed by
+    /// the header cells.
+    ///
+    /// Empty cells are represented as empty [`String`]s, while the cells
+    /// without a header cell are omitted.
+    ///
+    /// [1]: https://cucumber.io/docs/gherkin/reference#data-tables
+    #[must_use]
+    fn table_rows_as_maps(&self) -> Option<Vec<HashMap<String, String>>>;
+
+    /// Parses all the cells of the single-column [Data Table][1] of this
+    /// [`gherkin::Step`] (without a header) as `T`.
+    ///
+    /// Returns [`None`] if there is no [Data Table][1], or it has more than
+    /// one column.
+    ///
+    /// # Errors
+    ///
+    /// If any of the cells fails to parse as `T`.
+    ///
+    /// [1]: https://cucumber.io/docs/gherkin/reference#data-tables
+    #[must_use]
+    fn table_as<T: FromStr>(&self) -> Option<Result<Vec<T>, T::Err>>;
+}
+
+#[sealed]
+impl Ext for gherkin::Step {
+    fn docstring_dedented(&self) -> Option<String> {
+        self.docstring.as_deref().map(dedent)
+    }
+
+    fn docstring_with_margin(&self, margin: char) -> Option<String> {
+        self.docstring.as_deref().map(|doc| {
+            trim_blank_lines(doc)
+                .map(|l| l.trim_start().strip_prefix(margin).unwrap_or(l))
+                .join("\n")
+        })
+    }
+
+    fn table_header(&self) -> Option<&[String]> {
+        self.table.as_ref()?.rows.first().map(Vec::as_slice)
+    }
+
+    fn table_rows_as_maps(&self) -> Option<Vec<HashMap<String, String>>> {
+        let (header, rows) = self.table.as_ref()?.rows.split_first()?;
+        Some(
+            rows.iter()
+                .map(|row| header.iter().cloned().zip(row.clone()).collect())
+                .collect(),
+        )
+    }
+
+    fn table_as<T: FromStr>(&self) -> Option<Result<Vec<T>, T::Err>> {
+        let rows = &self.table.as_ref()?.rows;
+        rows.iter()
+            .all(|row| row.len() == 1)
+            .then(|| rows.iter().flatten().map(|c| c.parse()).collect())
+    }
+}
+
+/// Strips the common leading whitespace of all the non-blank lines of the
+/// given `text`.
+fn dedent(text: &str) -> String {
+    let is_blank = |l: &str| l.trim().is_empty();
+
+    let common = trim_blank_lines(text)
+        .filter(|l| !is_blank(l))
+        .map(|l| l.chars().take_while(|c| c.is_whitespace()).collect())
+        .reduce(|common: String, indent| {
+            common
+                .chars()
+                .zip(indent.chars())
+                .take_while(|(a, b)| a == b)
+                .map(|(c, _)| c)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    trim_blank_lines(text)
+        .map(|l| {
+            if is_blank(l) {
+                ""
+            } else {
+                l.strip_prefix(common.as_str()).unwrap_or(l)
+            }
+        })
+        .join("\n")
+}
+
+/// Returns the lines of the given `text` without the leading and trailing
+/// blank ones.
+fn trim_blank_lines(text: &str) -> impl Iterator<Item = &str> {
+    let lines = text.lines().collect::<Vec<_>>();
+    let first = lines.iter().position(|l| !l.trim().is_empty());
+    let last = lines.iter().rposition(|l| !l.trim().is_empty());
+    let range = first.zip(last).map_or(0..0, |(f, l)| f..(l + 1));
+    lines.into_iter().take(range.end).skip(range.start)
+}
+
+/// Context for a [`Step`] function execution.
+#[allow(clippy::partial_pub_fields)] // only `Runner` may join threads
+#[derive(Clone, Debug)]
+pub struct Context {
     /// [`Step`] matched to a [`Step`] function.
     ///
     /// [`Step`]: gherkin::Step
@@ -244,8 +1647,603 @@ pub struct Context {
 
     /// [`Regex`] matches of a [`Step::value`].
     ///
+    /// The first one is always the whole match, followed by all the capture
+    /// groups of the [`Regex`]. Groups not participating in the match (like an
+    /// optional `(?: with (\d+) retries)?` one) are represented with empty
+    /// strings. Arguments of `#[given]`, `#[when]` and `#[then]` attribute
+    /// macros are parsed from the capture groups only.
+    ///
     /// [`Step::value`]: gherkin::Step::value
     pub matches: Vec<(CaptureName, String)>,
+
+    /// [`Regex`] of the matched [`Step`] function, producing the
+    /// [`Context::matches`].
+    regex: HashableRegex,
+
+    /// [`thread`]s spawned via [`Context::spawn_joined()`].
+    joined_threads: JoinedThreads,
+
+    /// Values shared between [`Step`]s of the same [`Scenario`] via
+    /// [`Context::insert()`] and [`Context::get()`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    scratch: Scratch,
+
+    /// [`LogSender`] of the [`Scenario`] this [`Step`] is run in, if any.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    log_sender: Option<(ScenarioId, LogSender)>,
+
+    /// [`Feature`], [`Rule`] and [`Scenario`] this [`Step`] is run in, if
+    /// any.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    scenario: Option<ScenarioContext>,
+
+    /// Indicator whether the matched [`Step`] function is a work-in-progress
+    /// one, so shouldn't be executed.
+    ///
+    /// [`Step`]: gherkin::Step
+    is_wip: bool,
+}
+
+impl Context {
+    /// Spawns a new [`thread`], which is joined by a [`Runner`] right after
+    /// the [`Step`] function finishes.
+    ///
+    /// Panics inside the spawned [`thread`] fail the [`Step`] the same way
+    /// panics inside the [`Step`] function itself do. This isn't the case for
+    /// [`thread`]s spawned in any other way, as their panics can't be caught
+    /// by a [`Runner`].
+    ///
+    /// The [`Runner`] awaits the spawned [`thread`] without blocking, so it's
+    /// fine for the [`thread`] to wait for something happening on the executor
+    /// the [`Step`] function runs on.
+    ///
+    /// [`Runner`]: crate::Runner
+    /// [`Step`]: gherkin::Step
+    pub fn spawn_joined<F>(&self, f: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        let (tx, rx) = oneshot::channel();
+        drop(thread::spawn(move || {
+            drop(tx.send(panic::catch_unwind(AssertUnwindSafe(f))));
+        }));
+        self.joined_threads.push(rx);
+    }
+
+    /// Logs the given `msg` line as an [`event::Scenario::Log`] right away,
+    /// while the [`Step`] is still running.
+    ///
+    /// Useful for reporting progress of long-running [`Step`]s, which are
+    /// silent otherwise until they finish. May be called from [`thread`]s
+    /// spawned by the [`Step`] too, as long as it's still running.
+    ///
+    /// Does nothing if the [`Step`] isn't run by a [`Runner`].
+    ///
+    /// [`Runner`]: crate::Runner
+    /// [`Step`]: gherkin::Step
+    pub fn log(&self, msg: impl fmt::Display) {
+        if let Some((id, sender)) = &self.log_sender {
+            // If the receiver end is dropped, then no one listens for logs,
+            // so we can just ignore it.
+            drop(sender.unbounded_send((*id, format!("{msg}\n"))));
+        }
+    }
+
+    /// Stores the given `value` in the scratch context of the current
+    /// [`Scenario`], so it may be retrieved by the following [`Step`]s via
+    /// [`Context::get()`].
+    ///
+    /// The scratch context is keyed by types, so returns the previously stored
+    /// value of the same type, if any. It's created fresh for each [`Scenario`]
+    /// (including its retries), dropped once the [`Scenario`] finishes, and
+    /// is independent of the [`World`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    /// [`World`]: crate::World
+    pub fn insert<T: Any + Send>(&self, value: T) -> Option<T> {
+        self.scratch.insert(value)
+    }
+
+    /// Returns a clone of the value of type `T` stored in the scratch context
+    /// of the current [`Scenario`] via [`Context::insert()`].
+    ///
+    /// # Errors
+    ///
+    /// If no value of type `T` has been stored in the current [`Scenario`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub fn get<T: Any + Clone + Send>(
+        &self,
+    ) -> Result<T, MissingScratchValueError> {
+        self.scratch.get()
+    }
+
+    /// Removes the value of type `T` from the scratch context of the current
+    /// [`Scenario`], returning it.
+    ///
+    /// # Errors
+    ///
+    /// If no value of type `T` has been stored in the current [`Scenario`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub fn remove<T: Any + Send>(&self) -> Result<T, MissingScratchValueError> {
+        self.scratch.remove()
+    }
+
+    /// Parses the capture group of the given `index` from the
+    /// [`Context::matches`] (`0` being the whole match).
+    ///
+    /// # Errors
+    ///
+    /// - If there is no capture group with the given `index`.
+    /// - If the captured text fails to be parsed as `T`.
+    pub fn capture<T>(&self, index: usize) -> Result<T, CaptureError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let text = self.matches.get(index).map(|(_, s)| s.as_str());
+        self.parse_capture(index.to_string(), text)
+    }
+
+    /// Parses the capture group of the given `name` from the
+    /// [`Context::matches`].
+    ///
+    /// # Errors
+    ///
+    /// - If there is no capture group with the given `name`.
+    /// - If the captured text fails to be parsed as `T`.
+    pub fn named_capture<T>(&self, name: &str) -> Result<T, CaptureError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let text = self
+            .matches
+            .iter()
+            .find(|(n, _)| n.as_deref() == Some(name))
+            .map(|(_, s)| s.as_str());
+        self.parse_capture(name.to_owned(), text)
+    }
+
+    /// Returns the [`Feature`] this [`Step`] is run in.
+    ///
+    /// Returns [`None`] if the [`Step`] isn't run by a [`Runner`].
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Runner`]: crate::Runner
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn feature(&self) -> Option<&gherkin::Feature> {
+        self.scenario.as_ref().map(|(f, _, _)| &**f)
+    }
+
+    /// Returns the [`Rule`] this [`Step`] is run in, if any.
+    ///
+    /// Returns [`None`] if the [`Step`] isn't run by a [`Runner`].
+    ///
+    /// [`Rule`]: gherkin::Rule
+    /// [`Runner`]: crate::Runner
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn rule(&self) -> Option<&gherkin::Rule> {
+        self.scenario.as_ref().and_then(|(_, r, _)| r.as_deref())
+    }
+
+    /// Returns the [`Scenario`] this [`Step`] is run in.
+    ///
+    /// Returns [`None`] if the [`Step`] isn't run by a [`Runner`].
+    ///
+    /// [`Runner`]: crate::Runner
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn scenario(&self) -> Option<&gherkin::Scenario> {
+        self.scenario.as_ref().map(|(_, _, s)| &**s)
+    }
+
+    /// Returns tags of the [`Scenario`] this [`Step`] is run in, along with
+    /// the ones inherited from its [`Feature`] and [`Rule`] (in the
+    /// `Feature` -> `Rule` -> `Scenario` order), without the leading `@`.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    pub fn tags(&self) -> impl Iterator<Item = &str> {
+        self.feature()
+            .into_iter()
+            .flat_map(|f| &f.tags)
+            .chain(self.rule().into_iter().flat_map(|r| &r.tags))
+            .chain(self.scenario().into_iter().flat_map(|s| &s.tags))
+            .map(String::as_str)
+    }
+
+    /// Indicates whether the [`Scenario`] this [`Step`] is run in has the
+    /// given `tag` (without the leading `@`), either directly or inherited
+    /// from its [`Feature`] or [`Rule`].
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags().any(|t| t == tag)
+    }
+
+    /// Parses the given `text` of the capture `group` as `T`.
+    fn parse_capture<T>(
+        &self,
+        group: String,
+        text: Option<&str>,
+    ) -> Result<T, CaptureError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let Some(text) = text else {
+            return Err(CaptureError::NotFound {
+                regex: self.regex.clone(),
+                group,
+            });
+        };
+        text.parse().map_err(|e: T::Err| CaptureError::Parse {
+            regex: self.regex.clone(),
+            group,
+            text: text.to_owned(),
+            reason: e.to_string(),
+        })
+    }
+
+    /// Indicates whether the matched [`Step`] function is a work-in-progress
+    /// one, so shouldn't be executed.
+    ///
+    /// [`Step`]: gherkin::Step
+    pub(crate) const fn is_wip(&self) -> bool {
+        self.is_wip
+    }
+
+    /// Returns [`Regex`] of the matched [`Step`] function.
+    ///
+    /// [`Step`]: gherkin::Step
+    pub(crate) const fn regex(&self) -> &HashableRegex {
+        &self.regex
+    }
+
+    /// Returns [`thread`]s spawned via [`Context::spawn_joined()`].
+    pub(crate) fn joined_threads(&self) -> JoinedThreads {
+        self.joined_threads.clone()
+    }
+
+    /// Makes [`Context::insert()`] and [`Context::get()`] operate on the given
+    /// [`Scratch`] of the current [`Scenario`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub(crate) fn with_scratch(mut self, scratch: Scratch) -> Self {
+        self.scratch = scratch;
+        self
+    }
+
+    /// Makes [`Context::log()`] send messages via the given [`LogSender`] on
+    /// behalf of the [`Scenario`] with the given [`ScenarioId`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    pub(crate) fn with_log_sender(
+        mut self,
+        id: ScenarioId,
+        sender: LogSender,
+    ) -> Self {
+        self.log_sender = Some((id, sender));
+        self
+    }
+
+    /// Makes [`Context::feature()`], [`Context::rule()`],
+    /// [`Context::scenario()`] and [`Context::tags()`] return the given
+    /// [`ScenarioContext`].
+    pub(crate) fn with_scenario(mut self, scenario: ScenarioContext) -> Self {
+        self.scenario = Some(scenario);
+        self
+    }
+}
+
+impl Borrow<gherkin::Step> for Context {
+    fn borrow(&self) -> &gherkin::Step {
+        &self.step
+    }
+}
+
+/// [`Feature`], [`Rule`] and [`Scenario`] a [`Step`] is run in.
+///
+/// [`Feature`]: gherkin::Feature
+/// [`Rule`]: gherkin::Rule
+/// [`Scenario`]: gherkin::Scenario
+/// [`Step`]: gherkin::Step
+pub(crate) type ScenarioContext = (
+    Arc<gherkin::Feature>,
+    Option<Arc<gherkin::Rule>>,
+    Arc<gherkin::Scenario>,
+);
+
+/// Sender of the messages logged via [`Context::log()`].
+pub(crate) type LogSender = mpsc::UnboundedSender<(ScenarioId, String)>;
+
+/// [`thread`]s spawned via [`Context::spawn_joined()`] and not joined yet.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct JoinedThreads(
+    Arc<Mutex<Vec<oneshot::Receiver<thread::Result<()>>>>>,
+);
+
+impl JoinedThreads {
+    /// Adds the given [`oneshot::Receiver`] of a [`thread`] result to be
+    /// joined later.
+    fn push(&self, rx: oneshot::Receiver<thread::Result<()>>) {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(rx);
+    }
+
+    /// Awaits all the spawned [`thread`]s, including the ones spawned while
+    /// awaiting, without blocking the executor.
+    ///
+    /// # Errors
+    ///
+    /// With the payload of the first panicked [`thread`], if any.
+    pub(crate) async fn join(&self) -> thread::Result<()> {
+        let mut result = Ok(());
+        loop {
+            let receivers = mem::take(
+                &mut *self.0.lock().unwrap_or_else(PoisonError::into_inner),
+            );
+            if receivers.is_empty() {
+                return result;
+            }
+            for res in future::join_all(receivers).await {
+                // `oneshot::Canceled` is impossible, as the panics are caught
+                // before sending.
+                let res = res.unwrap_or(Ok(()));
+                if result.is_ok() {
+                    result = res;
+                }
+            }
+        }
+    }
+}
+
+/// Type-keyed values shared between [`Step`]s of a single [`Scenario`] via
+/// [`Context::insert()`] and [`Context::get()`].
+///
+/// [`Scenario`]: gherkin::Scenario
+/// [`Step`]: gherkin::Step
+#[derive(Clone, Default)]
+pub(crate) struct Scratch(Arc<Mutex<HashMap<TypeId, ScratchValue>>>);
+
+/// Value stored in a [`Scratch`] along with the name of its type.
+type ScratchValue = (&'static str, Box<dyn Any + Send>);
+
+impl fmt::Debug for Scratch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set()
+            .entries(
+                self.0
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .values()
+                    .map(|(name, _)| name),
+            )
+            .finish()
+    }
+}
+
+impl Scratch {
+    /// Stores the given `value`, returning the previous one of the same type,
+    /// if any.
+    fn insert<T: Any + Send>(&self, value: T) -> Option<T> {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(TypeId::of::<T>(), (any::type_name::<T>(), Box::new(value)))
+            .and_then(|(_, prev)| prev.downcast().ok())
+            .map(|prev| *prev)
+    }
+
+    /// Returns a clone of the stored value of type `T`.
+    ///
+    /// # Errors
+    ///
+    /// If there is no stored value of type `T`.
+    fn get<T: Any + Clone + Send>(
+        &self,
+    ) -> Result<T, MissingScratchValueError> {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&TypeId::of::<T>())
+            .and_then(|(_, val)| val.downcast_ref::<T>())
+            .cloned()
+            .ok_or_else(MissingScratchValueError::of::<T>)
+    }
+
+    /// Removes the stored value of type `T`, returning it.
+    ///
+    /// # Errors
+    ///
+    /// If there is no stored value of type `T`.
+    fn remove<T: Any + Send>(&self) -> Result<T, MissingScratchValueError> {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&TypeId::of::<T>())
+            .and_then(|(_, val)| val.downcast().ok())
+            .map(|val| *val)
+            .ok_or_else(MissingScratchValueError::of::<T>)
+    }
+}
+
+/// Panic payload marking the currently running [`Step`] as pending, raised by
+/// the [`pending!`] macro.
+///
+/// Such a [`Step`] is reported as [`event::Step::Pending`], the same way as a
+/// work-in-progress one, instead of being failed. Detection relies on the
+/// payload type only, so any other panic, whatever its message is, fails the
+/// [`Step`].
+///
+/// [`pending!`]: crate::pending!
+/// [`Step`]: gherkin::Step
+#[derive(Clone, Copy, Debug, Default, Display, Error)]
+#[display(fmt = "step is pending")]
+pub struct Pending;
+
+/// Marks the currently running [`Step`] as pending, stopping its execution.
+///
+/// The [`Step`] is reported as [`event::Step::Pending`], so it's counted by
+/// [`Stats::pending_steps()`] and fails the run only with
+/// [`FailOnSkipped::fail_on_pending()`].
+///
+/// ```rust
+/// # use cucumber::{pending, when, World};
+/// #
+/// # #[derive(Debug, Default, World)]
+/// # struct MyWorld;
+/// #
+/// #[when("the cat is fed")]
+/// fn feed_cat(_: &mut MyWorld) {
+///     pending!();
+/// }
+/// ```
+///
+/// [`event::Step::Pending`]: crate::event::Step::Pending
+/// [`FailOnSkipped::fail_on_pending()`]: crate::writer::FailOnSkipped::fail_on_pending()
+/// [`Stats::pending_steps()`]: crate::writer::Stats::pending_steps()
+/// [`Step`]: gherkin::Step
+#[macro_export]
+macro_rules! pending {
+    () => {
+        ::std::panic::panic_any($crate::step::Pending)
+    };
+}
+
+/// Panic payload skipping the rest of the currently running [`Scenario`],
+/// raised by the [`skip!`] macro.
+///
+/// The [`Step`] is reported as [`event::Step::SkippedWithReason`], and its
+/// [`Scenario`] is considered skipped rather than failed.
+///
+/// [`Scenario`]: gherkin::Scenario
+/// [`skip!`]: crate::skip!
+/// [`Step`]: gherkin::Step
+#[derive(Clone, Debug, Display, Error)]
+#[display(fmt = "scenario is skipped: {}", reason)]
+pub struct Skip {
+    /// Reason of skipping the [`Scenario`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[error(not(source))]
+    pub reason: String,
+}
+
+/// Skips the rest of the currently running [`Scenario`] with the reason,
+/// formatted from the provided arguments (like [`format!`] does).
+///
+/// The [`Scenario`] is reported as skipped, not failed, and the reason is
+/// shown in the output.
+///
+/// ```rust
+/// # use std::env;
+/// #
+/// # use cucumber::{given, skip, World};
+/// #
+/// # #[derive(Debug, Default, World)]
+/// # struct MyWorld;
+/// #
+/// #[given("a Docker host")]
+/// fn docker_host(_: &mut MyWorld) {
+///     if env::var_os("DOCKER_HOST").is_none() {
+///         skip!("requires `DOCKER_HOST`");
+///     }
+/// }
+/// ```
+///
+/// [`Scenario`]: gherkin::Scenario
+#[macro_export]
+macro_rules! skip {
+    ($($arg:tt)+) => {
+        ::std::panic::panic_any($crate::step::Skip {
+            reason: ::std::format!($($arg)+),
+        })
+    };
+}
+
+/// Error of retrieving a value, which hasn't been stored in the scratch context
+/// of a [`Scenario`] via [`Context::insert()`].
+///
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Clone, Copy, Debug, Display, Error)]
+#[display(
+    fmt = "no value of `{}` type has been inserted into the scratch \
+           context of the current `Scenario`",
+    type_name
+)]
+pub struct MissingScratchValueError {
+    /// Name of the requested type.
+    #[error(not(source))]
+    pub type_name: &'static str,
+}
+
+impl MissingScratchValueError {
+    /// Creates a new [`MissingScratchValueError`] of the requested type `T`.
+    fn of<T: Any>() -> Self {
+        Self {
+            type_name: any::type_name::<T>(),
+        }
+    }
+}
+
+/// Error of parsing a capture group via [`Context::capture()`] or
+/// [`Context::named_capture()`].
+#[derive(Clone, Debug, Display, Error)]
+pub enum CaptureError {
+    /// [`Regex`] has no such capture group.
+    #[display(fmt = "`{}` has no capture group `{}`", regex, group)]
+    NotFound {
+        /// [`Regex`] of the matched [`Step`] function.
+        regex: HashableRegex,
+
+        /// Index or name of the requested capture group.
+        group: String,
+    },
+
+    /// Captured text failed to be parsed.
+    #[display(
+        fmt = "failed to parse capture group `{}` of `{}` from \"{}\": {}",
+        group,
+        regex,
+        text,
+        reason
+    )]
+    Parse {
+        /// [`Regex`] of the matched [`Step`] function.
+        regex: HashableRegex,
+
+        /// Index or name of the requested capture group.
+        group: String,
+
+        /// Captured text failed to be parsed.
+        text: String,
+
+        /// Reason of the parsing failure.
+        reason: String,
+    },
 }
 
 /// Error of a [`gherkin::Step`] matching multiple [`Step`] [`Regex`]es inside a
@@ -284,12 +2282,16 @@ pub struct Location {
 }
 
 /// [`Regex`] wrapper implementing [`Eq`], [`Ord`] and [`Hash`].
-#[derive(Clone, Debug, Deref, DerefMut, Display)]
-pub struct HashableRegex(Regex);
+///
+/// Clones share the same [`Regex`] (along with its matching cache), so are
+/// cheap to match with.
+#[derive(Clone, Debug, Deref, Display)]
+#[deref(forward)]
+pub struct HashableRegex(Arc<Regex>);
 
 impl From<Regex> for HashableRegex {
     fn from(re: Regex) -> Self {
-        Self(re)
+        Self(Arc::new(re))
     }
 }
 
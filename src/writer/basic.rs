@@ -12,12 +12,23 @@
 
 use std::{
     borrow::Cow,
-    cmp, env,
+    cmp,
+    collections::hash_map::RandomState,
+    env,
+    error::Error,
     fmt::{Debug, Display},
-    io,
+    fs,
+    hash::{BuildHasher as _, Hasher as _},
+    io::{self, IsTerminal as _, Write as _},
+    path::{Path, PathBuf},
+    process,
     str::FromStr,
+    time::Duration,
 };
 
+#[cfg(feature = "timestamps")]
+use std::time::SystemTime;
+
 use async_trait::async_trait;
 use derive_more::{Deref, DerefMut};
 use itertools::Itertools as _;
@@ -27,8 +38,9 @@ use smart_default::SmartDefault;
 
 use crate::{
     cli::Colored,
+    diagnostics,
     event::{self, Info, Retries},
-    parser, step,
+    feature, parser, step,
     writer::{
         self,
         out::{Styles, WriteStrExt as _},
@@ -37,6 +49,9 @@ use crate::{
     Event, World, Writer,
 };
 
+/// Terminal width used in the [`Cli::golden`] mode.
+pub const GOLDEN_TERM_WIDTH: u16 = 80;
+
 /// CLI options of a [`Basic`] [`Writer`].
 #[derive(clap::Args, Clone, Copy, Debug, SmartDefault)]
 #[group(skip)]
@@ -44,7 +59,8 @@ pub struct Cli {
     /// Verbosity of an output.
     ///
     /// `-v` is default verbosity, `-vv` additionally outputs world on failed
-    /// steps, `-vvv` additionally outputs step's doc string (if present).
+    /// steps and definitions matched by passed ones, `-vvv` additionally
+    /// outputs step's doc string (if present).
     #[arg(short, action = clap::ArgAction::Count, global = true)]
     pub verbose: u8,
 
@@ -57,6 +73,65 @@ pub struct Cli {
     )]
     #[default(Coloring::Auto)]
     pub color: Coloring,
+
+    /// Outputs only ASCII characters, instead of Unicode markers of steps and
+    /// punctuation.
+    ///
+    /// Enabled automatically on Windows consoles not supporting Unicode.
+    #[arg(long, global = true)]
+    pub ascii: bool,
+
+    /// Maximum number of lines of a captured output to show on failures.
+    ///
+    /// Lines in the middle are omitted, while the whole captured output is
+    /// written into a temporary file, which path is shown instead.
+    #[arg(long, value_name = "int", global = true)]
+    pub max_output_lines: Option<usize>,
+
+    /// Terminal width to use instead of the detected one.
+    ///
+    /// May be also set via `CUCUMBER_TERM_WIDTH` environment variable. Values
+    /// less than 40 are clamped.
+    #[arg(long, value_name = "int", global = true)]
+    pub term_width: Option<u16>,
+
+    /// Flushing policy of an output.
+    ///
+    /// `auto` flushes the output after each event only if it's not a terminal,
+    /// so nothing is lost in case the process is killed (by a CI timeout, for
+    /// example).
+    #[arg(
+        long,
+        value_name = "auto|always|never",
+        default_value = "auto",
+        global = true
+    )]
+    #[default(Flushing::Auto)]
+    pub flush: Flushing,
+
+    /// Outputs absolute paths of `.feature` files, instead of the ones relative
+    /// to the current project directory.
+    #[arg(long, global = true)]
+    pub absolute_paths: bool,
+
+    /// Outputs deterministically for snapshot testing: without colors, with
+    /// the fixed terminal width, forward slashes in paths and without paths
+    /// of temporary files.
+    #[arg(long, global = true)]
+    pub golden: bool,
+
+    /// Outputs durations of steps taking longer than the specified one, along
+    /// with the total duration of the run.
+    ///
+    /// Duration is represented in a human-readable format like `500ms`.
+    #[cfg(feature = "timestamps")]
+    #[arg(
+        long,
+        value_name = "duration",
+        value_parser = humantime::parse_duration,
+        global = true
+    )]
+    pub slow_steps: Option<Duration>,
 }
 
 impl Colored for Cli {
@@ -92,6 +167,118 @@ impl FromStr for Coloring {
     }
 }
 
+/// Possible policies of flushing a [`Basic`] [`Writer`]'s output.
+#[derive(Clone, Copy, Debug)]
+pub enum Flushing {
+    /// Flushing the output after each event, only if [`io::Stdout`] is not a
+    /// terminal.
+    Auto,
+
+    /// Forcing flushing of the output after each event.
+    Always,
+
+    /// Never flushing the output explicitly, leaving it to the underlying
+    /// [`io::Write`] implementor.
+    Never,
+}
+
+impl FromStr for Flushing {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "auto" => Ok(Self::Auto),
+            "always" => Ok(Self::Always),
+            "never" => Ok(Self::Never),
+            _ => Err("possible options: auto, always, never"),
+        }
+    }
+}
+
+/// Marker of a [`Step`]'s outcome, prefixing it in an output.
+///
+/// [`Step`]: gherkin::Step
+#[derive(Clone, Copy, Debug)]
+enum Marker {
+    /// [`Step`] has passed.
+    ///
+    /// [`Step`]: gherkin::Step
+    Passed,
+
+    /// [`Step`] has failed.
+    ///
+    /// [`Step`]: gherkin::Step
+    Failed,
+
+    /// [`Step`] has timed out.
+    ///
+    /// [`Step`]: gherkin::Step
+    TimedOut,
+
+    /// [`Background`] [`Step`] has passed.
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Step`]: gherkin::Step
+    BackgroundPassed,
+
+    /// [`Background`] [`Step`] has failed.
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Step`]: gherkin::Step
+    BackgroundFailed,
+
+    /// [`Background`] [`Step`] has timed out.
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Step`]: gherkin::Step
+    BackgroundTimedOut,
+}
+
+impl Marker {
+    /// Returns this [`Marker`] as a 3 columns wide string, so the [`Step`]s
+    /// are aligned regardless of their outcome.
+    ///
+    /// [`Step`]: gherkin::Step
+    const fn as_str(self, ascii: bool) -> &'static str {
+        match (self, ascii) {
+            (Self::Passed, false) => "✔  ",
+            (Self::Passed, true) => "v  ",
+            (Self::Failed, false) => "✘  ",
+            (Self::Failed, true) => "x  ",
+            (Self::TimedOut, false) => "⏱  ",
+            (Self::TimedOut, true) => "t  ",
+            (Self::BackgroundPassed, false) => "✔> ",
+            (Self::BackgroundPassed, true) => "v> ",
+            (Self::BackgroundFailed, false) => "✘> ",
+            (Self::BackgroundFailed, true) => "x> ",
+            (Self::BackgroundTimedOut, false) => "⏱> ",
+            (Self::BackgroundTimedOut, true) => "t> ",
+        }
+    }
+}
+
+/// Modes of formatting the output of a [`Basic`] [`Writer`].
+#[derive(Clone, Copy, Debug, Default)]
+struct Format {
+    /// Indicator whether only ASCII characters should be output, instead of
+    /// Unicode markers of [`Step`]s and punctuation.
+    ///
+    /// See [`Cli::ascii`] for details.
+    ///
+    /// [`Step`]: gherkin::Step
+    ascii: bool,
+
+    /// Indicator whether absolute paths of `.feature` files should be output.
+    ///
+    /// See [`Cli::absolute_paths`] for details.
+    absolute_paths: bool,
+
+    /// Indicator whether the output should be deterministic.
+    ///
+    /// See [`Cli::golden`] for details.
+    golden: bool,
+}
+
 /// Default [`Writer`] implementation outputting to an [`io::Write`] implementor
 /// ([`io::Stdout`] by default).
 ///
@@ -130,6 +317,44 @@ pub struct Basic<Out: io::Write = io::Stdout> {
 
     /// [`Verbosity`] of this [`Writer`].
     verbosity: Verbosity,
+
+    /// Maximum number of lines of a captured output to show on failures.
+    ///
+    /// [`None`] means no limit.
+    max_output_lines: Option<usize>,
+
+    /// Indicator whether the output should be flushed after each event.
+    flush: bool,
+
+    /// [`Format`] of the output.
+    format: Format,
+
+    /// Indicator whether writing into the output has failed already, so no
+    /// more writes should be attempted.
+    write_failed: bool,
+
+    /// Minimal duration of a [`Step`] to be output along with it.
+    ///
+    /// [`None`] means no durations are output.
+    ///
+    /// [`Step`]: gherkin::Step
+    slow_steps: Option<Duration>,
+
+    /// [`SystemTime`] when the whole run has started.
+    #[cfg(feature = "timestamps")]
+    run_started_at: Option<SystemTime>,
+
+    /// [`SystemTime`] when the currently output [`Step`] has started.
+    ///
+    /// [`Step`]: gherkin::Step
+    #[cfg(feature = "timestamps")]
+    step_started_at: Option<SystemTime>,
+
+    /// Duration of the currently output [`Step`], if it exceeds the
+    /// [`Basic::slow_steps`] threshold.
+    ///
+    /// [`Step`]: gherkin::Step
+    step_duration: Option<Duration>,
 }
 
 #[async_trait(?Send)]
@@ -148,22 +373,40 @@ where
         use event::{Cucumber, Feature};
 
         self.apply_cli(*opts);
+        if self.write_failed {
+            return;
+        }
+
+        #[cfg(feature = "timestamps")]
+        if let Ok(ev) = &ev {
+            self.measure_duration(ev);
+        }
 
-        match ev.map(Event::into_inner) {
+        let res = match ev.map(Event::into_inner) {
             Err(err) => self.parsing_failed(&err),
             Ok(
                 Cucumber::Started
                 | Cucumber::ParsingFinished { .. }
-                | Cucumber::Finished,
+                | Cucumber::Aborted,
             ) => Ok(()),
+            Ok(Cucumber::DuplicateSteps(steps)) => self.duplicate_steps(&steps),
+            Ok(Cucumber::UnusedSteps(steps)) => self.unused_steps(&steps),
+            Ok(Cucumber::Finished) => self.finished(),
             Ok(Cucumber::Feature(f, ev)) => match ev {
                 Feature::Started => self.feature_started(&f),
                 Feature::Scenario(sc, ev) => self.scenario(&f, &sc, &ev),
                 Feature::Rule(r, ev) => self.rule(&f, &r, ev),
-                Feature::Finished => Ok(()),
+                Feature::Finished(_) => Ok(()),
             },
         }
-        .unwrap_or_else(|e| panic!("Failed to write into terminal: {e}"));
+        .and_then(|()| {
+            if self.flush {
+                self.output.flush()
+            } else {
+                Ok(())
+            }
+        });
+        self.handle_write_result(res);
     }
 }
 
@@ -178,8 +421,10 @@ where
     where
         'val: 'async_trait,
     {
-        self.write_line(val.as_ref())
-            .unwrap_or_else(|e| panic!("Failed to write: {e}"));
+        if !self.write_failed {
+            let res = self.write_line(val.as_ref());
+            self.handle_write_result(res);
+        }
     }
 }
 
@@ -231,14 +476,57 @@ impl<Out: io::Write> Basic<Out> {
             lines_to_clear: 0,
             re_output_after_clear: String::new(),
             verbosity: verbosity.into(),
+            max_output_lines: None,
+            flush: !io::stdout().is_terminal(),
+            format: Format {
+                ascii: cfg!(windows) && {
+                    let term = console::Term::stdout();
+                    term.is_term() && !term.features().wants_emoji()
+                },
+                ..Format::default()
+            },
+            write_failed: false,
+            slow_steps: None,
+            #[cfg(feature = "timestamps")]
+            run_started_at: None,
+            #[cfg(feature = "timestamps")]
+            step_started_at: None,
+            step_duration: None,
         };
         basic.apply_cli(Cli {
             verbose: u8::from(basic.verbosity) + 1,
             color,
+            ascii: false,
+            max_output_lines: None,
+            term_width: None,
+            flush: Flushing::Auto,
+            absolute_paths: false,
+            golden: false,
+            #[cfg(feature = "timestamps")]
+            slow_steps: None,
         });
         basic
     }
 
+    /// Stops any further writing into the output of this [`Basic`] [`Writer`]
+    /// if the given `res`ult of writing is an error.
+    ///
+    /// [`io::ErrorKind::BrokenPipe`] is ignored silently (the output is piped
+    /// into `head`, for example), while any other error is reported into
+    /// [`io::Stderr`] once. Either way, the execution goes on, so other
+    /// [`Writer`]s still produce their reports and the exit status is correct.
+    fn handle_write_result(&mut self, res: io::Result<()>) {
+        if let Err(e) = res {
+            self.write_failed = true;
+            if e.kind() != io::ErrorKind::BrokenPipe {
+                diagnostics::emit(format_args!(
+                    "failed to write into terminal, suppressing further \
+                     output: {e}",
+                ));
+            }
+        }
+    }
+
     /// Applies the given [`Cli`] options to this [`Basic`] [`Writer`].
     pub fn apply_cli(&mut self, cli: Cli) {
         match cli.verbose {
@@ -248,6 +536,174 @@ impl<Out: io::Write> Basic<Out> {
             _ => self.verbosity = Verbosity::ShowWorldAndDocString,
         };
         self.styles.apply_coloring(cli.color);
+        if cli.ascii {
+            self.format.ascii = true;
+        }
+        if let Some(max) = cli.max_output_lines {
+            self.max_output_lines = Some(max);
+        }
+        if let Some(width) = cli.term_width {
+            self.styles.override_term_width(width);
+        }
+        match cli.flush {
+            Flushing::Auto => {}
+            Flushing::Always => self.flush = true,
+            Flushing::Never => self.flush = false,
+        }
+        if cli.absolute_paths {
+            self.format.absolute_paths = true;
+        }
+        if cli.golden {
+            self.format.golden = true;
+        }
+        if self.format.golden {
+            self.styles.apply_coloring(Coloring::Never);
+            self.styles.override_term_width(GOLDEN_TERM_WIDTH);
+            self.format.ascii = cli.ascii;
+        }
+        #[cfg(feature = "timestamps")]
+        if let Some(threshold) = cli.slow_steps {
+            self.slow_steps = Some(threshold);
+        }
+    }
+
+    /// Measures durations of the [`Step`]s and the whole run out of the
+    /// timestamps of the given [`Event`].
+    ///
+    /// [`Step`]: gherkin::Step
+    #[cfg(feature = "timestamps")]
+    fn measure_duration<W>(&mut self, ev: &Event<event::Cucumber<W>>) {
+        use event::{Cucumber, Feature, Rule, Scenario, Step};
+
+        self.step_duration = None;
+        let Some(threshold) = self.slow_steps else {
+            return;
+        };
+
+        let sc = match &ev.value {
+            Cucumber::Started => {
+                self.run_started_at = Some(ev.at);
+                return;
+            }
+            Cucumber::Finished => {
+                self.step_duration = self
+                    .run_started_at
+                    .and_then(|started| ev.at.duration_since(started).ok());
+                return;
+            }
+            Cucumber::Feature(
+                _,
+                Feature::Scenario(_, sc)
+                | Feature::Rule(_, Rule::Scenario(_, sc)),
+            ) => sc,
+            Cucumber::ParsingFinished { .. }
+            | Cucumber::DuplicateSteps(_)
+            | Cucumber::UnusedSteps(_)
+            | Cucumber::Aborted
+            | Cucumber::Feature(..) => return,
+        };
+        match &sc.event {
+            Scenario::Step(_, Step::Started)
+            | Scenario::Background(_, Step::Started) => {
+                self.step_started_at = Some(ev.at);
+            }
+            Scenario::Step(_, Step::Passed(..) | Step::Failed(..))
+            | Scenario::Background(_, Step::Passed(..) | Step::Failed(..)) => {
+                self.step_duration = self
+                    .step_started_at
+                    .take()
+                    .and_then(|started| ev.at.duration_since(started).ok())
+                    .filter(|dur| *dur >= threshold);
+            }
+            Scenario::Started
+            | Scenario::Hook(..)
+            | Scenario::Step(
+                _,
+                Step::Skipped | Step::Pending(_) | Step::SkippedWithReason(..),
+            )
+            | Scenario::Background(
+                _,
+                Step::Skipped | Step::Pending(_) | Step::SkippedWithReason(..),
+            )
+            | Scenario::Log(_)
+            | Scenario::Finished(_) => {}
+        }
+    }
+
+    /// Formats the duration of the currently output [`Step`], if it exceeds
+    /// the [`Basic::slow_steps`] threshold.
+    ///
+    /// [`Step`]: gherkin::Step
+    fn format_step_duration(&self) -> String {
+        self.step_duration
+            .map(|dur| format!(" ({dur:.2?})"))
+            .unwrap_or_default()
+    }
+
+    /// Outputs the total duration of the run, if [`Basic::slow_steps`] are
+    /// output.
+    fn finished(&mut self) -> io::Result<()> {
+        match self.step_duration.take() {
+            Some(dur) if self.slow_steps.is_some() => self
+                .output
+                .write_line(self.styles.bold(format!("Run took {dur:.2?}"))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Outputs the [`Step`] definitions not matched by any [`Step`].
+    ///
+    /// [`Step`]: gherkin::Step
+    fn unused_steps(
+        &mut self,
+        steps: &[(
+            Option<gherkin::StepType>,
+            step::HashableRegex,
+            Option<step::Location>,
+        )],
+    ) -> io::Result<()> {
+        if steps.is_empty() {
+            return Ok(());
+        }
+
+        self.output.write_line(self.styles.skipped(format!(
+            "{} unused step definition{}:",
+            steps.len(),
+            if steps.len() == 1 { "" } else { "s" },
+        )))?;
+        for (ty, re, loc) in steps {
+            let ty = ty.map_or_else(|| "Any".to_owned(), |t| format!("{t:?}"));
+            let loc = loc.map(|l| format!(" --> {l}")).unwrap_or_default();
+            self.output.write_line(
+                self.styles.skipped(format!("  {ty} `{re}`{loc}")),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Outputs a warning about the [`Step`] definitions defined more than once.
+    ///
+    /// [`Step`]: step::Step
+    fn duplicate_steps(&mut self, steps: &[step::Duplicate]) -> io::Result<()> {
+        for dup in steps {
+            let ty = dup
+                .ty
+                .map_or_else(|| "Any".to_owned(), |t| format!("{t:?}"));
+            self.output.write_line(self.styles.err(format!(
+                "Warning: {ty} step `{}` is defined more than once, use \
+                 `step::Collection::allow_overrides()` if this is intended:",
+                dup.regex,
+            )))?;
+            for loc in &dup.locations {
+                let loc = loc.map_or_else(
+                    || "unknown location".to_owned(),
+                    |l| l.to_string(),
+                );
+                self.output
+                    .write_line(self.styles.err(format!("  --> {loc}")))?;
+            }
+        }
+        Ok(())
     }
 
     /// Clears last `n` lines if [`Coloring`] is enabled.
@@ -374,7 +830,7 @@ impl<Out: io::Write> Basic<Out> {
             Scenario::Step(st, ev) => {
                 self.step(feat, scenario, st, ev, retries)?;
             }
-            Scenario::Finished => {
+            Scenario::Finished(_) => {
                 self.indent = self.indent.saturating_sub(2);
             }
             Scenario::Log(msg) => self.emit_log(msg)?,
@@ -413,16 +869,14 @@ impl<Out: io::Write> Basic<Out> {
         };
 
         self.output.write_line(&style(format!(
-            "{indent}✘  Scenario's {which} hook failed {}:{}:{}\n\
+            "{indent}{}Scenario's {which} hook failed {}:{}:{}\n\
              {indent}   Captured output: {}{}",
-            feat.path
-                .as_ref()
-                .and_then(|p| p.to_str().map(trim_path))
-                .unwrap_or(&feat.name),
+            self.marker(Marker::Failed),
+            self.feature_path(feat),
             sc.position.line,
             sc.position.col,
             format_str_with_indent(
-                coerce_error(info),
+                self.truncate_output(&coerce_error(info)),
                 self.indent.saturating_sub(3) + 3
             ),
             world
@@ -451,7 +905,7 @@ impl<Out: io::Write> Basic<Out> {
                 "{}{}: {} | Retry attempt: {}/{}",
                 " ".repeat(self.indent),
                 scenario.keyword,
-                scenario.name,
+                feature::display_name(scenario),
                 retries.current,
                 retries.left + retries.current,
             );
@@ -462,7 +916,7 @@ impl<Out: io::Write> Basic<Out> {
                 "{}{}: {}",
                 " ".repeat(self.indent),
                 scenario.keyword,
-                scenario.name,
+                feature::display_name(scenario),
             );
             self.lines_to_clear += self.styles.lines_count(&out);
             self.output.write_line(&self.styles.ok(out))
@@ -490,17 +944,28 @@ impl<Out: io::Write> Basic<Out> {
             Step::Started => {
                 self.step_started(step)?;
             }
-            Step::Passed(captures, _) => {
-                self.step_passed(sc, step, captures, retries)?;
+            Step::Passed(captures, loc) => {
+                self.step_passed(sc, step, captures, *loc, retries)?;
                 self.indent = self.indent.saturating_sub(4);
             }
             Step::Skipped => {
                 self.step_skipped(feat, step)?;
                 self.indent = self.indent.saturating_sub(4);
             }
+            Step::Pending(loc) => {
+                self.step_pending(feat, step, *loc, "?  ", "Step")?;
+                self.indent = self.indent.saturating_sub(4);
+            }
+            Step::SkippedWithReason(loc, reason) => {
+                self.step_skipped_with_reason(
+                    feat, step, *loc, reason, "?  ", "Step",
+                )?;
+                self.indent = self.indent.saturating_sub(4);
+            }
             Step::Failed(c, loc, w, i) => {
                 self.step_failed(
                     feat,
+                    sc,
                     step,
                     c.as_ref(),
                     *loc,
@@ -566,6 +1031,7 @@ impl<Out: io::Write> Basic<Out> {
         scenario: &gherkin::Scenario,
         step: &gherkin::Step,
         captures: &CaptureLocations,
+        loc: Option<step::Location>,
         retries: Option<Retries>,
     ) -> io::Result<()> {
         self.clear_last_lines_if_term_present()?;
@@ -580,7 +1046,8 @@ impl<Out: io::Write> Basic<Out> {
             }
         };
 
-        let step_keyword = style(format!("✔  {}", step.keyword));
+        let step_keyword =
+            style(format!("{}{}", self.marker(Marker::Passed), step.keyword));
         let step_value = format_captures(
             &step.value,
             captures,
@@ -607,8 +1074,12 @@ impl<Out: io::Write> Basic<Out> {
                 .unwrap_or_default(),
         );
 
+        let matched = style(self.format_step_definition(loc));
+
         self.output.write_line(&style(format!(
-            "{indent}{step_keyword}{step_value}{doc_str}{step_table}",
+            "{indent}{step_keyword}{step_value}{}{doc_str}{step_table}\
+             {matched}",
+            self.format_step_duration(),
             indent = " ".repeat(self.indent.saturating_sub(3)),
         )))
     }
@@ -641,16 +1112,112 @@ impl<Out: io::Write> Basic<Out> {
                 .as_ref()
                 .map(|t| format_table(t, self.indent))
                 .unwrap_or_default(),
-            feat.path
-                .as_ref()
-                .and_then(|p| p.to_str().map(trim_path))
-                .unwrap_or(&feat.name),
+            self.feature_path(feat),
             step.position.line,
             step.position.col,
             indent = " ".repeat(self.indent.saturating_sub(3)),
         )))
     }
 
+    /// Outputs the [pending] [`Step`] (either a regular or a [`Background`]
+    /// one, depending on the provided `marker` and `kind`).
+    ///
+    /// [pending]: event::Step::Pending
+    /// [`Background`]: gherkin::Background
+    /// [`Step`]: gherkin::Step
+    pub(crate) fn step_pending(
+        &mut self,
+        feat: &gherkin::Feature,
+        step: &gherkin::Step,
+        loc: Option<step::Location>,
+        marker: &str,
+        kind: &str,
+    ) -> io::Result<()> {
+        self.clear_last_lines_if_term_present()?;
+
+        let indent = " ".repeat(self.indent.saturating_sub(3));
+        self.output.write_line(self.styles.skipped(format!(
+            "{indent}{marker}{}{}{}{}\n\
+             {indent}   {kind} pending: {}:{}:{}{}",
+            step.keyword,
+            step.value,
+            step.docstring
+                .as_ref()
+                .and_then(|doc| self.verbosity.shows_docstring().then(|| {
+                    format_str_with_indent(
+                        doc,
+                        self.indent.saturating_sub(3) + 3,
+                    )
+                }))
+                .unwrap_or_default(),
+            step.table
+                .as_ref()
+                .map(|t| format_table(t, self.indent))
+                .unwrap_or_default(),
+            self.feature_path(feat),
+            step.position.line,
+            step.position.col,
+            loc.map(|l| format!(
+                "\n{indent}   Matched: {}:{}:{}",
+                self.format_path(l.path.into()),
+                l.line,
+                l.column,
+            ))
+            .unwrap_or_default(),
+        )))
+    }
+
+    /// Outputs the [`Step`] [skipped with a reason][0] (either a regular or
+    /// a [`Background`] one, depending on the provided `marker` and `kind`).
+    ///
+    /// [0]: event::Step::SkippedWithReason
+    /// [`Background`]: gherkin::Background
+    /// [`Step`]: gherkin::Step
+    pub(crate) fn step_skipped_with_reason(
+        &mut self,
+        feat: &gherkin::Feature,
+        step: &gherkin::Step,
+        loc: Option<step::Location>,
+        reason: &str,
+        marker: &str,
+        kind: &str,
+    ) -> io::Result<()> {
+        self.clear_last_lines_if_term_present()?;
+
+        let indent = " ".repeat(self.indent.saturating_sub(3));
+        self.output.write_line(self.styles.skipped(format!(
+            "{indent}{marker}{}{}{}{}\n\
+             {indent}   {kind} skipped: {}:{}:{}{}\n\
+             {indent}   Reason: {}",
+            step.keyword,
+            step.value,
+            step.docstring
+                .as_ref()
+                .and_then(|doc| self.verbosity.shows_docstring().then(|| {
+                    format_str_with_indent(
+                        doc,
+                        self.indent.saturating_sub(3) + 3,
+                    )
+                }))
+                .unwrap_or_default(),
+            step.table
+                .as_ref()
+                .map(|t| format_table(t, self.indent))
+                .unwrap_or_default(),
+            self.feature_path(feat),
+            step.position.line,
+            step.position.col,
+            loc.map(|l| format!(
+                "\n{indent}   Matched: {}:{}:{}",
+                self.format_path(l.path.into()),
+                l.line,
+                l.column,
+            ))
+            .unwrap_or_default(),
+            reason,
+        )))
+    }
+
     /// Outputs the [failed] [`Step`].
     ///
     /// [failed]: event::Step::Failed
@@ -659,6 +1226,7 @@ impl<Out: io::Write> Basic<Out> {
     pub(crate) fn step_failed<W: Debug>(
         &mut self,
         feat: &gherkin::Feature,
+        sc: &gherkin::Scenario,
         step: &gherkin::Step,
         captures: Option<&CaptureLocations>,
         loc: Option<step::Location>,
@@ -668,6 +1236,7 @@ impl<Out: io::Write> Basic<Out> {
     ) -> io::Result<()> {
         self.clear_last_lines_if_term_present()?;
 
+        let is_timeout = matches!(err, event::StepError::Timeout(_));
         let style = |s| {
             if retries
                 .filter(|r| {
@@ -676,6 +1245,8 @@ impl<Out: io::Write> Basic<Out> {
                 .is_some()
             {
                 self.styles.bright().retry(s)
+            } else if is_timeout {
+                self.styles.timeout(s)
             } else {
                 self.styles.err(s)
             }
@@ -683,7 +1254,15 @@ impl<Out: io::Write> Basic<Out> {
 
         let indent = " ".repeat(self.indent.saturating_sub(3));
 
-        let step_keyword = style(format!("{indent}✘  {}", step.keyword));
+        let step_keyword = style(format!(
+            "{indent}{}{}",
+            self.marker(if is_timeout {
+                Marker::TimedOut
+            } else {
+                Marker::Failed
+            }),
+            step.keyword,
+        ));
         let step_value = captures.map_or_else(
             || style(step.value.clone()),
             |capts| {
@@ -699,8 +1278,8 @@ impl<Out: io::Write> Basic<Out> {
 
         let diagnostics = style(format!(
             "{}{}\n\
-             {indent}   Step failed:\n\
-             {indent}   Defined: {}:{}:{}{}{}{}",
+             {indent}   Step failed:{}\n\
+             {indent}   Defined: {}:{}:{}{}{}{}{}",
             step.docstring
                 .as_ref()
                 .and_then(|doc| self.verbosity.shows_docstring().then(|| {
@@ -714,19 +1293,20 @@ impl<Out: io::Write> Basic<Out> {
                 .as_ref()
                 .map(|t| format_table(t, self.indent))
                 .unwrap_or_default(),
-            feat.path
-                .as_ref()
-                .and_then(|p| p.to_str().map(trim_path))
-                .unwrap_or(&feat.name),
+            self.format_scenario_header(feat, sc),
+            self.feature_path(feat),
             step.position.line,
             step.position.col,
+            self.format_examples_row(feat, sc),
             loc.map(|l| format!(
                 "\n{indent}   Matched: {}:{}:{}",
-                l.path, l.line, l.column,
+                self.format_path(l.path.into()),
+                l.line,
+                l.column,
             ))
             .unwrap_or_default(),
             format_str_with_indent(
-                err.to_string(),
+                self.truncate_output(&err.to_string()),
                 self.indent.saturating_sub(3) + 3,
             ),
             world
@@ -738,7 +1318,10 @@ impl<Out: io::Write> Basic<Out> {
                 .unwrap_or_default(),
         ));
 
-        self.write_line(&format!("{step_keyword}{step_value}{diagnostics}"))
+        let duration = style(self.format_step_duration());
+        self.write_line(format!(
+            "{step_keyword}{step_value}{duration}{diagnostics}",
+        ))
     }
 
     /// Outputs the [`Background`] [`Step`]'s
@@ -764,17 +1347,33 @@ impl<Out: io::Write> Basic<Out> {
             Step::Started => {
                 self.bg_step_started(bg)?;
             }
-            Step::Passed(captures, _) => {
-                self.bg_step_passed(sc, bg, captures, retries)?;
+            Step::Passed(captures, loc) => {
+                self.bg_step_passed(sc, bg, captures, *loc, retries)?;
                 self.indent = self.indent.saturating_sub(4);
             }
             Step::Skipped => {
                 self.bg_step_skipped(feat, bg)?;
                 self.indent = self.indent.saturating_sub(4);
             }
+            Step::Pending(loc) => {
+                self.step_pending(feat, bg, *loc, "?> ", "Background step")?;
+                self.indent = self.indent.saturating_sub(4);
+            }
+            Step::SkippedWithReason(loc, reason) => {
+                self.step_skipped_with_reason(
+                    feat,
+                    bg,
+                    *loc,
+                    reason,
+                    "?> ",
+                    "Background step",
+                )?;
+                self.indent = self.indent.saturating_sub(4);
+            }
             Step::Failed(c, loc, w, i) => {
                 self.bg_step_failed(
                     feat,
+                    sc,
                     bg,
                     c.as_ref(),
                     *loc,
@@ -842,6 +1441,7 @@ impl<Out: io::Write> Basic<Out> {
         scenario: &gherkin::Scenario,
         step: &gherkin::Step,
         captures: &CaptureLocations,
+        loc: Option<step::Location>,
         retries: Option<Retries>,
     ) -> io::Result<()> {
         self.clear_last_lines_if_term_present()?;
@@ -858,7 +1458,11 @@ impl<Out: io::Write> Basic<Out> {
 
         let indent = " ".repeat(self.indent.saturating_sub(3));
 
-        let step_keyword = style(format!("{indent}✔> {}", step.keyword));
+        let step_keyword = style(format!(
+            "{indent}{}{}",
+            self.marker(Marker::BackgroundPassed),
+            step.keyword,
+        ));
         let step_value = format_captures(
             &step.value,
             captures,
@@ -885,8 +1489,11 @@ impl<Out: io::Write> Basic<Out> {
                 .unwrap_or_default(),
         );
 
+        let matched = style(self.format_step_definition(loc));
+
         self.output.write_line(&style(format!(
-            "{step_keyword}{step_value}{doc_str}{step_table}",
+            "{step_keyword}{step_value}{}{doc_str}{step_table}{matched}",
+            self.format_step_duration(),
         )))
     }
 
@@ -919,10 +1526,7 @@ impl<Out: io::Write> Basic<Out> {
                 .as_ref()
                 .map(|t| format_table(t, self.indent))
                 .unwrap_or_default(),
-            feat.path
-                .as_ref()
-                .and_then(|p| p.to_str().map(trim_path))
-                .unwrap_or(&feat.name),
+            self.feature_path(feat),
             step.position.line,
             step.position.col,
             indent = " ".repeat(self.indent.saturating_sub(3)),
@@ -938,6 +1542,7 @@ impl<Out: io::Write> Basic<Out> {
     pub(crate) fn bg_step_failed<W: Debug>(
         &mut self,
         feat: &gherkin::Feature,
+        sc: &gherkin::Scenario,
         step: &gherkin::Step,
         captures: Option<&CaptureLocations>,
         loc: Option<step::Location>,
@@ -947,6 +1552,7 @@ impl<Out: io::Write> Basic<Out> {
     ) -> io::Result<()> {
         self.clear_last_lines_if_term_present()?;
 
+        let is_timeout = matches!(err, event::StepError::Timeout(_));
         let style = |s| {
             if retries
                 .filter(|r| {
@@ -955,13 +1561,23 @@ impl<Out: io::Write> Basic<Out> {
                 .is_some()
             {
                 self.styles.bright().retry(s)
+            } else if is_timeout {
+                self.styles.timeout(s)
             } else {
                 self.styles.err(s)
             }
         };
 
         let indent = " ".repeat(self.indent.saturating_sub(3));
-        let step_keyword = style(format!("{indent}✘> {}", step.keyword));
+        let step_keyword = style(format!(
+            "{indent}{}{}",
+            self.marker(if is_timeout {
+                Marker::BackgroundTimedOut
+            } else {
+                Marker::BackgroundFailed
+            }),
+            step.keyword,
+        ));
         let step_value = captures.map_or_else(
             || style(step.value.clone()),
             |capts| {
@@ -977,8 +1593,8 @@ impl<Out: io::Write> Basic<Out> {
 
         let diagnostics = style(format!(
             "{}{}\n\
-             {indent}   Step failed:\n\
-             {indent}   Defined: {}:{}:{}{}{}{}",
+             {indent}   Step failed:{}\n\
+             {indent}   Defined: {}:{}:{}{}{}{}{}",
             step.docstring
                 .as_ref()
                 .and_then(|doc| self.verbosity.shows_docstring().then(|| {
@@ -992,19 +1608,20 @@ impl<Out: io::Write> Basic<Out> {
                 .as_ref()
                 .map(|t| format_table(t, self.indent))
                 .unwrap_or_default(),
-            feat.path
-                .as_ref()
-                .and_then(|p| p.to_str().map(trim_path))
-                .unwrap_or(&feat.name),
+            self.format_scenario_header(feat, sc),
+            self.feature_path(feat),
             step.position.line,
             step.position.col,
+            self.format_examples_row(feat, sc),
             loc.map(|l| format!(
                 "\n{indent}   Matched: {}:{}:{}",
-                l.path, l.line, l.column,
+                self.format_path(l.path.into()),
+                l.line,
+                l.column,
             ))
             .unwrap_or_default(),
             format_str_with_indent(
-                err.to_string(),
+                self.truncate_output(&err.to_string()),
                 self.indent.saturating_sub(3) + 3,
             ),
             world
@@ -1015,21 +1632,216 @@ impl<Out: io::Write> Basic<Out> {
                 .unwrap_or_default(),
         ));
 
-        self.write_line(&format!("{step_keyword}{step_value}{diagnostics}"))
+        let duration = style(self.format_step_duration());
+        self.write_line(format!(
+            "{step_keyword}{step_value}{duration}{diagnostics}",
+        ))
+    }
+
+    /// Truncates the given captured `output` to the [`Cli::max_output_lines`],
+    /// if any, keeping its first and last lines.
+    ///
+    /// The whole `output` is written into a temporary file then, which path is
+    /// shown instead of the omitted lines.
+    fn truncate_output<'o>(&self, output: &'o str) -> Cow<'o, str> {
+        let total = output.lines().count();
+        let Some(max) = self.max_output_lines.filter(|max| total > *max) else {
+            return output.into();
+        };
+
+        let head = (max + 1) / 2;
+        let tail = max - head;
+        let saved = save_output(output)
+            .map(|p| {
+                let p = if self.format.golden {
+                    "<temporary file>".into()
+                } else {
+                    p.display().to_string()
+                };
+                format!(", full output: {p}")
+            })
+            .unwrap_or_default();
+        let ellipsis = if self.format.ascii { "..." } else { "…" };
+        let marker = format!(
+            "{ellipsis} {} lines omitted{saved} {ellipsis}",
+            total - max
+        );
+
+        output
+            .lines()
+            .take(head)
+            .chain(Some(marker.as_str()))
+            .chain(output.lines().skip(total - tail))
+            .join("\n")
+            .into()
+    }
+
+    /// Returns the given [`Marker`] as a string, considering the [`Cli::ascii`]
+    /// mode.
+    const fn marker(&self, marker: Marker) -> &'static str {
+        marker.as_str(self.format.ascii)
+    }
+
+    /// Returns the path of the given [`Feature`] to be output, considering the
+    /// [`Cli::absolute_paths`] and [`Cli::golden`] modes.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    fn feature_path<'f>(&self, feat: &'f gherkin::Feature) -> Cow<'f, str> {
+        let path = if self.format.absolute_paths {
+            feat.path.as_deref().map_or_else(
+                || feat.name.as_str().into(),
+                Path::to_string_lossy,
+            )
+        } else {
+            feature_path(feat)
+        };
+        self.format_path(path)
+    }
+
+    /// Formats the given `path` with forward slashes, if the [`Cli::golden`]
+    /// mode is enabled, so the output is the same on all platforms.
+    fn format_path<'p>(&self, path: Cow<'p, str>) -> Cow<'p, str> {
+        if self.format.golden {
+            path.replace('\\', "/").into()
+        } else {
+            path
+        }
+    }
+
+    /// Formats the header naming the [`Feature`] and the [`Scenario`] of a
+    /// failed [`Step`], so the failure is self-contained when grepping logs.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    fn format_scenario_header(
+        &self,
+        feat: &gherkin::Feature,
+        sc: &gherkin::Scenario,
+    ) -> String {
+        format!(
+            "\n{indent}   {} \"{}\" of {} \"{}\" {} {}:{}:{}",
+            sc.keyword,
+            feature::display_name(sc),
+            feat.keyword,
+            feat.name,
+            if self.format.ascii { "-" } else { "—" },
+            self.feature_path(feat),
+            sc.position.line,
+            sc.position.col,
+            indent = " ".repeat(self.indent.saturating_sub(3)),
+        )
+    }
+
+    /// Formats the [`step::Location`] of the definition a passed [`Step`] is
+    /// matched to, if the [`Verbosity`] shows the [`World`].
+    ///
+    /// [`Step`]: gherkin::Step
+    fn format_step_definition(&self, loc: Option<step::Location>) -> String {
+        loc.filter(|_| self.verbosity.shows_world())
+            .map(|l| {
+                format!(
+                    "\n{}   Matched: {}:{}:{}",
+                    " ".repeat(self.indent.saturating_sub(3)),
+                    self.format_path(l.path.into()),
+                    l.line,
+                    l.column,
+                )
+            })
+            .unwrap_or_default()
+    }
+
+    /// Formats the [`Examples`] row the given [`Scenario`] was expanded from,
+    /// if any, to be outputted along with its failed [`Step`].
+    ///
+    /// [`Examples`]: gherkin::Examples
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    fn format_examples_row(
+        &self,
+        feat: &gherkin::Feature,
+        sc: &gherkin::Scenario,
+    ) -> String {
+        feature::examples_row(sc)
+            .map(|row| {
+                format!(
+                    "\n{indent}   Example: {}:{}{}",
+                    self.feature_path(feat),
+                    row.position.line,
+                    format_table(&row, self.indent),
+                    indent = " ".repeat(self.indent.saturating_sub(3)),
+                )
+            })
+            .unwrap_or_default()
     }
 }
 
 /// Tries to coerce [`catch_unwind()`] output to [`String`].
 ///
+/// Besides string payloads, supports [`io::Error`]s and boxed [`Error`]s
+/// passed to [`panic_any()`].
+///
 /// [`catch_unwind()`]: std::panic::catch_unwind()
+/// [`Error`]: std::error::Error
+/// [`panic_any()`]: std::panic::panic_any()
 #[must_use]
 pub(crate) fn coerce_error(err: &Info) -> Cow<'static, str> {
     err.downcast_ref::<String>()
         .map(|s| s.clone().into())
         .or_else(|| err.downcast_ref::<&str>().map(|s| s.to_owned().into()))
+        .or_else(|| {
+            err.downcast_ref::<io::Error>()
+                .map(|e| e.to_string().into())
+        })
+        .or_else(|| {
+            err.downcast_ref::<Box<dyn Error + Send + Sync>>()
+                .map(|e| e.to_string().into())
+        })
         .unwrap_or_else(|| "(Could not resolve panic payload)".into())
 }
 
+/// Writes the given captured `output` into a new temporary file.
+///
+/// The file is created exclusively and has a random name, so can't be
+/// pre-created or symlinked by somebody else.
+///
+/// # Errors
+///
+/// If failed to create or write the file.
+fn save_output(output: &str) -> io::Result<PathBuf> {
+    /// Number of attempts to pick a name not taken yet.
+    const ATTEMPTS: usize = 16;
+
+    let mut last_err = None;
+    for _ in 0..ATTEMPTS {
+        // `RandomState` is randomly seeded, so serves as a source of random
+        // file names without pulling any additional dependencies.
+        let suffix = RandomState::new().build_hasher().finish();
+        let path = env::temp_dir().join(format!(
+            "cucumber-output-{}-{suffix:016x}.log",
+            process::id()
+        ));
+
+        let mut opts = fs::OpenOptions::new();
+        _ = opts.write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            _ = std::os::unix::fs::OpenOptionsExt::mode(&mut opts, 0o600);
+        }
+        match opts.open(&path) {
+            Ok(mut file) => {
+                file.write_all(output.as_bytes())?;
+                return Ok(path);
+            }
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
+                last_err = Some(e);
+            }
+            Err(e) => return Err(e),
+        }
+    }
+    Err(last_err.unwrap_or_else(|| unreachable!("`ATTEMPTS` is not zero")))
+}
+
 /// Formats the given [`str`] by adding `indent`s to each line to prettify the
 /// output.
 fn format_str_with_indent(str: impl AsRef<str>, indent: usize) -> String {
@@ -1045,7 +1857,7 @@ fn format_str_with_indent(str: impl AsRef<str>, indent: usize) -> String {
 
 /// Formats the given [`gherkin::Table`] and adds `indent`s to each line to
 /// prettify the output.
-fn format_table(table: &gherkin::Table, indent: usize) -> String {
+pub(crate) fn format_table(table: &gherkin::Table, indent: usize) -> String {
     let max_row_len = table
         .rows
         .iter()
@@ -1121,7 +1933,25 @@ where
     formatted
 }
 
-/// Trims start of the path if it matches the current project directory.
+/// Returns the [`trim_path()`]ed path of the given [`Feature`], falling back to
+/// its name, if the path is absent.
+///
+/// Allocates only if the path isn't a valid UTF-8, so is displayed lossy.
+///
+/// [`Feature`]: gherkin::Feature
+pub(crate) fn feature_path(feat: &gherkin::Feature) -> Cow<'_, str> {
+    feat.path.as_deref().map_or_else(
+        || feat.name.as_str().into(),
+        |p| match p.to_string_lossy() {
+            Cow::Borrowed(p) => trim_path(p).into(),
+            Cow::Owned(p) => trim_path(&p).to_owned().into(),
+        },
+    )
+}
+
+/// Trims start of the path if it's inside the current project directory.
+///
+/// Paths outside of it (on another drive, for example) are left "as is".
 pub(crate) fn trim_path(path: &str) -> &str {
     /// Path of the current project directory.
     static CURRENT_DIR: Lazy<String> = Lazy::new(|| {
@@ -1134,7 +1964,92 @@ pub(crate) fn trim_path(path: &str) -> &str {
             })
     });
 
-    path.trim_start_matches(&**CURRENT_DIR)
-        .trim_start_matches('/')
-        .trim_start_matches('\\')
+    Some(&**CURRENT_DIR)
+        .filter(|dir| !dir.is_empty())
+        .and_then(|dir| path.strip_prefix(dir))
+        .filter(|rest| rest.is_empty() || rest.starts_with(['/', '\\']))
+        .map_or(path, |rest| rest.trim_start_matches(['/', '\\']))
+}
+
+#[cfg(test)]
+mod feature_path {
+    use std::{borrow::Cow, path::Path};
+
+    use gherkin::GherkinEnv;
+
+    use crate::writer::{Coloring, Verbosity};
+
+    use super::{feature_path, trim_path, Basic, Cli};
+
+    fn feature(path: Option<&Path>) -> gherkin::Feature {
+        let mut f =
+            gherkin::Feature::parse("Feature: name\n", GherkinEnv::default())
+                .unwrap_or_else(|e| panic!("failed to parse feature: {e}"));
+        f.path = path.map(Path::to_path_buf);
+        f
+    }
+
+    #[test]
+    fn trims_current_dir() {
+        let dir = env!("CARGO_MANIFEST_DIR");
+
+        assert_eq!(trim_path(&format!("{dir}/a/b.feature")), "a/b.feature");
+        assert_eq!(
+            feature_path(&feature(Some(&Path::new(dir).join("b.feature")))),
+            "b.feature",
+        );
+    }
+
+    #[test]
+    fn keeps_paths_outside_current_dir() {
+        let dir = env!("CARGO_MANIFEST_DIR");
+
+        for path in [
+            format!("{dir}-other/b.feature"),
+            "/elsewhere/b.feature".to_owned(),
+            r"D:\elsewhere\b.feature".to_owned(),
+        ] {
+            assert_eq!(trim_path(&path), path);
+        }
+    }
+
+    #[test]
+    fn falls_back_to_name() {
+        assert_eq!(feature_path(&feature(None)), "name");
+    }
+
+    #[test]
+    fn does_not_allocate() {
+        let dir = env!("CARGO_MANIFEST_DIR");
+
+        for f in [
+            feature(Some(&Path::new(dir).join("b.feature"))),
+            feature(Some(Path::new("/elsewhere/b.feature"))),
+            feature(None),
+        ] {
+            for _ in 0..3 {
+                assert!(
+                    matches!(feature_path(&f), Cow::Borrowed(_)),
+                    "`feature_path()` allocates for {:?}",
+                    f.path,
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn outputs_absolute_paths() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("b.feature");
+        let f = feature(Some(&path));
+
+        let mut basic =
+            Basic::raw(Vec::new(), Coloring::Never, Verbosity::Default);
+        assert_eq!(basic.feature_path(&f), "b.feature");
+
+        basic.apply_cli(Cli {
+            absolute_paths: true,
+            ..Cli::default()
+        });
+        assert_eq!(basic.feature_path(&f), path.to_string_lossy());
+    }
 }
@@ -69,10 +69,18 @@ where
         self.0.skipped_steps()
     }
 
+    fn pending_steps(&self) -> usize {
+        self.0.pending_steps()
+    }
+
     fn failed_steps(&self) -> usize {
         self.0.failed_steps()
     }
 
+    fn strict_failed_steps(&self) -> usize {
+        self.0.strict_failed_steps()
+    }
+
     fn retried_steps(&self) -> usize {
         self.0.retried_steps()
     }
@@ -85,9 +93,17 @@ where
         self.0.hook_errors()
     }
 
+    fn execution_aborted(&self) -> bool {
+        self.0.execution_aborted()
+    }
+
     fn execution_has_failed(&self) -> bool {
         self.0.execution_has_failed()
     }
+
+    fn outcome(&self) -> writer::Outcome {
+        self.0.outcome()
+    }
 }
 
 #[warn(clippy::missing_trait_methods)]
@@ -160,11 +176,20 @@ where
         0
     }
 
+    fn pending_steps(&self) -> usize {
+        0
+    }
+
     /// Always returns `0`.
     fn failed_steps(&self) -> usize {
         0
     }
 
+    /// Always returns `0`.
+    fn strict_failed_steps(&self) -> usize {
+        0
+    }
+
     /// Always returns `0`.
     fn retried_steps(&self) -> usize {
         0
@@ -179,6 +204,11 @@ where
     fn hook_errors(&self) -> usize {
         0
     }
+
+    /// Always returns `false`.
+    fn execution_aborted(&self) -> bool {
+        false
+    }
 }
 
 #[warn(clippy::missing_trait_methods)]
@@ -19,7 +19,7 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use derive_more::Deref;
 
-use crate::{event, parser, writer, Event, World, Writer};
+use crate::{event, parser, result, writer, Event, World, Writer};
 
 /// [`Writer`]-wrapper for transforming [`Skipped`] [`Step`]s into [`Failed`].
 ///
@@ -38,6 +38,14 @@ pub struct FailOnSkipped<W, F = SkipFn> {
     /// [`Failed`]: event::Step::Failed
     /// [`Skipped`]: event::Step::Skipped
     should_fail: F,
+
+    /// Indicator whether [`Pending`] [`Step`]s should be considered as
+    /// [`Failed`] too.
+    ///
+    /// [`Failed`]: event::Step::Failed
+    /// [`Pending`]: event::Step::Pending
+    /// [`Step`]: gherkin::Step
+    fail_pending: bool,
 }
 
 /// Alias for a [`fn`] used to determine whether [`Skipped`] test should be
@@ -61,6 +69,7 @@ where
 {
     type Cli = Wr::Cli;
 
+    #[allow(clippy::too_many_lines)]
     async fn handle_event(
         &mut self,
         event: parser::Result<Event<event::Cucumber<W>>>,
@@ -68,7 +77,7 @@ where
     ) {
         use event::{
             Cucumber, Feature, RetryableScenario, Rule, Scenario, Step,
-            StepError::NotFound,
+            StepError::{NotFound, Pending},
         };
 
         let map_failed = |f: &Arc<_>, r: &Option<_>, sc: &Arc<_>| {
@@ -91,8 +100,58 @@ where
                 Cucumber::scenario(f, r, sc, ev)
             };
 
-        let event = event.map(|outer| {
+        let map_pending = |f, r, sc, ev: Scenario<W>, retries| {
+            let ev = match ev {
+                Scenario::Background(st, Step::Pending(loc)) => {
+                    Scenario::Background(
+                        st,
+                        Step::Failed(None, loc, None, Pending),
+                    )
+                }
+                Scenario::Step(st, Step::Pending(loc)) => {
+                    Scenario::Step(st, Step::Failed(None, loc, None, Pending))
+                }
+                ev @ (Scenario::Started
+                | Scenario::Hook(..)
+                | Scenario::Background(..)
+                | Scenario::Step(..)
+                | Scenario::Log(_)
+                | Scenario::Finished(_)) => ev,
+            };
+            Cucumber::scenario(f, r, sc, ev.with_retries(retries))
+        };
+
+        let mut event = event.map(|outer| {
             outer.map(|ev| match ev {
+                Cucumber::Feature(
+                    f,
+                    Feature::Rule(
+                        r,
+                        Rule::Scenario(
+                            sc,
+                            RetryableScenario {
+                                event:
+                                    ev @ (Scenario::Background(_, Step::Pending(_))
+                                    | Scenario::Step(_, Step::Pending(_))),
+                                retries,
+                            },
+                        ),
+                    ),
+                ) if self.fail_pending => {
+                    map_pending(f, Some(r), sc, ev, retries)
+                }
+                Cucumber::Feature(
+                    f,
+                    Feature::Scenario(
+                        sc,
+                        RetryableScenario {
+                            event:
+                                ev @ (Scenario::Background(_, Step::Pending(_))
+                                | Scenario::Step(_, Step::Pending(_))),
+                            retries,
+                        },
+                    ),
+                ) if self.fail_pending => map_pending(f, None, sc, ev, retries),
                 Cucumber::Feature(
                     f,
                     Feature::Rule(
@@ -143,14 +202,110 @@ where
                 Cucumber::Started
                 | Cucumber::Feature(..)
                 | Cucumber::ParsingFinished { .. }
+                | Cucumber::DuplicateSteps(_)
+                | Cucumber::UnusedSteps(_)
+                | Cucumber::Aborted
                 | Cucumber::Finished => ev,
             })
         });
+        if let Ok(ev) = &mut event {
+            self.fail_results(ev);
+        }
 
         self.writer.handle_event(event, cli).await;
     }
 }
 
+impl<Wr, F> FailOnSkipped<Wr, F>
+where
+    F: Fn(
+        &gherkin::Feature,
+        Option<&gherkin::Rule>,
+        &gherkin::Scenario,
+    ) -> bool,
+{
+    /// Transforms the [`result::StepResult`]s passed along with the given
+    /// `Finished` [`event::Cucumber`] the same way as [`Step`] events are
+    /// transformed.
+    ///
+    /// [`Step`]: gherkin::Step
+    fn fail_results<W>(&self, ev: &mut event::Cucumber<W>) {
+        use event::{Cucumber, Feature, Rule, Scenario};
+
+        match ev {
+            Cucumber::Feature(f, Feature::Scenario(sc, ev)) => {
+                if let Scenario::Finished(res) = &mut ev.event {
+                    self.fail_scenario(f, None, sc, Arc::make_mut(res));
+                }
+            }
+            Cucumber::Feature(f, Feature::Rule(r, Rule::Scenario(sc, ev))) => {
+                if let Scenario::Finished(res) = &mut ev.event {
+                    self.fail_scenario(f, Some(r), sc, Arc::make_mut(res));
+                }
+            }
+            Cucumber::Feature(f, Feature::Finished(res)) => {
+                for sc_res in &mut Arc::make_mut(res).scenarios {
+                    let found = f
+                        .scenarios
+                        .iter()
+                        .map(|sc| (None, sc))
+                        .chain(f.rules.iter().flat_map(|r| {
+                            r.scenarios.iter().map(move |sc| (Some(r), sc))
+                        }))
+                        .find(|(_, sc)| {
+                            (sc.position.line, sc.position.col)
+                                == (sc_res.line, sc_res.col)
+                        });
+                    if let Some((r, sc)) = found {
+                        self.fail_scenario(f, r, sc, sc_res);
+                    }
+                }
+            }
+            Cucumber::Started
+            | Cucumber::Feature(..)
+            | Cucumber::ParsingFinished { .. }
+            | Cucumber::DuplicateSteps(_)
+            | Cucumber::UnusedSteps(_)
+            | Cucumber::Aborted
+            | Cucumber::Finished => {}
+        }
+    }
+
+    /// Transforms the undefined (and [`Pending`], if required)
+    /// [`result::StepResult`]s of the given [`result::ScenarioResult`] into
+    /// [`Failed`] ones.
+    ///
+    /// [`Failed`]: result::Status::Failed
+    /// [`Pending`]: result::Status::Pending
+    fn fail_scenario(
+        &self,
+        feature: &gherkin::Feature,
+        rule: Option<&gherkin::Rule>,
+        scenario: &gherkin::Scenario,
+        res: &mut result::ScenarioResult,
+    ) {
+        use result::Status;
+
+        let should_fail = (self.should_fail)(feature, rule, scenario);
+        for st in &mut res.steps {
+            let err = match st.status {
+                Status::Skipped if should_fail && st.skip_reason.is_none() => {
+                    event::StepError::NotFound
+                }
+                Status::Pending if self.fail_pending => {
+                    event::StepError::Pending
+                }
+                Status::Passed
+                | Status::Skipped
+                | Status::Pending
+                | Status::Failed => continue,
+            };
+            st.status = Status::Failed;
+            st.error = Some(err.to_string());
+        }
+    }
+}
+
 #[warn(clippy::missing_trait_methods)]
 #[async_trait(?Send)]
 impl<'val, W, Wr, Val, F> writer::Arbitrary<'val, W, Val>
@@ -183,10 +338,18 @@ where
         self.writer.skipped_steps()
     }
 
+    fn pending_steps(&self) -> usize {
+        self.writer.pending_steps()
+    }
+
     fn failed_steps(&self) -> usize {
         self.writer.failed_steps()
     }
 
+    fn strict_failed_steps(&self) -> usize {
+        self.writer.strict_failed_steps()
+    }
+
     fn retried_steps(&self) -> usize {
         self.writer.retried_steps()
     }
@@ -199,9 +362,17 @@ where
         self.writer.hook_errors()
     }
 
+    fn execution_aborted(&self) -> bool {
+        self.writer.execution_aborted()
+    }
+
     fn execution_has_failed(&self) -> bool {
         self.writer.execution_has_failed()
     }
+
+    fn outcome(&self) -> writer::Outcome {
+        self.writer.outcome()
+    }
 }
 
 #[warn(clippy::missing_trait_methods)]
@@ -218,6 +389,7 @@ impl<Writer> From<Writer> for FailOnSkipped<Writer> {
                     .chain(&feat.tags)
                     .any(|t| t == "allow.skipped")
             },
+            fail_pending: false,
         }
     }
 }
@@ -251,6 +423,7 @@ impl<Writer> FailOnSkipped<Writer> {
         FailOnSkipped {
             writer,
             should_fail: predicate,
+            fail_pending: false,
         }
     }
 
@@ -260,3 +433,22 @@ impl<Writer> FailOnSkipped<Writer> {
         &self.writer
     }
 }
+
+impl<Writer, F> FailOnSkipped<Writer, F> {
+    /// Makes this [`FailOnSkipped`] [`Writer`] to transform [`Pending`]
+    /// [`Step`]s (matched by work-in-progress [`Step`] definitions) into
+    /// [`Failed`] ones too.
+    ///
+    /// By default, [`Pending`] [`Step`]s are left as they are, regardless of
+    /// the predicate used for [`Skipped`] [`Step`]s.
+    ///
+    /// [`Failed`]: event::Step::Failed
+    /// [`Pending`]: event::Step::Pending
+    /// [`Skipped`]: event::Step::Skipped
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub const fn fail_on_pending(mut self) -> Self {
+        self.fail_pending = true;
+        self
+    }
+}
@@ -28,11 +28,11 @@ use serde::{Serialize, Serializer};
 
 use crate::{
     cli, event,
-    feature::ExpandExamplesError,
+    feature::{self, ExpandExamplesError},
     parser,
     writer::{
         self,
-        basic::{coerce_error, trim_path},
+        basic::{coerce_error, format_table, trim_path},
         discard, Ext as _,
     },
     Event, World, Writer,
@@ -107,6 +107,9 @@ impl<W: World + Debug, Out: io::Write> Writer<W> for Json<Out> {
             )) => {
                 self.handle_scenario_event(&f, Some(&r), &sc, ev.event, meta);
             }
+            Ok((Cucumber::Aborted, _)) => {
+                self.features.push(Feature::aborted());
+            }
             Ok((Cucumber::Finished, _)) => {
                 self.output
                     .write_all(
@@ -206,8 +209,15 @@ impl<Out: io::Write> Json<Out> {
             Scenario::Log(msg) => {
                 self.logs.push(msg);
             }
-            Scenario::Finished => {
+            Scenario::Finished(res) => {
                 self.logs.clear();
+
+                if res.is_flaky() {
+                    self.mut_or_insert_element(
+                        feature, rule, scenario, "scenario",
+                    )
+                    .flaky = true;
+                }
             }
         }
     }
@@ -319,14 +329,29 @@ impl<Out: io::Write> Json<Out> {
             event::Step::Failed(_, loc, _, err) => {
                 let status = match &err {
                     event::StepError::NotFound => Status::Undefined,
+                    event::StepError::Pending => Status::Pending,
                     event::StepError::AmbiguousMatch(..) => Status::Ambiguous,
-                    event::StepError::Panic(..) => Status::Failed,
+                    event::StepError::Panic(..)
+                    | event::StepError::Timeout(..)
+                    | event::StepError::WorldPoisoned => Status::Failed,
                 };
                 RunResult {
                     status,
                     duration: duration(),
                     error_message: Some(format!(
-                        "{}{err}",
+                        "{}{}{err}",
+                        feature::examples_row(scenario)
+                            .map(|row| format!(
+                                "Example: {}:{}{}\n",
+                                feature
+                                    .path
+                                    .as_ref()
+                                    .and_then(|p| p.to_str().map(trim_path))
+                                    .unwrap_or(&feature.name),
+                                row.position.line,
+                                format_table(&row, 0),
+                            ))
+                            .unwrap_or_default(),
                         loc.map(|l| format!(
                             "Matched: {}:{}:{}\n",
                             l.path, l.line, l.column,
@@ -340,6 +365,16 @@ impl<Out: io::Write> Json<Out> {
                 duration: duration(),
                 error_message: None,
             },
+            event::Step::Pending(_) => RunResult {
+                status: Status::Pending,
+                duration: duration(),
+                error_message: None,
+            },
+            event::Step::SkippedWithReason(_, reason) => RunResult {
+                status: Status::Skipped,
+                duration: duration(),
+                error_message: Some(reason),
+            },
         };
 
         let step = Step {
@@ -495,9 +530,8 @@ pub enum Status {
     /// [`event::Step::Failed`] with an [`event::StepError::NotFound`].
     Undefined,
 
-    /// Never constructed and is here only to fully describe [JSON schema][1].
-    ///
-    /// [1]: https://github.com/cucumber/cucumber-json-schema
+    /// [`event::Step::Pending`] or [`event::Step::Failed`] with an
+    /// [`event::StepError::Pending`].
     Pending,
 }
 
@@ -626,6 +660,15 @@ pub struct Element {
 
     /// [`gherkin::Scenario`]'s [`Step`]s.
     pub steps: Vec<Step>,
+
+    /// Indicator whether this [`gherkin::Scenario`] is flaky, having passed
+    /// only after being retried.
+    ///
+    /// Doesn't appear in the [JSON schema][1], so is omitted unless set.
+    ///
+    /// [1]: https://github.com/cucumber/cucumber-json-schema
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub flaky: bool,
 }
 
 impl Element {
@@ -667,6 +710,7 @@ impl Element {
                 })
                 .collect(),
             steps: vec![],
+            flaky: false,
         }
     }
 }
@@ -751,6 +795,46 @@ impl Feature {
                     },
                     embeddings: vec![],
                 }],
+                flaky: false,
+            }],
+        }
+    }
+
+    /// Creates a new [`Feature`] marking the execution as [`Aborted`].
+    ///
+    /// [`Aborted`]: event::Cucumber::Aborted
+    fn aborted() -> Self {
+        Self {
+            uri: None,
+            keyword: String::new(),
+            name: String::new(),
+            tags: vec![],
+            elements: vec![Element {
+                after: vec![],
+                before: vec![],
+                keyword: String::new(),
+                r#type: "scenario",
+                id: "execution-aborted".into(),
+                line: 0,
+                name: String::new(),
+                tags: vec![],
+                steps: vec![Step {
+                    keyword: String::new(),
+                    line: 0,
+                    name: "scenario".into(),
+                    hidden: false,
+                    result: RunResult {
+                        status: Status::Failed,
+                        duration: 0,
+                        error_message: Some(
+                            "Execution aborted, not all scenarios have been \
+                             run"
+                            .into(),
+                        ),
+                    },
+                    embeddings: vec![],
+                }],
+                flaky: false,
             }],
         }
     }
@@ -794,6 +878,7 @@ impl Feature {
                     },
                     embeddings: vec![],
                 }],
+                flaky: false,
             }],
         }
     }
@@ -12,7 +12,7 @@
 //!
 //! [1]: https://llg.cubic.org/docs/junit
 
-use std::{fmt::Debug, io, mem, time::SystemTime};
+use std::{fmt::Debug, io, mem};
 
 use async_trait::async_trait;
 use junit_report::{
@@ -20,10 +20,11 @@ use junit_report::{
 };
 
 use crate::{
-    event, parser,
+    event, feature, parser,
+    result::{ScenarioResult, Status},
     writer::{
         self,
-        basic::{coerce_error, trim_path, Coloring},
+        basic::{trim_path, Coloring},
         discard,
         out::WritableString,
         Ext as _, Verbosity,
@@ -51,6 +52,10 @@ pub struct Cli {
 /// [JUnit XML report][1] [`Writer`] implementation outputting XML to an
 /// [`io::Write`] implementor.
 ///
+/// Flaky [`Scenario`]s (passed only after being retried) are marked with a
+/// `system-err` of their `testcase`s, as [JUnit XML report][1] has no
+/// dedicated attribute for them.
+///
 /// # Ordering
 ///
 /// This [`Writer`] isn't [`Normalized`] by itself, so should be wrapped into
@@ -58,6 +63,7 @@ pub struct Cli {
 /// form correct [JUnit `testsuite`s][1].
 ///
 /// [`Normalized`]: writer::Normalized
+/// [`Scenario`]: gherkin::Scenario
 /// [1]: https://llg.cubic.org/docs/junit
 #[derive(Debug)]
 pub struct JUnit<W, Out: io::Write> {
@@ -74,11 +80,6 @@ pub struct JUnit<W, Out: io::Write> {
     /// [1]: https://llg.cubic.org/docs/junit
     suit: Option<TestSuite>,
 
-    /// [`SystemTime`] when the current [`Scenario`] has started.
-    ///
-    /// [`Scenario`]: gherkin::Scenario
-    scenario_started_at: Option<SystemTime>,
-
     /// Current [`Scenario`] [events][1].
     ///
     /// [`Scenario`]: gherkin::Scenario
@@ -97,7 +98,6 @@ impl<World, Out: Clone + io::Write> Clone for JUnit<World, Out> {
             output: self.output.clone(),
             report: self.report.clone(),
             suit: self.suit.clone(),
-            scenario_started_at: self.scenario_started_at,
             events: self.events.clone(),
             verbosity: self.verbosity,
         }
@@ -123,7 +123,13 @@ where
 
         match ev.map(Event::split) {
             Err(err) => self.handle_error(&err),
-            Ok((Cucumber::Started | Cucumber::ParsingFinished { .. }, _)) => {}
+            Ok((
+                Cucumber::Started
+                | Cucumber::ParsingFinished { .. }
+                | Cucumber::DuplicateSteps(..)
+                | Cucumber::UnusedSteps(..),
+                _,
+            )) => {}
             Ok((Cucumber::Feature(feat, ev), meta)) => match ev {
                 Feature::Started => {
                     self.suit = Some(
@@ -141,13 +147,11 @@ where
                     );
                 }
                 Feature::Rule(_, Rule::Started | Rule::Finished) => {}
-                Feature::Rule(r, Rule::Scenario(sc, ev)) => {
-                    self.handle_scenario_event(&feat, Some(&r), &sc, ev, meta);
-                }
-                Feature::Scenario(sc, ev) => {
-                    self.handle_scenario_event(&feat, None, &sc, ev, meta);
+                Feature::Rule(_, Rule::Scenario(sc, ev))
+                | Feature::Scenario(sc, ev) => {
+                    self.handle_scenario_event(&feat, &sc, ev);
                 }
-                Feature::Finished => {
+                Feature::Finished(_) => {
                     let suite = self.suit.take().unwrap_or_else(|| {
                         panic!(
                             "No `TestSuit` for `Feature` \"{}\"\n{WRAP_ADVICE}",
@@ -157,6 +161,18 @@ where
                     self.report.add_testsuite(suite);
                 }
             },
+            Ok((Cucumber::Aborted, _)) => {
+                self.report.add_testsuite(
+                    TestSuiteBuilder::new("Aborted")
+                        .add_testcase(TestCase::error(
+                            "Execution aborted",
+                            Duration::ZERO,
+                            "Aborted",
+                            "not all scenarios have been run",
+                        ))
+                        .build(),
+                );
+            }
             Ok((Cucumber::Finished, _)) => {
                 self.report
                     .write_xml(&mut self.output)
@@ -214,7 +230,6 @@ impl<W: Debug, Out: io::Write> JUnit<W, Out> {
             output,
             report: Report::new(),
             suit: None,
-            scenario_started_at: None,
             events: vec![],
             verbosity: verbosity.into(),
         }
@@ -278,28 +293,22 @@ impl<W: Debug, Out: io::Write> JUnit<W, Out> {
     fn handle_scenario_event(
         &mut self,
         feat: &gherkin::Feature,
-        rule: Option<&gherkin::Rule>,
         sc: &gherkin::Scenario,
         ev: event::RetryableScenario<W>,
-        meta: Event<()>,
     ) {
         use event::Scenario;
 
         match &ev.event {
-            Scenario::Started => {
-                self.scenario_started_at = Some(meta.at);
-                self.events.push(ev);
-            }
-            Scenario::Log(_)
+            Scenario::Started
+            | Scenario::Log(_)
             | Scenario::Hook(..)
             | Scenario::Background(..)
             | Scenario::Step(..) => {
                 self.events.push(ev);
             }
-            Scenario::Finished => {
-                let dur = self.scenario_duration(meta.at, sc);
+            Scenario::Finished(res) => {
                 let events = mem::take(&mut self.events);
-                let case = self.test_case(feat, rule, sc, &events, dur);
+                let case = self.test_case(feat, sc, res, &events);
 
                 self.suit
                     .as_mut()
@@ -315,86 +324,68 @@ impl<W: Debug, Out: io::Write> JUnit<W, Out> {
         }
     }
 
-    /// Forms a [`TestCase`] on [`event::Scenario::Finished`].
+    /// Forms a [`TestCase`] out of the given [`ScenarioResult`] on
+    /// [`event::Scenario::Finished`].
+    ///
+    /// The raw `events` are used only for rendering the `system-out` of the
+    /// [`TestCase`] via [`writer::Basic`].
     fn test_case(
         &self,
         feat: &gherkin::Feature,
-        rule: Option<&gherkin::Rule>,
         sc: &gherkin::Scenario,
+        res: &ScenarioResult,
         events: &[event::RetryableScenario<W>],
-        duration: Duration,
     ) -> TestCase {
-        use event::{Hook, HookType, Scenario, Step};
-
-        let last_event = events
-            .iter()
-            .rev()
-            .find(|ev| {
-                !matches!(
-                    ev.event,
-                    Scenario::Log(_)
-                        | Scenario::Hook(
-                            HookType::After,
-                            Hook::Passed | Hook::Started,
-                        ),
-                )
-            })
-            .unwrap_or_else(|| {
-                panic!(
-                    "No events for `Scenario` \"{}\"\n{WRAP_ADVICE}",
-                    sc.name,
-                )
-            });
-
         let case_name = format!(
             "{}Scenario: {}: {}{}:{}",
-            rule.map(|r| format!("Rule: {}: ", r.name))
+            res.rule
+                .as_ref()
+                .map(|r| format!("Rule: {r}: "))
                 .unwrap_or_default(),
-            sc.name,
-            feat.path
+            feature::display_name(sc),
+            res.path
                 .as_ref()
                 .and_then(|p| p.to_str().map(trim_path))
                 .map(|path| format!("{path}:"))
                 .unwrap_or_default(),
-            sc.position.line,
-            sc.position.col,
+            res.line,
+            res.col,
         );
 
-        let mut case = match &last_event.event {
-            Scenario::Started
-            | Scenario::Log(_)
-            | Scenario::Hook(_, Hook::Started | Hook::Passed)
-            | Scenario::Background(_, Step::Started | Step::Passed(_, _))
-            | Scenario::Step(_, Step::Started | Step::Passed(_, _)) => {
+        let duration = Duration::try_from(res.duration).unwrap_or_else(|e| {
+            panic!(
+                "Cannot covert `std::time::Duration` to `time::Duration`: {e}",
+            )
+        });
+
+        let mut case = match res.status() {
+            Status::Passed => {
                 TestCaseBuilder::success(&case_name, duration).build()
             }
-            Scenario::Background(_, Step::Skipped)
-            | Scenario::Step(_, Step::Skipped) => {
+            Status::Skipped | Status::Pending => {
                 TestCaseBuilder::skipped(&case_name).build()
             }
-            Scenario::Hook(_, Hook::Failed(_, e)) => TestCaseBuilder::failure(
-                &case_name,
-                duration,
-                "Hook Panicked",
-                coerce_error(e).as_ref(),
-            )
-            .build(),
-            Scenario::Background(_, Step::Failed(_, _, _, e))
-            | Scenario::Step(_, Step::Failed(_, _, _, e)) => {
-                TestCaseBuilder::failure(
-                    &case_name,
-                    duration,
-                    "Step Panicked",
-                    &e.to_string(),
-                )
-                .build()
-            }
-            Scenario::Finished => {
-                panic!(
-                    "Duplicated `Finished` event for `Scenario`: \"{}\"\n\
-                     {WRAP_ADVICE}",
-                    sc.name,
-                );
+            Status::Failed => {
+                // Failed `After` hook is the last thing happening in a
+                // `Scenario`, so it takes precedence over a failed `Step`.
+                let (ty, msg) =
+                    res.hook_errors
+                        .last()
+                        .map(|(_, e)| ("Hook Panicked", e.as_str()))
+                        .or_else(|| {
+                            res.steps.iter().find_map(|st| {
+                                st.error
+                                    .as_deref()
+                                    .map(|e| ("Step Panicked", e))
+                            })
+                        })
+                        .unwrap_or_else(|| {
+                            panic!(
+                                "No error for failed `Scenario` \"{}\"",
+                                sc.name,
+                            )
+                        });
+                TestCaseBuilder::failure(&case_name, duration, ty, msg).build()
             }
         };
 
@@ -417,36 +408,14 @@ impl<W: Debug, Out: io::Write> JUnit<W, Out> {
             });
 
         case.set_system_out(&output);
+        if let Some(retries) = res.retries.filter(|_| res.is_flaky()) {
+            case.set_system_err(&format!(
+                "Flaky: passed on retry attempt {}/{}",
+                retries.current,
+                retries.left + retries.current,
+            ));
+        }
 
         case
     }
-
-    /// Returns [`Scenario`]'s [`Duration`] on [`event::Scenario::Finished`].
-    ///
-    /// [`Scenario`]: gherkin::Scenario
-    fn scenario_duration(
-        &mut self,
-        ended: SystemTime,
-        sc: &gherkin::Scenario,
-    ) -> Duration {
-        let started_at = self.scenario_started_at.take().unwrap_or_else(|| {
-            panic!(
-                "No `Started` event for `Scenario` \"{}\"\n{WRAP_ADVICE}",
-                sc.name,
-            )
-        });
-        Duration::try_from(ended.duration_since(started_at).unwrap_or_else(
-            |e| {
-                panic!(
-                    "Failed to compute duration between {ended:?} and \
-                     {started_at:?}: {e}",
-                )
-            },
-        ))
-        .unwrap_or_else(|e| {
-            panic!(
-                "Cannot covert `std::time::Duration` to `time::Duration`: {e}",
-            )
-        })
-    }
 }
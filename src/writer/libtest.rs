@@ -28,7 +28,7 @@ use serde::Serialize;
 use crate::{
     cli,
     event::{self, Retries},
-    parser,
+    feature, parser,
     writer::{
         self,
         basic::{coerce_error, trim_path},
@@ -160,6 +160,13 @@ pub struct Libtest<W, Out: io::Write = io::Stdout> {
     /// [`Step`]: gherkin::Step
     failed: usize,
 
+    /// Number of [`Step`]s failed only because of being undefined or
+    /// [pending] (already counted in [`Libtest::failed`]).
+    ///
+    /// [pending]: event::Step::Pending
+    /// [`Step`]: gherkin::Step
+    strict_failed: usize,
+
     /// Number of retried [`Step`]s.
     ///
     /// [`Step`]: gherkin::Step
@@ -180,6 +187,13 @@ pub struct Libtest<W, Out: io::Write = io::Stdout> {
     /// [`Hook`]: event::Hook
     hook_errors: usize,
 
+    /// Indicator whether execution has been [`Aborted`] before all the
+    /// [`Scenario`]s have been run.
+    ///
+    /// [`Aborted`]: event::Cucumber::Aborted
+    /// [`Scenario`]: gherkin::Scenario
+    aborted: bool,
+
     /// Number of [`Feature`]s with [`path`] set to [`None`].
     ///
     /// This value is used to generate a unique name for each [`Feature`] to
@@ -212,10 +226,12 @@ impl<World, Out: Clone + io::Write> Clone for Libtest<World, Out> {
             parsed_all: self.parsed_all,
             passed: self.passed,
             failed: self.failed,
+            strict_failed: self.strict_failed,
             retried: self.retried,
             ignored: self.ignored,
             parsing_errors: self.parsing_errors,
             hook_errors: self.hook_errors,
+            aborted: self.aborted,
             features_without_path: self.features_without_path,
             started_at: self.started_at,
             step_started_at: self.step_started_at,
@@ -323,9 +339,11 @@ impl<W: Debug + World, Out: io::Write> Libtest<W, Out> {
             parsed_all: false,
             passed: 0,
             failed: 0,
+            strict_failed: 0,
             retried: 0,
             parsing_errors: 0,
             hook_errors: 0,
+            aborted: false,
             ignored: 0,
             features_without_path: 0,
             started_at: None,
@@ -381,6 +399,11 @@ impl<W: Debug + World, Out: io::Write> Libtest<W, Out> {
                 }))
                 .unwrap_or_else(|e| panic!("Failed to write: {e}"));
         }
+        // Flushing eagerly, so the consuming tool sees the events as soon as
+        // possible, and nothing is lost if the process is killed.
+        self.output
+            .flush()
+            .unwrap_or_else(|e| panic!("Failed to flush: {e}"));
     }
 
     /// Converts the provided [`event::Cucumber`] into [`LibTestJsonEvent`]s.
@@ -409,6 +432,20 @@ impl<W: Debug + World, Out: io::Write> Libtest<W, Out> {
                 }
                 .into()]
             }
+            Ok((Cucumber::DuplicateSteps(_) | Cucumber::UnusedSteps(_), _)) => {
+                Vec::new()
+            }
+            Ok((Cucumber::Aborted, _)) => {
+                self.aborted = true;
+
+                let name = "Execution aborted".to_owned();
+                vec![
+                    TestEvent::started(name.clone()).into(),
+                    TestEvent::failed(name, None)
+                        .with_stdout("not all scenarios have been run".into())
+                        .into(),
+                ]
+            }
             Ok((Cucumber::Finished, meta)) => {
                 let exec_time = self
                     .started_at
@@ -416,8 +453,10 @@ impl<W: Debug + World, Out: io::Write> Libtest<W, Out> {
                     .as_ref()
                     .map(Duration::as_secs_f64);
 
-                let failed =
-                    self.failed + self.parsing_errors + self.hook_errors;
+                let failed = self.failed
+                    + self.parsing_errors
+                    + self.hook_errors
+                    + usize::from(self.aborted);
                 let results = SuiteResults {
                     passed: self.passed,
                     failed,
@@ -478,7 +517,7 @@ impl<W: Debug + World, Out: io::Write> Libtest<W, Out> {
 
         match ev {
             Feature::Started
-            | Feature::Finished
+            | Feature::Finished(_)
             | Feature::Rule(_, Rule::Started | Rule::Finished) => Vec::new(),
             Feature::Rule(rule, Rule::Scenario(scenario, ev)) => self
                 .expand_scenario_event(
@@ -508,7 +547,7 @@ impl<W: Debug + World, Out: io::Write> Libtest<W, Out> {
 
         let retries = ev.retries;
         match ev.event {
-            Scenario::Started | Scenario::Finished => Vec::new(),
+            Scenario::Started | Scenario::Finished(_) => Vec::new(),
             Scenario::Hook(ty, ev) => self.expand_hook_event(
                 feature, rule, scenario, ty, ev, retries, meta, cli,
             ),
@@ -578,7 +617,7 @@ impl<W: Debug + World, Out: io::Write> Libtest<W, Out> {
     }
 
     /// Converts the provided [`event::Step`] into [`LibTestJsonEvent`]s.
-    #[allow(clippy::too_many_arguments)]
+    #[allow(clippy::too_many_arguments, clippy::too_many_lines)]
     fn expand_step_event(
         &mut self,
         feature: &gherkin::Feature,
@@ -630,6 +669,56 @@ impl<W: Debug + World, Out: io::Write> Libtest<W, Out> {
                     event
                 }
             }
+            Step::Pending(loc) => {
+                self.ignored += 1;
+
+                let event =
+                    TestEvent::ignored(name, self.step_exec_time(meta, cli));
+                if cli.show_output {
+                    event.with_stdout(format!(
+                        "{}:{}:{} (defined){}\npending",
+                        feature
+                            .path
+                            .as_ref()
+                            .and_then(|p| p.to_str().map(trim_path))
+                            .unwrap_or(&feature.name),
+                        step.position.line,
+                        step.position.col,
+                        loc.map(|l| format!(
+                            "\n{}:{}:{} (matched)",
+                            l.path, l.line, l.column,
+                        ))
+                        .unwrap_or_default()
+                    ))
+                } else {
+                    event
+                }
+            }
+            Step::SkippedWithReason(loc, reason) => {
+                self.ignored += 1;
+
+                let event =
+                    TestEvent::ignored(name, self.step_exec_time(meta, cli));
+                if cli.show_output {
+                    event.with_stdout(format!(
+                        "{}:{}:{} (defined){}\nskipped: {reason}",
+                        feature
+                            .path
+                            .as_ref()
+                            .and_then(|p| p.to_str().map(trim_path))
+                            .unwrap_or(&feature.name),
+                        step.position.line,
+                        step.position.col,
+                        loc.map(|l| format!(
+                            "\n{}:{}:{} (matched)",
+                            l.path, l.line, l.column,
+                        ))
+                        .unwrap_or_default()
+                    ))
+                } else {
+                    event
+                }
+            }
             Step::Skipped => {
                 self.ignored += 1;
 
@@ -660,25 +749,36 @@ impl<W: Debug + World, Out: io::Write> Libtest<W, Out> {
                     self.retried += 1;
                 } else {
                     self.failed += 1;
+                    if matches!(
+                        err,
+                        event::StepError::NotFound | event::StepError::Pending,
+                    ) {
+                        self.strict_failed += 1;
+                    }
                 }
 
-                TestEvent::failed(name, self.step_exec_time(meta, cli))
-                    .with_stdout(format!(
-                        "{}:{}:{} (defined){}\n{err}{}",
-                        feature
-                            .path
-                            .as_ref()
-                            .and_then(|p| p.to_str().map(trim_path))
-                            .unwrap_or(&feature.name),
-                        step.position.line,
-                        step.position.col,
-                        loc.map(|l| format!(
-                            "\n{}:{}:{} (matched)",
-                            l.path, l.line, l.column,
-                        ))
-                        .unwrap_or_default(),
-                        world.map(|w| format!("\n{w:#?}")).unwrap_or_default(),
+                let exec_time = self.step_exec_time(meta, cli);
+                if matches!(err, event::StepError::Timeout(_)) {
+                    TestEvent::timeout(name, exec_time)
+                } else {
+                    TestEvent::failed(name, exec_time)
+                }
+                .with_stdout(format!(
+                    "{}:{}:{} (defined){}\n{err}{}",
+                    feature
+                        .path
+                        .as_ref()
+                        .and_then(|p| p.to_str().map(trim_path))
+                        .unwrap_or(&feature.name),
+                    step.position.line,
+                    step.position.col,
+                    loc.map(|l| format!(
+                        "\n{}:{}:{} (matched)",
+                        l.path, l.line, l.column,
                     ))
+                    .unwrap_or_default(),
+                    world.map(|w| format!("\n{w:#?}")).unwrap_or_default(),
+                ))
             }
         };
 
@@ -717,7 +817,7 @@ impl<W: Debug + World, Out: io::Write> Libtest<W, Out> {
             "{}: {}: {}{}",
             scenario.position.line,
             scenario.keyword,
-            scenario.name,
+            feature::display_name(scenario),
             retries
                 .filter(|r| r.current > 0)
                 .map(|r| format!(
@@ -803,6 +903,10 @@ where
         self.failed
     }
 
+    fn strict_failed_steps(&self) -> usize {
+        self.strict_failed
+    }
+
     fn retried_steps(&self) -> usize {
         self.retried
     }
@@ -814,6 +918,10 @@ where
     fn hook_errors(&self) -> usize {
         self.hook_errors
     }
+
+    fn execution_aborted(&self) -> bool {
+        self.aborted
+    }
 }
 
 #[async_trait(?Send)]
@@ -952,7 +1060,6 @@ impl TestEvent {
     }
 
     /// Creates a new [`TestEvent::Timeout`].
-    #[allow(dead_code)]
     fn timeout(name: String, exec_time: Option<Duration>) -> Self {
         Self::Timeout(TestEventInner::new(name).with_exec_time(exec_time))
     }
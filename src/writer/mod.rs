@@ -25,9 +25,12 @@ pub mod normalize;
 pub mod or;
 pub mod out;
 pub mod repeat;
+pub mod rerun;
 pub mod summarize;
 pub mod tee;
 
+use std::{path::PathBuf, process::ExitCode};
+
 use async_trait::async_trait;
 use sealed::sealed;
 
@@ -49,6 +52,7 @@ pub use self::{
     normalize::{AssertNormalized, Normalize, Normalized},
     or::Or,
     repeat::Repeat,
+    rerun::Rerun,
     summarize::{Summarizable, Summarize},
     tee::Tee,
 };
@@ -125,6 +129,16 @@ pub trait Stats<World>: Writer<World> {
     #[must_use]
     fn skipped_steps(&self) -> usize;
 
+    /// Returns number of [`Pending`] [`Step`]s (already counted in
+    /// [`Stats::skipped_steps()`]).
+    ///
+    /// [`Pending`]: event::Step::Pending
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    fn pending_steps(&self) -> usize {
+        0
+    }
+
     /// Returns number of [`Failed`] [`Step`]s.
     ///
     /// [`Failed`]: event::Step::Failed
@@ -132,6 +146,19 @@ pub trait Stats<World>: Writer<World> {
     #[must_use]
     fn failed_steps(&self) -> usize;
 
+    /// Returns number of [`Failed`] [`Step`]s (already counted in
+    /// [`Stats::failed_steps()`]), which have failed only because of being
+    /// undefined or [`Pending`] in a strict mode (either by a
+    /// [`FailOnSkipped`] [`Writer`] or in a dry run).
+    ///
+    /// [`Failed`]: event::Step::Failed
+    /// [`Pending`]: event::Step::Pending
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    fn strict_failed_steps(&self) -> usize {
+        0
+    }
+
     /// Returns number of retried [`Step`]s.
     ///
     /// [`Step`]: gherkin::Step
@@ -148,12 +175,127 @@ pub trait Stats<World>: Writer<World> {
     #[must_use]
     fn hook_errors(&self) -> usize;
 
-    /// Indicates whether there were failures/errors during execution.
+    /// Indicates whether execution has been [`Aborted`] before all the
+    /// [`Scenario`]s have been run.
+    ///
+    /// [`Aborted`]: event::Cucumber::Aborted
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    fn execution_aborted(&self) -> bool {
+        false
+    }
+
+    /// Indicates whether there were failures/errors during execution, or it
+    /// has been aborted.
     #[must_use]
     fn execution_has_failed(&self) -> bool {
         self.failed_steps() > 0
             || self.parsing_errors() > 0
             || self.hook_errors() > 0
+            || self.execution_aborted()
+    }
+
+    /// Returns the [`Outcome`] of execution, convertible into an [`ExitCode`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::{path::PathBuf, process::ExitCode};
+    /// #
+    /// # use cucumber::{StatsWriter as _, World};
+    /// #
+    /// # #[derive(Debug, Default, World)]
+    /// # struct MyWorld;
+    /// #
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() -> ExitCode {
+    /// let writer = MyWorld::cucumber().run("tests/features/readme").await;
+    /// writer.outcome().into()
+    /// # }
+    /// ```
+    #[must_use]
+    fn outcome(&self) -> Outcome {
+        if self.execution_aborted() {
+            Outcome::Aborted
+        } else if self.parsing_errors() > 0 || self.hook_errors() > 0 {
+            Outcome::SetupFailed
+        } else if self.failed_steps() > self.strict_failed_steps() {
+            Outcome::Failed
+        } else if self.strict_failed_steps() > 0 {
+            Outcome::Undefined
+        } else {
+            Outcome::Passed
+        }
+    }
+}
+
+/// Outcome of execution, as reported by a [`Stats`] [`Writer`].
+///
+/// Each [`Outcome`] has its own distinct [`Outcome::exit_code()`], so CI is
+/// able to tell failed tests from a misconfigured suite.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Outcome {
+    /// Everything has passed.
+    ///
+    /// Exit code is `0`.
+    Passed,
+
+    /// At least one [`Step`] has [`Failed`].
+    ///
+    /// Exit code is `1`.
+    ///
+    /// [`Failed`]: event::Step::Failed
+    /// [`Step`]: gherkin::Step
+    Failed,
+
+    /// No [`Step`] has [`Failed`] on its own, but there were undefined or
+    /// [`Pending`] [`Step`]s treated as [`Failed`] in a strict mode (by a
+    /// [`FailOnSkipped`] [`Writer`] or in a dry run).
+    ///
+    /// Exit code is `2`.
+    ///
+    /// [`Failed`]: event::Step::Failed
+    /// [`Pending`]: event::Step::Pending
+    /// [`Step`]: gherkin::Step
+    Undefined,
+
+    /// There were parsing errors or failed [`Scenario`] hooks, so the suite
+    /// itself is misconfigured. Takes precedence over any other [`Outcome`],
+    /// except [`Outcome::Aborted`].
+    ///
+    /// Exit code is `3`.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    SetupFailed,
+
+    /// Execution has been [`Aborted`] (on receiving `SIGINT`, for example), so
+    /// not all the [`Scenario`]s have been run. Takes precedence over any other
+    /// [`Outcome`].
+    ///
+    /// Exit code is `130` (as of a process terminated by `SIGINT`).
+    ///
+    /// [`Aborted`]: event::Cucumber::Aborted
+    /// [`Scenario`]: gherkin::Scenario
+    Aborted,
+}
+
+impl Outcome {
+    /// Returns the process exit code of this [`Outcome`].
+    #[must_use]
+    pub const fn exit_code(self) -> u8 {
+        match self {
+            Self::Passed => 0,
+            Self::Failed => 1,
+            Self::Undefined => 2,
+            Self::SetupFailed => 3,
+            Self::Aborted => 130,
+        }
+    }
+}
+
+impl From<Outcome> for ExitCode {
+    fn from(outcome: Outcome) -> Self {
+        outcome.exit_code().into()
     }
 }
 
@@ -242,6 +384,15 @@ pub trait Ext: Sized {
     where
         F: Fn(&parser::Result<Event<event::Cucumber<W>>>) -> bool;
 
+    /// Wraps this [`Writer`] to write a rerun file of failed [`Scenario`]s
+    /// into the provided `path` at the end of an output.
+    ///
+    /// See [`Rerun`] for more information.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    fn rerun_file(self, path: impl Into<PathBuf>) -> Rerun<Self>;
+
     /// Attaches the provided `other` [`Writer`] to the current one for passing
     /// events to both of them simultaneously.
     #[must_use]
@@ -314,6 +465,10 @@ impl<T> Ext for T {
         Repeat::new(self, filter)
     }
 
+    fn rerun_file(self, path: impl Into<PathBuf>) -> Rerun<Self> {
+        Rerun::new(self, path)
+    }
+
     fn tee<W, Wr: Writer<W>>(self, other: Wr) -> Tee<Self, Wr> {
         Tee::new(self, other)
     }
@@ -381,17 +536,14 @@ impl<T> Ext for T {
 /// ```
 ///
 /// ```rust
-/// # use std::panic::AssertUnwindSafe;
-/// #
-/// # use cucumber::{writer, World, WriterExt as _};
-/// # use futures::FutureExt as _;
+/// # use cucumber::{writer, StatsWriter as _, World, WriterExt as _};
 /// #
 /// # #[derive(Debug, Default, World)]
 /// # struct MyWorld;
 /// #
 /// # #[tokio::main(flavor = "current_thread")]
 /// # async fn main() {
-/// # let fut = async {
+/// # let writer =
 /// MyWorld::cucumber()
 ///     .with_writer(
 ///         // `Writer`s pipeline is constructed in a reversed order.
@@ -400,15 +552,9 @@ impl<T> Ext for T {
 ///             .summarized()       // Only then, count summary for them.
 ///             .fail_on_skipped(), // First, transform skipped steps to failed.
 ///     )
-///     .run_and_exit("tests/features/readme")
+///     .run("tests/features/readme")
 ///     .await;
-/// # };
-/// # let err = AssertUnwindSafe(fut)
-/// #     .catch_unwind()
-/// #     .await
-/// #     .expect_err("should err");
-/// # let err = err.downcast_ref::<String>().unwrap();
-/// # assert_eq!(err, "1 step failed");
+/// # assert_eq!(writer.failed_steps(), 1);
 /// # }
 /// ```
 ///
@@ -425,9 +571,10 @@ pub enum Verbosity {
     Default = 0,
 
     /// Outputs the whole [`World`] on [`Failed`] [`Step`]s whenever is
-    /// possible.
+    /// possible, and definitions matched by [`Passed`] [`Step`]s.
     ///
     /// [`Failed`]: event::Step::Failed
+    /// [`Passed`]: event::Step::Passed
     /// [`Step`]: gherkin::Step
     /// [`World`]: crate::World
     ShowWorld = 1,
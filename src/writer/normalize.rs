@@ -19,7 +19,7 @@ use linked_hash_map::LinkedHashMap;
 
 use crate::{
     event::{self, Metadata, Retries},
-    parser, writer, Event, World, Writer,
+    parser, result, writer, Event, World, Writer,
 };
 
 /// Wrapper for a [`Writer`] implementation for outputting events corresponding
@@ -102,7 +102,11 @@ impl<World, Wr: Writer<World>> Writer<World> for Normalize<World, Wr> {
         match event.map(Event::split) {
             res @ (Err(_)
             | Ok((
-                Cucumber::Started | Cucumber::ParsingFinished { .. },
+                Cucumber::Started
+                | Cucumber::ParsingFinished { .. }
+                | Cucumber::DuplicateSteps(_)
+                | Cucumber::UnusedSteps(_)
+                | Cucumber::Aborted,
                 _,
             ))) => {
                 self.writer
@@ -120,7 +124,9 @@ impl<World, Wr: Writer<World>> Writer<World> for Normalize<World, Wr> {
                         meta.wrap(ev),
                     );
                 }
-                Feature::Finished => self.queue.feature_finished(meta.wrap(&f)),
+                Feature::Finished(res) => {
+                    self.queue.feature_finished(&f, meta.wrap(res));
+                }
                 Feature::Rule(r, ev) => match ev {
                     Rule::Started => self.queue.new_rule(&f, meta.wrap(r)),
                     Rule::Scenario(s, ev) => {
@@ -181,10 +187,18 @@ where
         self.writer.skipped_steps()
     }
 
+    fn pending_steps(&self) -> usize {
+        self.writer.pending_steps()
+    }
+
     fn failed_steps(&self) -> usize {
         self.writer.failed_steps()
     }
 
+    fn strict_failed_steps(&self) -> usize {
+        self.writer.strict_failed_steps()
+    }
+
     fn retried_steps(&self) -> usize {
         self.writer.retried_steps()
     }
@@ -197,9 +211,17 @@ where
         self.writer.hook_errors()
     }
 
+    fn execution_aborted(&self) -> bool {
+        self.writer.execution_aborted()
+    }
+
     fn execution_has_failed(&self) -> bool {
         self.writer.execution_has_failed()
     }
+
+    fn outcome(&self) -> writer::Outcome {
+        self.writer.outcome()
+    }
 }
 
 #[warn(clippy::missing_trait_methods)]
@@ -304,10 +326,18 @@ where
         self.0.skipped_steps()
     }
 
+    fn pending_steps(&self) -> usize {
+        self.0.pending_steps()
+    }
+
     fn failed_steps(&self) -> usize {
         self.0.failed_steps()
     }
 
+    fn strict_failed_steps(&self) -> usize {
+        self.0.strict_failed_steps()
+    }
+
     fn retried_steps(&self) -> usize {
         self.0.retried_steps()
     }
@@ -320,9 +350,17 @@ where
         self.0.hook_errors()
     }
 
+    fn execution_aborted(&self) -> bool {
+        self.0.execution_aborted()
+    }
+
     fn execution_has_failed(&self) -> bool {
         self.0.execution_has_failed()
     }
+
+    fn outcome(&self) -> writer::Outcome {
+        self.0.outcome()
+    }
 }
 
 #[warn(clippy::missing_trait_methods)]
@@ -345,7 +383,7 @@ impl<Writer> Normalized for AssertNormalized<Writer> {}
 ///
 /// [`next()`]: Iterator::next()
 #[derive(Clone, Debug)]
-struct Queue<K: Eq + Hash, V> {
+struct Queue<K: Eq + Hash, V, F = ()> {
     /// Underlying FIFO queue of values.
     queue: LinkedHashMap<K, V>,
 
@@ -356,10 +394,10 @@ struct Queue<K: Eq + Hash, V> {
     initial: Option<Metadata>,
 
     /// [`FinishedState`] of this [`Queue`].
-    state: FinishedState,
+    state: FinishedState<F>,
 }
 
-impl<K: Eq + Hash, V> Queue<K, V> {
+impl<K: Eq + Hash, V, F> Queue<K, V, F> {
     /// Creates a new normalization [`Queue`] with an initial metadata.
     fn new(initial: Metadata) -> Self {
         Self {
@@ -372,8 +410,8 @@ impl<K: Eq + Hash, V> Queue<K, V> {
     /// Marks this [`Queue`] as [`FinishedButNotEmitted`].
     ///
     /// [`FinishedButNotEmitted`]: FinishedState::FinishedButNotEmitted
-    fn finished(&mut self, meta: Metadata) {
-        self.state = FinishedState::FinishedButNotEmitted(meta);
+    fn finished(&mut self, ev: Event<F>) {
+        self.state = FinishedState::FinishedButNotEmitted(ev);
     }
 
     /// Checks whether this [`Queue`] transited to [`FinishedAndEmitted`] state.
@@ -390,8 +428,10 @@ impl<K: Eq + Hash, V> Queue<K, V> {
 }
 
 /// Finishing state of a [`Queue`].
-#[derive(Clone, Copy, Debug)]
-enum FinishedState {
+///
+/// `F` is a value the `Finished` event carries (if any).
+#[derive(Clone, Debug)]
+enum FinishedState<F = ()> {
     /// `Finished` event hasn't been encountered yet.
     NotFinished,
 
@@ -399,22 +439,22 @@ enum FinishedState {
     /// [`Writer`] yet.
     ///
     /// This happens when output is busy due to outputting some other item.
-    FinishedButNotEmitted(Metadata),
+    FinishedButNotEmitted(Event<F>),
 
     /// `Finished` event has been encountered and passed to the inner
     /// [`Writer`].
     FinishedAndEmitted,
 }
 
-impl FinishedState {
-    /// Returns [`Metadata`] of this [`FinishedState::FinishedButNotEmitted`],
-    /// and makes it [`FinishedAndEmitted`].
+impl<F> FinishedState<F> {
+    /// Returns [`Event`] of this [`FinishedState::FinishedButNotEmitted`], and
+    /// makes it [`FinishedAndEmitted`].
     ///
     /// [`FinishedAndEmitted`]: FinishedState::FinishedAndEmitted
-    fn take_to_emit(&mut self) -> Option<Metadata> {
+    fn take_to_emit(&mut self) -> Option<Event<F>> {
         let current = mem::replace(self, Self::FinishedAndEmitted);
-        if let Self::FinishedButNotEmitted(meta) = current {
-            Some(meta)
+        if let Self::FinishedButNotEmitted(ev) = current {
+            Some(ev)
         } else {
             *self = current;
             None
@@ -498,12 +538,15 @@ impl<World> CucumberQueue<World> {
     /// [`Feature`]s holding the output.
     ///
     /// [`Feature`]: gherkin::Feature
-    fn feature_finished(&mut self, feat: Event<&gherkin::Feature>) {
-        let (feat, meta) = feat.split();
+    fn feature_finished(
+        &mut self,
+        feat: &gherkin::Feature,
+        res: Event<Arc<result::FeatureResult>>,
+    ) {
         self.queue
             .get_mut(feat)
             .unwrap_or_else(|| panic!("No Feature {}", feat.name))
-            .finished(meta);
+            .finished(res);
     }
 
     /// Inserts a new [`Rule`] on [`event::Rule::Started`].
@@ -589,12 +632,15 @@ impl<'me, World> Emitter<World> for &'me mut CucumberQueue<World> {
                 events.remove(&scenario_or_rule_to_remove);
             }
 
-            if let Some(meta) = events.state.take_to_emit() {
+            if let Some(res) = events.state.take_to_emit() {
                 writer
                     .handle_event(
-                        Ok(meta.wrap(event::Cucumber::feature_finished(
-                            Arc::clone(&f),
-                        ))),
+                        Ok(res.map(|res| {
+                            event::Cucumber::feature_finished(
+                                Arc::clone(&f),
+                                res,
+                            )
+                        })),
                         cli,
                     )
                     .await;
@@ -632,7 +678,11 @@ type NextRuleOrScenario<'events, World> = Either<
 /// [`Queue`] of all events of a single [`Feature`].
 ///
 /// [`Feature`]: gherkin::Feature
-type FeatureQueue<World> = Queue<RuleOrScenario, RuleOrScenarioQueue<World>>;
+type FeatureQueue<World> = Queue<
+    RuleOrScenario,
+    RuleOrScenarioQueue<World>,
+    Arc<result::FeatureResult>,
+>;
 
 impl<World> FeatureQueue<World> {
     /// Inserts a new [`Rule`].
@@ -847,7 +897,7 @@ impl<World> Emitter<World> for &mut ScenariosQueue<World> {
     ) -> Option<Self::Emitted> {
         while let Some((ev, meta)) = self.current_item().map(Event::split) {
             let should_be_removed =
-                matches!(ev.event, event::Scenario::Finished)
+                matches!(ev.event, event::Scenario::Finished(_))
                     .then(|| ev.retries);
 
             let ev = meta.wrap(event::Cucumber::scenario(
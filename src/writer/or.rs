@@ -101,10 +101,18 @@ where
         self.left.skipped_steps() + self.right.skipped_steps()
     }
 
+    fn pending_steps(&self) -> usize {
+        self.left.pending_steps() + self.right.pending_steps()
+    }
+
     fn failed_steps(&self) -> usize {
         self.left.failed_steps() + self.right.failed_steps()
     }
 
+    fn strict_failed_steps(&self) -> usize {
+        self.left.strict_failed_steps() + self.right.strict_failed_steps()
+    }
+
     fn retried_steps(&self) -> usize {
         self.left.retried_steps() + self.right.retried_steps()
     }
@@ -116,6 +124,10 @@ where
     fn hook_errors(&self) -> usize {
         self.left.hook_errors() + self.right.hook_errors()
     }
+
+    fn execution_aborted(&self) -> bool {
+        self.left.execution_aborted() || self.right.execution_aborted()
+    }
 }
 
 #[warn(clippy::missing_trait_methods)]
@@ -12,15 +12,29 @@
 
 use std::{
     borrow::Cow,
+    env,
     io::{self, IsTerminal},
     mem, str,
+    sync::atomic::{AtomicBool, Ordering},
 };
 
 use console::Style;
 use derive_more::{Deref, DerefMut, Display, From, Into};
 
+use crate::diagnostics;
+
 use super::Coloring;
 
+/// Name of the environment variable overriding the detected [`Term`] width.
+///
+/// [`Term`]: console::Term
+pub const TERM_WIDTH_ENV_VAR: &str = "CUCUMBER_TERM_WIDTH";
+
+/// Minimal [`Term`] width, the smaller ones are clamped to.
+///
+/// [`Term`]: console::Term
+pub const MIN_TERM_WIDTH: u16 = 40;
+
 /// [`Style`]s for terminal output.
 #[derive(Clone, Debug)]
 pub struct Styles {
@@ -38,6 +52,11 @@ pub struct Styles {
     /// [`Scenario`]: gherkin::Scenario
     pub retry: Style,
 
+    /// [`Style`] for rendering timed out [`Step`]s.
+    ///
+    /// [`Step`]: gherkin::Step
+    pub timeout: Style,
+
     /// [`Style`] for rendering header.
     pub header: Style,
 
@@ -46,6 +65,10 @@ pub struct Styles {
 
     /// [`Term`] width.
     ///
+    /// Detected automatically, unless overridden with the
+    /// [`TERM_WIDTH_ENV_VAR`] environment variable or
+    /// [`Styles::override_term_width()`].
+    ///
     /// [`Term`]: console::Term
     pub term_width: Option<u16>,
 
@@ -60,9 +83,15 @@ impl Default for Styles {
             skipped: Style::new().cyan(),
             err: Style::new().red(),
             retry: Style::new().magenta(),
+            timeout: Style::new().yellow(),
             header: Style::new().blue(),
             bold: Style::new().bold(),
-            term_width: console::Term::stdout().size_checked().map(|(_h, w)| w),
+            term_width: term_width_from_env().or_else(|| {
+                console::Term::stdout()
+                    .size_checked()
+                    .map(|(_h, w)| w)
+                    .filter(|w| *w > 0)
+            }),
             is_present: io::stdout().is_terminal() && console::colors_enabled(),
         }
     }
@@ -88,11 +117,20 @@ impl Styles {
         self.skipped = this.skipped.force_styling(is_present);
         self.err = this.err.force_styling(is_present);
         self.retry = this.retry.force_styling(is_present);
+        self.timeout = this.timeout.force_styling(is_present);
         self.header = this.header.force_styling(is_present);
         self.bold = this.bold.force_styling(is_present);
         self.is_present = is_present;
     }
 
+    /// Overrides the detected [`Term`] width with the given one, clamping it to
+    /// [`MIN_TERM_WIDTH`] (with a warning).
+    ///
+    /// [`Term`]: console::Term
+    pub fn override_term_width(&mut self, width: u16) {
+        self.term_width = Some(clamp_term_width(width));
+    }
+
     /// Returns [`Styles`] with brighter colors.
     #[must_use]
     pub fn bright(&self) -> Self {
@@ -101,6 +139,7 @@ impl Styles {
             skipped: self.skipped.clone().bright(),
             err: self.err.clone().bright(),
             retry: self.retry.clone().bright(),
+            timeout: self.timeout.clone().bright(),
             header: self.header.clone().bright(),
             bold: self.bold.clone().bright(),
             term_width: self.term_width,
@@ -152,6 +191,17 @@ impl Styles {
         }
     }
 
+    /// If terminal is present colors `input` with [`Styles::timeout`] color or
+    /// leaves "as is" otherwise.
+    #[must_use]
+    pub fn timeout<'a>(&self, input: impl Into<Cow<'a, str>>) -> Cow<'a, str> {
+        if self.is_present {
+            self.timeout.apply_to(input.into()).to_string().into()
+        } else {
+            input.into()
+        }
+    }
+
     /// If terminal is present colors `input` with [`Styles::header`] color or
     /// leaves "as is" otherwise.
     #[must_use]
@@ -177,6 +227,10 @@ impl Styles {
     /// Returns number of lines for the provided `s`tring, considering wrapping
     /// because of the [`Term`] width.
     ///
+    /// Lines are measured by their display width, so multi-byte characters
+    /// and ANSI escape codes don't overestimate it. Zero [`Term`] width is
+    /// considered unknown, so no wrapping is assumed.
+    ///
     /// [`Term`]: console::Term
     #[must_use]
     pub fn lines_count(&self, s: impl AsRef<str>) -> usize {
@@ -191,16 +245,55 @@ impl Styles {
                 d
             }
         };
+        let width = self.term_width.filter(|w| *w > 0);
         s.as_ref()
             .lines()
             .map(|l| {
-                self.term_width
-                    .map_or(1, |w| div_ceil(l.len(), usize::from(w)))
+                width.map_or(1, |w| {
+                    div_ceil(console::measure_text_width(l), usize::from(w))
+                })
             })
             .sum()
     }
 }
 
+/// Parses the [`TERM_WIDTH_ENV_VAR`] environment variable, if it's set.
+///
+/// Invalid values are ignored with a warning.
+fn term_width_from_env() -> Option<u16> {
+    let width = env::var(TERM_WIDTH_ENV_VAR).ok()?;
+    width
+        .trim()
+        .parse()
+        .map(clamp_term_width)
+        .map_err(|e| {
+            diagnostics::emit(format_args!(
+                "ignoring invalid `{TERM_WIDTH_ENV_VAR}` value \"{width}\": {e}",
+            ));
+        })
+        .ok()
+}
+
+/// Clamps the given [`Term`] width to [`MIN_TERM_WIDTH`], warning about it
+/// once.
+///
+/// [`Term`]: console::Term
+fn clamp_term_width(width: u16) -> u16 {
+    /// Indicator whether the warning has been already emitted.
+    static WARNED: AtomicBool = AtomicBool::new(false);
+
+    if width < MIN_TERM_WIDTH {
+        if !WARNED.swap(true, Ordering::Relaxed) {
+            diagnostics::emit(format_args!(
+                "terminal width {width} is too small, using {MIN_TERM_WIDTH}",
+            ));
+        }
+        MIN_TERM_WIDTH
+    } else {
+        width
+    }
+}
+
 /// [`io::Write`] extension for easier manipulation with strings and special
 /// sequences.
 pub trait WriteStrExt: io::Write {
@@ -120,10 +120,18 @@ where
         self.writer.skipped_steps()
     }
 
+    fn pending_steps(&self) -> usize {
+        self.writer.pending_steps()
+    }
+
     fn failed_steps(&self) -> usize {
         self.writer.failed_steps()
     }
 
+    fn strict_failed_steps(&self) -> usize {
+        self.writer.strict_failed_steps()
+    }
+
     fn retried_steps(&self) -> usize {
         self.writer.retried_steps()
     }
@@ -136,9 +144,17 @@ where
         self.writer.hook_errors()
     }
 
+    fn execution_aborted(&self) -> bool {
+        self.writer.execution_aborted()
+    }
+
     fn execution_has_failed(&self) -> bool {
         self.writer.execution_has_failed()
     }
+
+    fn outcome(&self) -> writer::Outcome {
+        self.writer.outcome()
+    }
 }
 
 #[warn(clippy::missing_trait_methods)]
@@ -183,19 +199,34 @@ impl<W, Wr> Repeat<W, Wr> {
                             Rule::Scenario(
                                 _,
                                 RetryableScenario {
-                                    event: Scenario::Step(_, Step::Skipped)
-                                        | Scenario::Background(
-                                            _,
-                                            Step::Skipped
-                                        ),
+                                    event: Scenario::Step(
+                                        _,
+                                        Step::Skipped
+                                            | Step::Pending(_)
+                                            | Step::SkippedWithReason(..)
+                                    ) | Scenario::Background(
+                                        _,
+                                        Step::Skipped
+                                            | Step::Pending(_)
+                                            | Step::SkippedWithReason(..)
+                                    ),
                                     ..
                                 }
                             )
                         ) | Feature::Scenario(
                             _,
                             RetryableScenario {
-                                event: Scenario::Step(_, Step::Skipped)
-                                    | Scenario::Background(_, Step::Skipped),
+                                event: Scenario::Step(
+                                    _,
+                                    Step::Skipped
+                                        | Step::Pending(_)
+                                        | Step::SkippedWithReason(..)
+                                ) | Scenario::Background(
+                                    _,
+                                    Step::Skipped
+                                        | Step::Pending(_)
+                                        | Step::SkippedWithReason(..)
+                                ),
                                 ..
                             }
                         )
@@ -0,0 +1,272 @@
+// Copyright (c) 2018-2023  Brendan Molloy <brendan@bbqsrc.net>,
+//                          Ilya Solovyiov <ilya.solovyiov@gmail.com>,
+//                          Kai Ren <tyranron@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [`Writer`]-wrapper for writing a rerun file of failed [`Scenario`]s.
+//!
+//! [`Scenario`]: gherkin::Scenario
+
+use std::{fs, path::PathBuf};
+
+use async_trait::async_trait;
+use derive_more::Deref;
+
+use crate::{
+    diagnostics, event, feature, parser,
+    writer::{self, basic::feature_path},
+    Event, World, Writer,
+};
+
+/// Wrapper for a [`Writer`] writing a rerun file of failed [`Scenario`]s at
+/// the end of an execution.
+///
+/// The rerun file lists one `path:line` entry per failed [`Scenario`], so the
+/// next run may execute only them, by passing the rerun file prefixed with `@`
+/// as an input of a [`parser::Basic`]:
+/// ```rust
+/// # use cucumber::{writer, World, WriterExt as _};
+/// #
+/// # #[derive(Debug, Default, World)]
+/// # struct MyWorld;
+/// #
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// # let dir = tempfile::tempdir().unwrap();
+/// # let rerun = dir.path().join("rerun.txt");
+/// MyWorld::cucumber()
+///     .with_writer(
+///         writer::Basic::stdout()
+///             .summarized()
+///             .normalized()
+///             .rerun_file(&rerun),
+///     )
+///     .run("tests/features/readme")
+///     .await;
+///
+/// MyWorld::cucumber()
+///     .run(format!("@{}", rerun.display()))
+///     .await;
+/// # }
+/// ```
+///
+/// A [`Scenario`] is considered failed once its [`Step`] or hook has failed
+/// with no retries left. The rerun file is written even if nothing has failed,
+/// so the stale entries of a previous run are never re-run.
+///
+/// [`parser::Basic`]: crate::parser::Basic
+/// [`Scenario`]: gherkin::Scenario
+/// [`Step`]: gherkin::Step
+#[derive(Clone, Debug, Deref)]
+pub struct Rerun<Wr> {
+    /// Original [`Writer`].
+    #[deref]
+    writer: Wr,
+
+    /// Path of the rerun file to write.
+    path: PathBuf,
+
+    /// `path:line` entries of the failed [`Scenario`]s, in their encounter
+    /// order.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    failed: Vec<String>,
+}
+
+#[async_trait(?Send)]
+impl<W, Wr> Writer<W> for Rerun<Wr>
+where
+    W: World,
+    Wr: Writer<W>,
+{
+    type Cli = Wr::Cli;
+
+    async fn handle_event(
+        &mut self,
+        event: parser::Result<Event<event::Cucumber<W>>>,
+        cli: &Self::Cli,
+    ) {
+        use event::{Cucumber, Feature, Rule};
+
+        match event.as_deref() {
+            Ok(Cucumber::Feature(
+                f,
+                Feature::Scenario(sc, ev)
+                | Feature::Rule(_, Rule::Scenario(sc, ev)),
+            )) => self.handle_scenario(f, sc, ev),
+            Ok(Cucumber::Finished) => self.write_file(),
+            Ok(_) | Err(_) => {}
+        }
+
+        self.writer.handle_event(event, cli).await;
+    }
+}
+
+#[warn(clippy::missing_trait_methods)]
+#[async_trait(?Send)]
+impl<'val, W, Wr, Val> writer::Arbitrary<'val, W, Val> for Rerun<Wr>
+where
+    W: World,
+    Wr: writer::Arbitrary<'val, W, Val>,
+    Val: 'val,
+{
+    async fn write(&mut self, val: Val)
+    where
+        'val: 'async_trait,
+    {
+        self.writer.write(val).await;
+    }
+}
+
+#[warn(clippy::missing_trait_methods)]
+impl<W, Wr> writer::Stats<W> for Rerun<Wr>
+where
+    Wr: writer::Stats<W>,
+    Self: Writer<W>,
+{
+    fn passed_steps(&self) -> usize {
+        self.writer.passed_steps()
+    }
+
+    fn skipped_steps(&self) -> usize {
+        self.writer.skipped_steps()
+    }
+
+    fn pending_steps(&self) -> usize {
+        self.writer.pending_steps()
+    }
+
+    fn failed_steps(&self) -> usize {
+        self.writer.failed_steps()
+    }
+
+    fn strict_failed_steps(&self) -> usize {
+        self.writer.strict_failed_steps()
+    }
+
+    fn retried_steps(&self) -> usize {
+        self.writer.retried_steps()
+    }
+
+    fn parsing_errors(&self) -> usize {
+        self.writer.parsing_errors()
+    }
+
+    fn hook_errors(&self) -> usize {
+        self.writer.hook_errors()
+    }
+
+    fn execution_aborted(&self) -> bool {
+        self.writer.execution_aborted()
+    }
+
+    fn execution_has_failed(&self) -> bool {
+        self.writer.execution_has_failed()
+    }
+
+    fn outcome(&self) -> writer::Outcome {
+        self.writer.outcome()
+    }
+}
+
+#[warn(clippy::missing_trait_methods)]
+impl<Wr: writer::Normalized> writer::Normalized for Rerun<Wr> {}
+
+#[warn(clippy::missing_trait_methods)]
+impl<Wr: writer::NonTransforming> writer::NonTransforming for Rerun<Wr> {}
+
+impl<Wr> Rerun<Wr> {
+    /// Wraps the given [`Writer`] to write a rerun file of failed
+    /// [`Scenario`]s into the provided `path`.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn new(writer: Wr, path: impl Into<PathBuf>) -> Self {
+        Self {
+            writer,
+            path: path.into(),
+            failed: Vec::new(),
+        }
+    }
+
+    /// Returns the original [`Writer`], wrapped by this [`Rerun`] one.
+    #[must_use]
+    pub const fn inner_writer(&self) -> &Wr {
+        &self.writer
+    }
+
+    /// Returns `path:line` entries of the [`Scenario`]s failed so far.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub fn failed_scenarios(&self) -> &[String] {
+        &self.failed
+    }
+
+    /// Records the given [`Scenario`] as failed, if the provided event is its
+    /// final failure.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    fn handle_scenario<W>(
+        &mut self,
+        feature: &gherkin::Feature,
+        scenario: &gherkin::Scenario,
+        ev: &event::RetryableScenario<W>,
+    ) {
+        use event::{Hook, Scenario, Step, StepError};
+
+        let is_failed = match &ev.event {
+            Scenario::Step(_, Step::Failed(.., err))
+            | Scenario::Background(_, Step::Failed(.., err)) => {
+                matches!(err, StepError::NotFound)
+                    || ev.retries.map_or(true, |r| r.left == 0)
+            }
+            Scenario::Hook(_, Hook::Failed(..)) => {
+                ev.retries.map_or(true, |r| r.left == 0)
+            }
+            Scenario::Started
+            | Scenario::Hook(..)
+            | Scenario::Background(..)
+            | Scenario::Step(..)
+            | Scenario::Log(_)
+            | Scenario::Finished(_) => false,
+        };
+        if !is_failed || feature.path.is_none() {
+            return;
+        }
+
+        let entry = format!(
+            "{}:{}",
+            feature_path(feature),
+            feature::source_line(scenario)
+        );
+        if !self.failed.contains(&entry) {
+            self.failed.push(entry);
+        }
+    }
+
+    /// Writes the rerun file with all the failed [`Scenario`]s.
+    ///
+    /// Failure to write it is reported into [`io::Stderr`], without affecting
+    /// the execution itself.
+    ///
+    /// [`io::Stderr`]: std::io::Stderr
+    /// [`Scenario`]: gherkin::Scenario
+    fn write_file(&self) {
+        let mut contents = self.failed.join("\n");
+        if !contents.is_empty() {
+            contents.push('\n');
+        }
+        if let Err(e) = fs::write(&self.path, contents) {
+            diagnostics::emit(format_args!(
+                "failed to write rerun file {}: {e}",
+                self.path.display(),
+            ));
+        }
+    }
+}
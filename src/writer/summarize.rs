@@ -10,7 +10,7 @@
 
 //! [`Writer`]-wrapper for collecting a summary of execution.
 
-use std::{borrow::Cow, collections::HashMap, sync::Arc};
+use std::{any, borrow::Cow, collections::HashMap, iter, sync::Arc};
 
 use async_trait::async_trait;
 use derive_more::Deref;
@@ -20,8 +20,12 @@ use crate::{
     cli::Colored,
     event,
     event::Retries,
-    parser,
-    writer::{self, out::Styles},
+    feature, parser, step,
+    writer::{
+        self,
+        basic::{self, trim_path},
+        out::Styles,
+    },
     Event, World, Writer,
 };
 
@@ -38,6 +42,9 @@ pub struct Stats {
 
     /// Number of skipped [`Step`]s (or [`Scenario`]s).
     ///
+    /// Includes [pending] [`Step`]s too.
+    ///
+    /// [pending]: event::Step::Pending
     /// [`Scenario`]: gherkin::Scenario
     /// [`Step`]: gherkin::Step
     pub skipped: usize,
@@ -158,6 +165,26 @@ pub struct Summarize<Writer> {
     /// [`Step`]: gherkin::Step
     steps: Stats,
 
+    /// Number of [pending] [`Step`]s (already counted as skipped ones in the
+    /// [`Summarize::steps`]).
+    ///
+    /// [pending]: event::Step::Pending
+    /// [`Step`]: gherkin::Step
+    pending_steps: usize,
+
+    /// Number of [`Step`]s failed only because of being undefined or
+    /// [pending] (already counted as failed ones in the [`Summarize::steps`]).
+    ///
+    /// [pending]: event::Step::Pending
+    /// [`Step`]: gherkin::Step
+    strict_failed_steps: usize,
+
+    /// Number of [`Scenario`]s filtered out and not run (by a tag expression,
+    /// for example).
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    filtered_scenarios: usize,
+
     /// Number of [`Parser`] errors.
     ///
     /// [`Parser`]: crate::Parser
@@ -168,6 +195,63 @@ pub struct Summarize<Writer> {
     /// [`Scenario`]: gherkin::Scenario
     failed_hooks: usize,
 
+    /// Number of [`Scenario`]s failed because of a failed [`Background`]
+    /// [`Step`] (already counted as failed ones in the
+    /// [`Summarize::scenarios`]).
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    background_failures: usize,
+
+    /// Number of retried [`Scenario`]s, which have passed eventually (already
+    /// counted as passed ones in the [`Summarize::scenarios`]).
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    flaky_scenarios: usize,
+
+    /// Failed [`Scenario`]s, in their failure order.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    failed_scenarios: Vec<ScenarioPath>,
+
+    /// [`Step`]s without a matching definition, in their encounter order,
+    /// to output snippets for.
+    ///
+    /// [`Step`]: gherkin::Step
+    undefined_steps: Vec<(gherkin::StepType, String)>,
+
+    /// Finally failed [`Step`]s, in their encounter order, to output a digest
+    /// of.
+    ///
+    /// [`Step`]: gherkin::Step
+    failures: Vec<Digested>,
+
+    /// Undefined [`Step`]s occurrences, in their encounter order, to output a
+    /// digest of.
+    ///
+    /// [`Step`]: gherkin::Step
+    undefined: Vec<Digested>,
+
+    /// Indicator whether [`Step`]s are only resolved without being executed,
+    /// so the undefined ones fail the execution.
+    ///
+    /// [`Step`]: gherkin::Step
+    dry_run: bool,
+
+    /// Seed the [`Scenario`]s have been shuffled with, if they're run in a
+    /// random order.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    seed: Option<u64>,
+
+    /// Indicator whether execution has been [`Aborted`] before all the
+    /// [`Scenario`]s have been run.
+    ///
+    /// [`Aborted`]: event::Cucumber::Aborted
+    /// [`Scenario`]: gherkin::Scenario
+    aborted: bool,
+
     /// Current [`State`] of this [`Writer`].
     state: State,
 
@@ -175,24 +259,59 @@ pub struct Summarize<Writer> {
     ///
     /// [`Scenario`]: gherkin::Scenario
     handled_scenarios: HandledScenarios,
+
+    /// [`Runs`] of each finished [`Scenario`], to output a report of the ones
+    /// failed in a repeated execution.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    runs: ScenariosRuns,
 }
 
-/// [`HashMap`] for keeping track of handled [`Scenario`]s. Whole path with
-/// [`Feature`] and [`Rule`] is used to avoid collisions in case [`Scenario`]s
-/// themself look identical.
+/// [`HashMap`] of [`Runs`] of finished [`Scenario`]s, keyed the same way as
+/// [`HandledScenarios`].
 ///
-/// [`Feature`]: gherkin::Feature
-/// [`Rule`]: gherkin::Rule
 /// [`Scenario`]: gherkin::Scenario
-type HandledScenarios = HashMap<
+type ScenariosRuns = HashMap<
     (
         Arc<gherkin::Feature>,
         Option<Arc<gherkin::Rule>>,
         Arc<gherkin::Scenario>,
     ),
-    Indicator,
+    Runs,
 >;
 
+/// Number of times a [`Scenario`] has finished, and failed among them.
+///
+/// [`Scenario`]: gherkin::Scenario
+#[derive(Clone, Copy, Debug, Default)]
+struct Runs {
+    /// Total number of finished runs.
+    total: usize,
+
+    /// Number of failed runs.
+    failed: usize,
+}
+
+/// [`HashMap`] for keeping track of handled [`Scenario`]s. Whole path with
+/// [`Feature`] and [`Rule`] is used to avoid collisions in case [`Scenario`]s
+/// themself look identical.
+///
+/// [`Feature`]: gherkin::Feature
+/// [`Rule`]: gherkin::Rule
+/// [`Scenario`]: gherkin::Scenario
+type HandledScenarios = HashMap<ScenarioPath, Indicator>;
+
+/// [`Feature`], [`Rule`] and [`Scenario`] identifying a [`Scenario`].
+///
+/// [`Feature`]: gherkin::Feature
+/// [`Rule`]: gherkin::Rule
+/// [`Scenario`]: gherkin::Scenario
+type ScenarioPath = (
+    Arc<gherkin::Feature>,
+    Option<Arc<gherkin::Rule>>,
+    Arc<gherkin::Scenario>,
+);
+
 #[async_trait(?Send)]
 impl<W, Wr> Writer<W> for Summarize<Wr>
 where
@@ -237,12 +356,27 @@ where
                             ev,
                         );
                     }
-                    Feature::Finished | Feature::Rule(..) => {}
+                    Feature::Finished(_) | Feature::Rule(..) => {}
                 },
+                Ok(Cucumber::Aborted) => self.aborted = true,
                 Ok(Cucumber::Finished) => {
                     self.state = State::FinishedButNotOutput;
                 }
-                Ok(Cucumber::Started | Cucumber::ParsingFinished { .. }) => {}
+                Ok(Cucumber::ParsingFinished {
+                    filtered_scenarios,
+                    dry_run,
+                    seed,
+                    ..
+                }) => {
+                    self.filtered_scenarios = *filtered_scenarios;
+                    self.dry_run = *dry_run;
+                    self.seed = *seed;
+                }
+                Ok(
+                    Cucumber::Started
+                    | Cucumber::DuplicateSteps(_)
+                    | Cucumber::UnusedSteps(_),
+                ) => {}
             };
         }
 
@@ -253,7 +387,18 @@ where
 
             let mut styles = Styles::new();
             styles.apply_coloring(cli.coloring());
+            if !self.failures.is_empty() || !self.undefined.is_empty() {
+                self.writer.write(styles.digest(self)).await;
+            }
+            let repeated = styles.repeated(self);
+            if !repeated.is_empty() {
+                self.writer.write(repeated).await;
+            }
             self.writer.write(styles.summary(self)).await;
+            if !self.undefined_steps.is_empty() {
+                let snippets = styles.snippets::<W>(&self.undefined_steps);
+                self.writer.write(snippets).await;
+            }
         }
     }
 }
@@ -288,8 +433,26 @@ where
         self.steps.skipped
     }
 
+    fn pending_steps(&self) -> usize {
+        self.pending_steps
+    }
+
     fn failed_steps(&self) -> usize {
-        self.steps.failed
+        // Undefined `Step`s are reported as skipped ones in a dry run too, but
+        // checking them is its whole point.
+        if self.dry_run {
+            self.steps.failed + self.undefined.len()
+        } else {
+            self.steps.failed
+        }
+    }
+
+    fn strict_failed_steps(&self) -> usize {
+        if self.dry_run {
+            self.strict_failed_steps + self.undefined.len()
+        } else {
+            self.strict_failed_steps
+        }
     }
 
     fn retried_steps(&self) -> usize {
@@ -303,6 +466,10 @@ where
     fn hook_errors(&self) -> usize {
         self.failed_hooks
     }
+
+    fn execution_aborted(&self) -> bool {
+        self.aborted
+    }
 }
 
 #[warn(clippy::missing_trait_methods)]
@@ -329,10 +496,23 @@ impl<Writer> From<Writer> for Summarize<Writer> {
                 failed: 0,
                 retried: 0,
             },
+            pending_steps: 0,
+            strict_failed_steps: 0,
+            filtered_scenarios: 0,
             parsing_errors: 0,
             failed_hooks: 0,
+            background_failures: 0,
+            flaky_scenarios: 0,
+            failed_scenarios: Vec::new(),
+            undefined_steps: Vec::new(),
+            failures: Vec::new(),
+            undefined: Vec::new(),
+            dry_run: false,
+            seed: None,
+            aborted: false,
             state: State::InProgress,
             handled_scenarios: HashMap::new(),
+            runs: HashMap::new(),
         }
     }
 }
@@ -359,20 +539,40 @@ impl<Writer> Summarize<Writer> {
             Step::Started => {}
             Step::Passed(..) => {
                 self.steps.passed += 1;
-                if scenario.steps.last().filter(|s| *s == step).is_some() {
-                    _ = self
-                        .handled_scenarios
-                        .remove(&(feature, rule, scenario));
+                // Only a retried `Scenario` may pass its last `Step` after being
+                // indicated, while a dry-run keeps resolving `Step`s of the
+                // already failed or skipped one.
+                let path = (feature, rule, scenario);
+                if path.2.steps.last().is_some_and(|s| s == step)
+                    && matches!(
+                        self.handled_scenarios.get(&path),
+                        Some(Retried)
+                    )
+                {
+                    _ = self.handled_scenarios.remove(&path);
+                    self.flaky_scenarios += 1;
                 }
             }
             Step::Skipped => {
+                self.undefined
+                    .push(Digested::new(&feature, &scenario, step, None));
+                let undefined = (step.ty, step.value.clone());
+                if !self.undefined_steps.contains(&undefined) {
+                    self.undefined_steps.push(undefined);
+                }
                 self.steps.skipped += 1;
-                self.scenarios.skipped += 1;
-                _ = self
-                    .handled_scenarios
-                    .insert((feature, rule, scenario), Skipped);
+                self.skip_scenario((feature, rule, scenario));
             }
-            Step::Failed(_, _, _, err) => {
+            Step::Pending(_) => {
+                self.steps.skipped += 1;
+                self.pending_steps += 1;
+                self.skip_scenario((feature, rule, scenario));
+            }
+            Step::SkippedWithReason(..) => {
+                self.steps.skipped += 1;
+                self.skip_scenario((feature, rule, scenario));
+            }
+            Step::Failed(_, loc, _, err) => {
                 if retries
                     .filter(|r| {
                         r.left > 0 && !matches!(err, event::StepError::NotFound)
@@ -390,16 +590,54 @@ impl<Writer> Summarize<Writer> {
                     }
                 } else {
                     self.steps.failed += 1;
-                    self.scenarios.failed += 1;
-
-                    _ = self
-                        .handled_scenarios
-                        .insert((feature, rule, scenario), Failed);
+                    if matches!(
+                        err,
+                        event::StepError::NotFound | event::StepError::Pending,
+                    ) {
+                        self.strict_failed_steps += 1;
+                    }
+                    self.failures
+                        .push(Digested::new(&feature, &scenario, step, *loc));
+
+                    let path = (feature, rule, scenario);
+                    match self.handled_scenarios.insert(path.clone(), Failed) {
+                        Some(Failed) => {}
+                        Some(Skipped) => {
+                            self.scenarios.skipped -= 1;
+                            self.fail_scenario(path);
+                        }
+                        Some(Retried) | None => self.fail_scenario(path),
+                    }
                 }
             }
         }
     }
 
+    /// Indicates the [`Scenario`] at the given `path` as [`Failed`].
+    ///
+    /// [`Failed`]: Indicator::Failed
+    /// [`Scenario`]: gherkin::Scenario
+    fn fail_scenario(&mut self, path: ScenarioPath) {
+        self.scenarios.failed += 1;
+        self.failed_scenarios.push(path);
+    }
+
+    /// Indicates the [`Scenario`] at the given `path` as [`Skipped`], unless
+    /// it's already indicated as [`Failed`] or [`Skipped`].
+    ///
+    /// [`Failed`]: event::Step::Failed
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Skipped`]: event::Step::Skipped
+    fn skip_scenario(&mut self, path: ScenarioPath) {
+        match self.handled_scenarios.get(&path) {
+            Some(Indicator::Failed | Indicator::Skipped) => {}
+            Some(Indicator::Retried) | None => {
+                self.scenarios.skipped += 1;
+                _ = self.handled_scenarios.insert(path, Indicator::Skipped);
+            }
+        }
+    }
+
     /// Keeps track of [`Scenario`]'s [`Stats`].
     ///
     /// [`Scenario`]: gherkin::Scenario
@@ -430,21 +668,32 @@ impl<Writer> Summarize<Writer> {
                     Some(Indicator::Failed | Indicator::Retried) => {}
                     Some(Indicator::Skipped) => {
                         self.scenarios.skipped -= 1;
-                        self.scenarios.failed += 1;
+                        self.fail_scenario(path);
                     }
                     None => {
-                        self.scenarios.failed += 1;
                         _ = self
                             .handled_scenarios
-                            .insert(path, Indicator::Failed);
+                            .insert(path.clone(), Indicator::Failed);
+                        self.fail_scenario(path);
                     }
                 }
                 self.failed_hooks += 1;
             }
-            Scenario::Background(st, ev) | Scenario::Step(st, ev) => {
+            Scenario::Background(st, ev) => {
+                if let event::Step::Failed(_, _, _, err) = ev {
+                    let is_retried = ret.is_some_and(|r| {
+                        r.left > 0 && !matches!(err, event::StepError::NotFound)
+                    });
+                    if !is_retried {
+                        self.background_failures += 1;
+                    }
+                }
+                self.handle_step(path.0, path.1, path.2, st.as_ref(), ev, ret);
+            }
+            Scenario::Step(st, ev) => {
                 self.handle_step(path.0, path.1, path.2, st.as_ref(), ev, ret);
             }
-            Scenario::Finished => {
+            Scenario::Finished(_) => {
                 // We don't remove retried `Scenario`s immediately, because we
                 // want to deduplicate. For example if some `Scenario` is
                 // retried 3 times, we'll see in summary 1 retried `Scenario`
@@ -455,9 +704,17 @@ impl<Writer> Summarize<Writer> {
                     .map(|indicator| matches!(indicator, Indicator::Retried))
                     .unwrap_or_default();
 
-                if !is_retried && self.handled_scenarios.remove(&path).is_none()
-                {
-                    self.scenarios.passed += 1;
+                if !is_retried {
+                    let indicator = self.handled_scenarios.remove(&path);
+                    if indicator.is_none() {
+                        self.scenarios.passed += 1;
+                    }
+
+                    let runs = self.runs.entry(path).or_default();
+                    runs.total += 1;
+                    if matches!(indicator, Some(Indicator::Failed)) {
+                        runs.failed += 1;
+                    }
                 }
             }
         }
@@ -493,6 +750,70 @@ impl<Writer> Summarize<Writer> {
     pub const fn steps_stats(&self) -> &Stats {
         &self.steps
     }
+
+    /// Returns number of [`Scenario`]s filtered out and not run (by a tag
+    /// expression, for example).
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub const fn filtered_scenarios(&self) -> usize {
+        self.filtered_scenarios
+    }
+
+    /// Returns the seed [`Scenario`]s have been shuffled with, if they're run
+    /// in a random order.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub const fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    /// Returns number of [`Scenario`]s failed because of a failed
+    /// [`Background`] [`Step`].
+    ///
+    /// [`Background`]: gherkin::Background
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub const fn background_failures(&self) -> usize {
+        self.background_failures
+    }
+
+    /// Returns number of retried [`Scenario`]s, which have passed eventually.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    #[must_use]
+    pub const fn flaky_scenarios(&self) -> usize {
+        self.flaky_scenarios
+    }
+
+    /// Returns [`Feature`]s, [`Rule`]s and failed [`Scenario`]s themselves,
+    /// in their failure order.
+    ///
+    /// Allows to identify failed [`Scenario`]s (by their [`Feature::path`],
+    /// [`Scenario::name`] and [`Scenario::position`], for example) without
+    /// parsing the output.
+    ///
+    /// [`Feature`]: gherkin::Feature
+    /// [`Feature::path`]: gherkin::Feature::path
+    /// [`Rule`]: gherkin::Rule
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Scenario::name`]: gherkin::Scenario::name
+    /// [`Scenario::position`]: gherkin::Scenario::position
+    pub fn failed_scenarios(
+        &self,
+    ) -> impl Iterator<
+        Item = (
+            &gherkin::Feature,
+            Option<&gherkin::Rule>,
+            &gherkin::Scenario,
+        ),
+    > {
+        self.failed_scenarios
+            .iter()
+            .map(|(f, r, s)| (&**f, r.as_deref(), &**s))
+    }
 }
 
 /// Marker indicating that a [`Writer`] can be wrapped into a [`Summarize`].
@@ -525,17 +846,14 @@ impl<Writer> Summarize<Writer> {
 /// ```
 ///
 /// ```rust
-/// # use std::panic::AssertUnwindSafe;
-/// #
-/// # use cucumber::{writer, World, WriterExt as _};
-/// # use futures::FutureExt as _;
+/// # use cucumber::{writer, StatsWriter as _, World, WriterExt as _};
 /// #
 /// # #[derive(Debug, Default, World)]
 /// # struct MyWorld;
 /// #
 /// # #[tokio::main(flavor = "current_thread")]
 /// # async fn main() {
-/// # let fut = async {
+/// # let writer =
 /// MyWorld::cucumber()
 ///     .with_writer(
 ///         // `Writer`s pipeline is constructed in a reversed order.
@@ -543,27 +861,79 @@ impl<Writer> Summarize<Writer> {
 ///             .summarized()       // Only then, count summary for them.
 ///             .fail_on_skipped()  // First, transform skipped steps to failed.
 ///     )
-///     .run_and_exit("tests/features/readme")
+///     .run("tests/features/readme")
 ///     .await;
-/// # };
-/// # let err = AssertUnwindSafe(fut)
-/// #         .catch_unwind()
-/// #         .await
-/// #         .expect_err("should err");
-/// # let err = err.downcast_ref::<String>().unwrap();
-/// # assert_eq!(err, "1 step failed");
+/// # assert_eq!(writer.failed_steps(), 1);
 /// # }
 /// ```
 pub trait Summarizable {}
 
 impl<T: writer::NonTransforming> Summarizable for T {}
 
+/// [`Step`] listed in a digest at the end of the run.
+///
+/// [`Step`]: gherkin::Step
+#[derive(Clone, Debug)]
+struct Digested {
+    /// Location of the [`Step`] in its `.feature` file.
+    ///
+    /// [`Step`]: gherkin::Step
+    at: String,
+
+    /// Name of the [`Scenario`] the [`Step`] belongs to.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    scenario: String,
+
+    /// Keyword and text of the [`Step`].
+    ///
+    /// [`Step`]: gherkin::Step
+    step: String,
+
+    /// [`step::Location`] of the matched [`Step`] definition, if any.
+    ///
+    /// [`Step`]: gherkin::Step
+    definition: Option<step::Location>,
+}
+
+impl Digested {
+    /// Creates a new [`Digested`] [`Step`] of the provided [`Scenario`].
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    /// [`Step`]: gherkin::Step
+    fn new(
+        feature: &gherkin::Feature,
+        scenario: &gherkin::Scenario,
+        step: &gherkin::Step,
+        definition: Option<step::Location>,
+    ) -> Self {
+        Self {
+            at: format!(
+                "{}:{}:{}",
+                basic::feature_path(feature),
+                step.position.line,
+                step.position.col,
+            ),
+            scenario: feature::display_name(scenario).into_owned(),
+            step: format!("{}{}", step.keyword, step.value),
+            definition,
+        }
+    }
+}
+
 // We better keep this here, as it's related to summarization only.
 #[allow(clippy::multiple_inherent_impl)]
 impl Styles {
     /// Generates a formatted summary [`String`].
     #[must_use]
     pub fn summary<W>(&self, summary: &Summarize<W>) -> String {
+        let aborted = if summary.aborted {
+            self.err("Execution aborted, not all scenarios have been run\n")
+        } else {
+            Cow::default()
+        };
+
         let features = self.maybe_plural("feature", summary.features);
 
         let rules = (summary.rules > 0)
@@ -573,9 +943,31 @@ impl Styles {
         let scenarios =
             self.maybe_plural("scenario", summary.scenarios.total());
         let scenarios_stats = self.format_stats(summary.scenarios);
+        let filtered_scenarios = if summary.filtered_scenarios > 0 {
+            self.skipped(format!(
+                ", {} filtered out",
+                summary.filtered_scenarios,
+            ))
+        } else {
+            Cow::default()
+        };
+        let background_failures = if summary.background_failures > 0 {
+            self.err(format!(
+                ", {} failed due to Background",
+                summary.background_failures,
+            ))
+        } else {
+            Cow::default()
+        };
+        let flaky_scenarios = if summary.flaky_scenarios > 0 {
+            self.retry(format!(", {} flaky", summary.flaky_scenarios))
+        } else {
+            Cow::default()
+        };
 
         let steps = self.maybe_plural("step", summary.steps.total());
-        let steps_stats = self.format_stats(summary.steps);
+        let steps_stats = self
+            .format_stats_with_pending(summary.steps, summary.pending_steps);
 
         let parsing_errors = (summary.parsing_errors > 0)
             .then(|| {
@@ -595,29 +987,185 @@ impl Styles {
             .then(|| self.err(", "))
             .unwrap_or_default();
 
+        let seed = summary
+            .seed
+            .map(|seed| {
+                format!(
+                    "Random order seed: {seed} (reproduce with \
+                     `--order random:{seed}`)\n",
+                )
+            })
+            .unwrap_or_default();
+
         format!(
-            "{summary}\n{features}\n{rules}{scenarios}{scenarios_stats}\n\
-             {steps}{steps_stats}\n{parsing_errors}{comma}{hook_errors}",
+            "{summary}\n{aborted}{features}\n{rules}{scenarios}{scenarios_stats}\
+             {filtered_scenarios}{background_failures}{flaky_scenarios}\n\
+             {steps}{steps_stats}\n{seed}{parsing_errors}{comma}{hook_errors}",
             summary = self.bold(self.header("[Summary]")),
         )
         .trim_end_matches('\n')
         .to_owned()
     }
 
+    /// Generates a formatted digest [`String`] of the finally failed and
+    /// undefined [`Step`]s.
+    ///
+    /// [`Step`]: gherkin::Step
+    #[must_use]
+    pub fn digest<W>(&self, summary: &Summarize<W>) -> String {
+        let failures = if summary.failures.is_empty() {
+            Cow::default()
+        } else {
+            self.err(self.digest_section("Failures:", &summary.failures))
+        };
+        let undefined = if summary.undefined.is_empty() {
+            Cow::default()
+        } else {
+            self.skipped(self.digest_section("Undefined:", &summary.undefined))
+        };
+
+        format!("{failures}{undefined}")
+            .trim_end_matches('\n')
+            .to_owned()
+    }
+
+    /// Generates a formatted report [`String`] of the [`Scenario`]s failed in a
+    /// repeated execution, the most often failed ones first.
+    ///
+    /// Empty if no [`Scenario`] has been run more than once.
+    ///
+    /// [`Scenario`]: gherkin::Scenario
+    fn repeated<W>(&self, summary: &Summarize<W>) -> String {
+        if summary.runs.values().all(|r| r.total < 2) {
+            return String::new();
+        }
+
+        let entries = summary
+            .runs
+            .iter()
+            .filter(|(_, r)| r.failed > 0)
+            .map(|((feat, _, sc), r)| {
+                let at = format!(
+                    "{}:{}",
+                    basic::feature_path(feat),
+                    sc.position.line,
+                );
+                (r, at, feature::display_name(sc))
+            })
+            .sorted_by(|(a, a_at, _), (b, b_at, _)| {
+                b.failed.cmp(&a.failed).then_with(|| a_at.cmp(b_at))
+            })
+            .enumerate()
+            .map(|(n, (r, at, name))| {
+                format!(
+                    "  {}) Scenario \"{name}\": failed {} of {} runs\n     \
+                     at {at}",
+                    n + 1,
+                    r.failed,
+                    r.total,
+                )
+            })
+            .join("\n");
+        if entries.is_empty() {
+            return String::new();
+        }
+
+        self.retry(format!(
+            "{}\n{entries}",
+            self.bold("Failed in repeated runs:"),
+        ))
+        .into_owned()
+    }
+
+    /// Formats a section of a digest with the provided `header`.
+    fn digest_section(&self, header: &str, steps: &[Digested]) -> String {
+        let entries = steps
+            .iter()
+            .enumerate()
+            .map(|(n, d)| {
+                let definition = d
+                    .definition
+                    .map(|l| {
+                        format!(
+                            " (defined at {}:{}:{})",
+                            trim_path(l.path),
+                            l.line,
+                            l.column,
+                        )
+                    })
+                    .unwrap_or_default();
+                format!(
+                    "  {}) Scenario \"{}\": {}\n     at {}{definition}",
+                    n + 1,
+                    d.scenario,
+                    d.step,
+                    d.at,
+                )
+            })
+            .join("\n");
+        format!("{}\n{entries}\n", self.bold(header))
+    }
+
+    /// Generates ready-to-paste snippets of [`Step`] definitions for the given
+    /// undefined [`Step`]s, grouped by their [`StepType`].
+    ///
+    /// [`Step`]: gherkin::Step
+    /// [`StepType`]: gherkin::StepType
+    fn snippets<W>(&self, steps: &[(gherkin::StepType, String)]) -> String {
+        use gherkin::StepType::{Given, Then, When};
+
+        let world = any::type_name::<W>().rsplit("::").next().unwrap_or("W");
+        let snippets = [Given, When, Then]
+            .into_iter()
+            .flat_map(|ty| {
+                steps
+                    .iter()
+                    .filter(move |(t, _)| *t == ty)
+                    .map(move |(_, value)| snippet(ty, value, world))
+            })
+            .unique()
+            .join("\n\n");
+
+        format!(
+            "\n{}\n\n{}",
+            self.bold(self.skipped(
+                "You can implement step definitions for undefined steps with \
+                 these snippets:",
+            )),
+            self.skipped(snippets),
+        )
+    }
+
     /// Formats [`Stats`] for a terminal output.
     #[must_use]
     pub fn format_stats(&self, stats: Stats) -> Cow<'static, str> {
+        self.format_stats_with_pending(stats, 0)
+    }
+
+    /// Formats [`Stats`] for a terminal output, separating the given number of
+    /// [pending] ones from the skipped ones.
+    ///
+    /// [pending]: event::Step::Pending
+    fn format_stats_with_pending(
+        &self,
+        stats: Stats,
+        pending: usize,
+    ) -> Cow<'static, str> {
+        let skipped = stats.skipped.saturating_sub(pending);
         let mut formatted = [
             (stats.passed > 0)
                 .then(|| self.bold(self.ok(format!("{} passed", stats.passed))))
                 .unwrap_or_default(),
-            (stats.skipped > 0)
-                .then(|| {
-                    self.bold(
-                        self.skipped(format!("{} skipped", stats.skipped)),
-                    )
-                })
-                .unwrap_or_default(),
+            if skipped > 0 {
+                self.bold(self.skipped(format!("{skipped} skipped")))
+            } else {
+                Cow::default()
+            },
+            if pending > 0 {
+                self.bold(self.skipped(format!("{pending} pending")))
+            } else {
+                Cow::default()
+            },
             (stats.failed > 0)
                 .then(|| {
                     self.bold(self.err(format!("{} failed", stats.failed)))
@@ -660,3 +1208,114 @@ impl Styles {
         ))
     }
 }
+
+/// Generates a snippet of a [`Step`] definition for the given undefined
+/// [`Step`] text, capturing numbers and quoted strings from it.
+///
+/// [`Step`]: gherkin::Step
+fn snippet(ty: gherkin::StepType, text: &str, world: &str) -> String {
+    let attr = match ty {
+        gherkin::StepType::Given => "given",
+        gherkin::StepType::When => "when",
+        gherkin::StepType::Then => "then",
+    };
+
+    let mut regex = String::from("^");
+    let mut literal = String::new();
+    let mut words = Vec::new();
+    let mut args = Vec::new();
+    let mut flush = |lit: &mut String, re: &mut String| {
+        words.extend(
+            lit.split(|c: char| !c.is_alphanumeric())
+                .filter(|w| !w.is_empty())
+                .map(str::to_lowercase),
+        );
+        re.push_str(&regex::escape(lit));
+        lit.clear();
+    };
+
+    let chars = text.chars().collect::<Vec<_>>();
+    let mut i = 0;
+    while let Some(&c) = chars.get(i) {
+        let prev_is_word =
+            i > 0 && chars.get(i - 1).is_some_and(|p| p.is_alphanumeric());
+        let closing_quote = (c == '"')
+            .then(|| chars.iter().skip(i + 1).position(|&q| q == '"'))
+            .flatten();
+        let number_len = (!prev_is_word)
+            .then(|| number_len(chars.get(i..).unwrap_or_default()))
+            .flatten();
+
+        if let Some(len) = closing_quote {
+            flush(&mut literal, &mut regex);
+            regex.push_str(r#""([^"]*)""#);
+            args.push("String");
+            i += len + 2;
+        } else if let Some((len, is_float)) = number_len {
+            flush(&mut literal, &mut regex);
+            regex.push_str(if is_float {
+                r"(-?\d+\.\d+)"
+            } else {
+                r"(-?\d+)"
+            });
+            args.push(if is_float { "f64" } else { "i64" });
+            i += len;
+        } else {
+            literal.push(c);
+            i += 1;
+        }
+    }
+    flush(&mut literal, &mut regex);
+    regex.push('$');
+
+    let mut name = words.join("_");
+    if name.chars().next().map_or(true, char::is_numeric) {
+        name.insert_str(0, "step_");
+    }
+    let hashes = if regex.contains("\"#") { "##" } else { "#" };
+    let args = iter::once(format!("world: &mut {world}"))
+        .chain(
+            args.iter()
+                .enumerate()
+                .map(|(n, arg)| format!("arg{n}: {arg}")),
+        )
+        .join(", ");
+
+    format!(
+        "#[{attr}(regex = r{hashes}\"{regex}\"{hashes})]\n\
+         async fn {name}({args}) {{\n    \
+             todo!()\n\
+         }}",
+    )
+}
+
+/// Returns the length of a number at the beginning of the given `chars`, if
+/// any, along with whether it's a floating point one.
+///
+/// Numbers followed by letters (like `3rd`) are not considered as such.
+fn number_len(chars: &[char]) -> Option<(usize, bool)> {
+    let sign = usize::from(chars.first() == Some(&'-'));
+    let digits = |from: usize| {
+        chars
+            .iter()
+            .skip(from)
+            .take_while(|c| c.is_ascii_digit())
+            .count()
+    };
+
+    let int = digits(sign);
+    if int == 0 {
+        return None;
+    }
+    let mut len = sign + int;
+    let fraction = (chars.get(len) == Some(&'.'))
+        .then(|| digits(len + 1))
+        .filter(|n| *n > 0);
+    if let Some(n) = fraction {
+        len += n + 1;
+    }
+    chars
+        .get(len)
+        .map_or(true, |c| !c.is_alphanumeric())
+        .then_some((len, fraction.is_some()))
+}
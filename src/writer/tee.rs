@@ -106,11 +106,24 @@ where
         cmp::max(self.left.skipped_steps(), self.right.skipped_steps())
     }
 
+    fn pending_steps(&self) -> usize {
+        // Either one of them is zero, or both numbers are the same.
+        cmp::max(self.left.pending_steps(), self.right.pending_steps())
+    }
+
     fn failed_steps(&self) -> usize {
         // Either one of them is zero, or both numbers are the same.
         cmp::max(self.left.failed_steps(), self.right.failed_steps())
     }
 
+    fn strict_failed_steps(&self) -> usize {
+        // Either one of them is zero, or both numbers are the same.
+        cmp::max(
+            self.left.strict_failed_steps(),
+            self.right.strict_failed_steps(),
+        )
+    }
+
     fn retried_steps(&self) -> usize {
         // Either one of them is zero, or both numbers are the same.
         cmp::max(self.left.retried_steps(), self.right.retried_steps())
@@ -125,6 +138,10 @@ where
         // Either one of them is zero, or both numbers are the same.
         cmp::max(self.left.hook_errors(), self.right.hook_errors())
     }
+
+    fn execution_aborted(&self) -> bool {
+        self.left.execution_aborted() || self.right.execution_aborted()
+    }
 }
 
 #[warn(clippy::missing_trait_methods)]
@@ -0,0 +1,56 @@
+use std::{fs, io::Read as _};
+
+use cucumber::{given, runner, then, when, writer, World as _, WriterExt as _};
+use once_cell::sync::Lazy;
+use tempfile::NamedTempFile;
+
+static STOP: Lazy<runner::StopHandle> = Lazy::new(runner::StopHandle::default);
+
+#[given("a step")]
+#[then("a step")]
+fn step(_: &mut World) {}
+
+#[when("a stop is requested")]
+fn request_stop(_: &mut World) {
+    _ = STOP.request();
+}
+
+#[tokio::test]
+async fn marks_reports_as_aborted() {
+    let mut json = NamedTempFile::new().unwrap();
+    let junit = NamedTempFile::new().unwrap();
+    drop(
+        World::cucumber()
+            .max_concurrent_scenarios(1)
+            .stop_handle(STOP.clone())
+            .with_writer(
+                writer::Json::for_tee(json.reopen().unwrap())
+                    .tee::<World, _>(writer::JUnit::for_tee(
+                        junit.reopen().unwrap(),
+                        0,
+                    ))
+                    .normalized(),
+            )
+            .with_default_cli()
+            .run("tests/features/stop_handle")
+            .await,
+    );
+    assert!(STOP.is_requested());
+
+    let mut buffer = String::new();
+    json.read_to_string(&mut buffer).unwrap();
+    assert!(buffer.contains(r#""id":"execution-aborted""#), "{buffer}");
+
+    let buffer = fs::read_to_string(junit.path()).unwrap();
+    assert!(
+        buffer.contains(r#"<testsuite id="1" name="Aborted""#),
+        "{buffer}"
+    );
+    assert!(
+        buffer.contains(r#"<error type="Aborted" message="not all scenarios"#),
+        "{buffer}",
+    );
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
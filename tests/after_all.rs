@@ -0,0 +1,52 @@
+use std::{
+    future,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use cucumber::{given, StatsWriter as _, World as _};
+use tokio::time;
+
+static FINISHED_TEARDOWNS: AtomicUsize = AtomicUsize::new(0);
+
+static ABORTED_TEARDOWNS: AtomicUsize = AtomicUsize::new(0);
+
+#[given("a step")]
+fn step(_: &mut World) {
+    assert_eq!(FINISHED_TEARDOWNS.load(Ordering::SeqCst), 0);
+}
+
+#[given("a hanging step")]
+async fn hanging_step(_: &mut World) {
+    future::pending::<()>().await;
+}
+
+#[tokio::test]
+async fn runs_once_after_all_scenarios() {
+    let writer = World::cucumber()
+        .after_all(|| {
+            _ = FINISHED_TEARDOWNS.fetch_add(1, Ordering::SeqCst);
+        })
+        .run("tests/features/after_all/finishing.feature")
+        .await;
+
+    assert_eq!(writer.passed_steps(), 2);
+    assert_eq!(FINISHED_TEARDOWNS.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn runs_when_execution_is_aborted() {
+    let run = World::cucumber()
+        .after_all(|| {
+            _ = ABORTED_TEARDOWNS.fetch_add(1, Ordering::SeqCst);
+        })
+        .run("tests/features/after_all/hanging.feature");
+
+    assert!(time::timeout(Duration::from_millis(500), run)
+        .await
+        .is_err());
+    assert_eq!(ABORTED_TEARDOWNS.load(Ordering::SeqCst), 1);
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
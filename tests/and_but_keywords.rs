@@ -0,0 +1,65 @@
+use cucumber::{
+    given, parser::basic::Inline, then, when, writer, writer::Stats as _,
+    World as _, WriterExt as _,
+};
+
+#[given("a step")]
+fn given(_: &mut World) {}
+
+#[when("a step")]
+fn when(_: &mut World) {}
+
+#[then("a step")]
+fn then(_: &mut World) {}
+
+#[tokio::test]
+async fn resolves_and_but_keywords() {
+    let writer = World::cucumber()
+        .with_writer(
+            writer::Basic::raw(
+                Vec::new(),
+                writer::Coloring::Never,
+                writer::Verbosity::Default,
+            )
+            .summarized()
+            .normalized(),
+        )
+        .with_default_cli()
+        .run(
+            Inline::new()
+                .feature(
+                    "and_but.feature",
+                    "Feature: And/But\n\n  \
+                     Scenario: conjunctions\n    \
+                     Given a step\n    \
+                     And a step\n    \
+                     When a step\n    \
+                     But a step\n    \
+                     Then a step\n    \
+                     And a step\n",
+                )
+                .feature(
+                    "leading_and.feature",
+                    "Feature: Leading And\n\n  \
+                     Scenario: invalid\n    \
+                     And a step\n",
+                ),
+        )
+        .await;
+
+    assert_eq!(writer.steps_stats().passed, 6);
+    assert_eq!(writer.steps_stats().skipped, 0);
+    assert_eq!(writer.parsing_errors(), 1);
+
+    let output = String::from_utf8(writer.inner_writer().to_vec()).unwrap();
+    for line in ["✔  And a step", "✔  But a step"] {
+        assert!(output.contains(line), "unexpected output:\n{output}");
+    }
+    assert!(
+        output.contains("leading_and.feature"),
+        "unexpected output:\n{output}",
+    );
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
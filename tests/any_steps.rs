@@ -0,0 +1,37 @@
+use cucumber::{any, then, StatsWriter as _, World as _};
+
+#[any("I wait 5 seconds")]
+fn wait_literal(world: &mut World) {
+    world.waits.push("literal".to_owned());
+}
+
+#[any(regex = r"^I wait (\d+) seconds$")]
+fn wait(world: &mut World, secs: u64) {
+    world.waits.push(format!("any {secs}"));
+}
+
+#[then(regex = r"^I wait (\d+) seconds$")]
+fn then_wait(world: &mut World, secs: u64) {
+    world.waits.push(format!("then {secs}"));
+}
+
+#[then(expr = "the waits are {string}")]
+fn waits_are(world: &mut World, expected: String) {
+    assert_eq!(world.waits.join(", "), expected);
+}
+
+#[tokio::test]
+async fn matches_steps_of_any_type() {
+    let writer = World::cucumber()
+        .with_default_cli()
+        .run("tests/features/any_steps")
+        .await;
+
+    assert_eq!(writer.passed_steps(), 5);
+    assert!(!writer.execution_has_failed());
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World {
+    waits: Vec<String>,
+}
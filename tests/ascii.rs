@@ -0,0 +1,38 @@
+use cucumber::{given, then, writer, World as _, WriterExt as _};
+
+#[given("a passing step")]
+#[then("a passing step")]
+fn passing(_: &mut World) {}
+
+#[then("a failing step")]
+fn failing(_: &mut World) {
+    panic!("failed");
+}
+
+#[tokio::test]
+async fn outputs_ascii_markers() {
+    let mut basic = writer::Basic::raw(
+        Vec::new(),
+        writer::Coloring::Never,
+        writer::Verbosity::Default,
+    );
+    basic.apply_cli(writer::basic::Cli {
+        ascii: true,
+        ..writer::basic::Cli::default()
+    });
+
+    let writer = World::cucumber()
+        .with_writer(basic.normalized())
+        .with_default_cli()
+        .run("tests/features/ascii")
+        .await;
+    let output = String::from_utf8(writer.inner_writer().to_vec()).unwrap();
+
+    assert!(output.contains("v> Given a passing step"), "{output}");
+    assert!(output.contains("v  Then a passing step"), "{output}");
+    assert!(output.contains("x  Then a failing step"), "{output}");
+    assert!(output.is_ascii(), "{output}");
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
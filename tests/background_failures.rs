@@ -0,0 +1,40 @@
+use cucumber::{
+    given, then, writer, StatsWriter as _, World as _, WriterExt as _,
+};
+
+#[given("a broken database")]
+fn broken(_: &mut World) {
+    panic!("connection refused");
+}
+
+#[then("nothing happens")]
+fn nothing(_: &mut World) {}
+
+#[tokio::test]
+async fn summarizes_scenarios_failed_due_to_background() {
+    let writer = World::cucumber()
+        .with_writer(
+            writer::Basic::raw(
+                Vec::new(),
+                writer::Coloring::Never,
+                writer::Verbosity::Default,
+            )
+            .normalized()
+            .summarized(),
+        )
+        .with_default_cli()
+        .run("tests/features/background_failures")
+        .await;
+    let output = String::from_utf8(writer.inner_writer().to_vec()).unwrap();
+
+    assert_eq!(writer.background_failures(), 2);
+    assert_eq!(writer.failed_steps(), 2);
+    assert!(
+        output
+            .contains("2 scenarios (2 failed), 2 failed due to Background\n",),
+        "{output}",
+    );
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
@@ -0,0 +1,35 @@
+use cucumber::{given, then, writer, World as _, WriterExt as _};
+
+#[given("a step")]
+fn step(_: &mut World) {}
+
+#[then("a failing step")]
+fn failing(_: &mut World) {
+    panic!("failed");
+}
+
+#[tokio::test]
+async fn parses_files_with_bom() {
+    let bom = std::fs::read("tests/features/bom/bom.feature").unwrap();
+    assert!(bom.starts_with(b"\xEF\xBB\xBF"), "fixture has no BOM");
+
+    let writer = World::cucumber()
+        .with_writer(
+            writer::Basic::raw(
+                Vec::new(),
+                writer::Coloring::Never,
+                writer::Verbosity::Default,
+            )
+            .normalized(),
+        )
+        .with_default_cli()
+        .run("tests/features/bom")
+        .await;
+    let output = String::from_utf8(writer.inner_writer().to_vec()).unwrap();
+
+    assert!(output.contains("Feature: BOM\n"), "{output}");
+    assert!(output.contains("bom.feature:5:5"), "{output}");
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
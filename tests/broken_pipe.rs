@@ -0,0 +1,76 @@
+use std::{cell::Cell, io, rc::Rc};
+
+use cucumber::{
+    given, writer, writer::Outcome, StatsWriter as _, World as _,
+    WriterExt as _,
+};
+
+#[given("a passing step")]
+fn passing(_: &mut World) {}
+
+#[given("a failing step")]
+fn failing(_: &mut World) {
+    panic!("failed");
+}
+
+/// [`io::Write`] implementor failing all the writes with the given
+/// [`io::ErrorKind`], like a closed pipe does.
+#[derive(Clone, Debug)]
+struct ClosedPipe {
+    kind: io::ErrorKind,
+    attempts: Rc<Cell<usize>>,
+}
+
+impl ClosedPipe {
+    fn new(kind: io::ErrorKind) -> Self {
+        Self {
+            kind,
+            attempts: Rc::default(),
+        }
+    }
+}
+
+impl io::Write for ClosedPipe {
+    fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+        self.attempts.set(self.attempts.get() + 1);
+        Err(self.kind.into())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+async fn run_into(pipe: ClosedPipe) {
+    let writer = World::cucumber()
+        .with_writer(
+            writer::Basic::raw(
+                pipe.clone(),
+                writer::Coloring::Never,
+                writer::Verbosity::Default,
+            )
+            .summarized()
+            .normalized(),
+        )
+        .with_default_cli()
+        .run("tests/features/exit_code/failed")
+        .await;
+
+    assert_eq!(writer.passed_steps(), 1);
+    assert_eq!(writer.failed_steps(), 1);
+    assert_eq!(writer.outcome(), Outcome::Failed);
+    assert_eq!(pipe.attempts.get(), 1, "output is not suppressed");
+}
+
+#[tokio::test]
+async fn completes_run_on_broken_pipe() {
+    run_into(ClosedPipe::new(io::ErrorKind::BrokenPipe)).await;
+}
+
+#[tokio::test]
+async fn completes_run_on_other_write_errors() {
+    run_into(ClosedPipe::new(io::ErrorKind::Other)).await;
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
@@ -0,0 +1,92 @@
+use cucumber::{gherkin, given, then, when, StatsWriter as _, World as _};
+
+#[given(regex = r"^a job(?: with (\d+) retries)?$")]
+fn job(w: &mut World, retries: String) {
+    w.retries.push(retries);
+}
+
+#[when(regex = r"^the (cat|dog) is (hungry|fed)$")]
+fn animal_state(w: &mut World, animal: String, state: String) {
+    w.animals.push(format!("{animal}:{state}"));
+}
+
+#[then("the job runs")]
+fn runs(w: &mut World) {
+    assert_eq!(w.retries, ["", "3"]);
+    assert_eq!(w.animals, ["cat:hungry", "dog:fed"]);
+}
+
+#[tokio::test]
+async fn passes_only_capture_groups_as_arguments() {
+    let writer = World::cucumber()
+        .with_default_cli()
+        .run("tests/features/capture_groups")
+        .await;
+
+    assert_eq!(writer.passed_steps(), 5);
+    assert_eq!(writer.failed_steps(), 0);
+}
+
+#[tokio::test]
+async fn exposes_whole_match_and_empty_optional_groups_in_context() {
+    let writer = Raw::cucumber()
+        .given(
+            regex::Regex::new(r"^a job(?: with (\d+) retries)?$").unwrap(),
+            |w: &mut Raw, ctx| {
+                assert_eq!(ctx.matches.len(), 2);
+                assert_eq!(ctx.matches[0].1, ctx.step.value);
+                w.0.push(ctx.matches[1].1.clone());
+                Box::pin(async {})
+            },
+        )
+        .with_default_cli()
+        .run("tests/features/capture_groups")
+        .await;
+
+    assert_eq!(writer.passed_steps(), 2);
+    assert_eq!(writer.skipped_steps(), 1);
+    assert_eq!(writer.failed_steps(), 0);
+}
+
+#[test]
+fn parses_typed_captures_with_descriptive_errors() {
+    let steps = cucumber::step::Collection::new().given(
+        None,
+        regex::Regex::new(r"^(?P<count>\d+) jobs? named (\w+)$").unwrap(),
+        |_: &mut Raw, _| Box::pin(async {}),
+    );
+    let step = gherkin::Step {
+        keyword: "Given ".into(),
+        ty: gherkin::StepType::Given,
+        value: "3 jobs named nightly".into(),
+        docstring: None,
+        table: None,
+        span: gherkin::Span::default(),
+        position: gherkin::LineCol::default(),
+    };
+    let (.., ctx) = steps.find(&step).unwrap().unwrap();
+
+    assert_eq!(ctx.capture::<u32>(1).unwrap(), 3);
+    assert_eq!(ctx.named_capture::<u32>("count").unwrap(), 3);
+    assert_eq!(ctx.capture::<String>(2).unwrap(), "nightly");
+
+    assert_eq!(
+        ctx.capture::<u32>(2).unwrap_err().to_string(),
+        "failed to parse capture group `2` of `^(?P<count>\\d+) jobs? named \
+         (\\w+)$` from \"nightly\": invalid digit found in string",
+    );
+    assert_eq!(
+        ctx.named_capture::<u32>("name").unwrap_err().to_string(),
+        "`^(?P<count>\\d+) jobs? named (\\w+)$` has no capture group `name`",
+    );
+    assert!(ctx.capture::<u32>(3).is_err());
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World {
+    retries: Vec<String>,
+    animals: Vec<String>,
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct Raw(Vec<String>);
@@ -1,8 +1,5 @@
-use std::panic::AssertUnwindSafe;
-
 use clap::Parser;
-use cucumber::{cli, given, World as _};
-use futures::FutureExt as _;
+use cucumber::{cli, given, StatsWriter as _, World as _};
 
 #[derive(cli::Args)]
 struct CustomCli {
@@ -30,7 +27,7 @@ fn invalid_step(_world: &mut World) {
 }
 
 // This test uses a subcommand with the global option `--tags` to filter on two
-// failing tests and verifies that the error output contains 2 failing steps.
+// failing tests and verifies that the run has 2 failing steps.
 #[tokio::test]
 async fn tags_option_filters_all_scenarios_with_subcommand() {
     let cli = cli::Opts::<_, _, _, CustomCli>::try_parse_from(&[
@@ -41,21 +38,16 @@ async fn tags_option_filters_all_scenarios_with_subcommand() {
     ])
     .expect("Invalid command line");
 
-    let res = World::cucumber()
+    let writer = World::cucumber()
         .with_cli(cli)
-        .run_and_exit("tests/features/cli");
-
-    let err = AssertUnwindSafe(res)
-        .catch_unwind()
-        .await
-        .expect_err("should err");
-    let err = err.downcast_ref::<String>().unwrap();
+        .run("tests/features/cli")
+        .await;
 
-    assert_eq!(err, "2 steps failed");
+    assert_eq!(writer.failed_steps(), 2);
 }
 
 // This test uses a subcommand with the global option `--tags` to filter on one
-// failing test and verifies that the error output contains 1 failing step.
+// failing test and verifies that the run has 1 failing step.
 #[tokio::test]
 async fn tags_option_filters_scenario1_with_subcommand() {
     let cli = cli::Opts::<_, _, _, CustomCli>::try_parse_from(&[
@@ -66,21 +58,16 @@ async fn tags_option_filters_scenario1_with_subcommand() {
     ])
     .expect("Invalid command line");
 
-    let res = World::cucumber()
+    let writer = World::cucumber()
         .with_cli(cli)
-        .run_and_exit("tests/features/cli");
+        .run("tests/features/cli")
+        .await;
 
-    let err = AssertUnwindSafe(res)
-        .catch_unwind()
-        .await
-        .expect_err("should err");
-    let err = err.downcast_ref::<String>().unwrap();
-
-    assert_eq!(err, "1 step failed");
+    assert_eq!(writer.failed_steps(), 1);
 }
 
 // This test verifies that the global option `--tags` is still available without
-// subcommands and that the error output contains 1 failing step.
+// subcommands and that the run has 1 failing step.
 #[tokio::test]
 async fn tags_option_filters_scenario1_no_subcommand() {
     let cli = cli::Opts::<_, _, _, CustomCli>::try_parse_from(&[
@@ -89,15 +76,10 @@ async fn tags_option_filters_scenario1_no_subcommand() {
     ])
     .expect("Invalid command line");
 
-    let res = World::cucumber()
+    let writer = World::cucumber()
         .with_cli(cli)
-        .run_and_exit("tests/features/cli");
-
-    let err = AssertUnwindSafe(res)
-        .catch_unwind()
-        .await
-        .expect_err("should err");
-    let err = err.downcast_ref::<String>().unwrap();
+        .run("tests/features/cli")
+        .await;
 
-    assert_eq!(err, "1 step failed");
+    assert_eq!(writer.failed_steps(), 1);
 }
@@ -0,0 +1,37 @@
+use cucumber::{StatsWriter as _, World as _};
+use futures::FutureExt as _;
+use regex::Regex;
+
+#[tokio::test]
+async fn registers_closures_capturing_environment() {
+    let base_url = String::from("http://localhost:8080");
+    let expected = base_url.clone();
+
+    let writer = World::cucumber()
+        .given(
+            Regex::new(r#"^the "(\S+)" endpoint$"#).unwrap(),
+            move |w: &mut World, ctx| {
+                w.url = format!("{base_url}/{}", ctx.matches[1].1);
+                async {}.boxed_local()
+            },
+        )
+        .then(
+            Regex::new(r#"^the URL is "(\S+)"$"#).unwrap(),
+            move |w: &mut World, ctx| {
+                assert!(w.url.starts_with(&expected));
+                assert_eq!(w.url, ctx.matches[1].1);
+                async {}.boxed_local()
+            },
+        )
+        .with_default_cli()
+        .run("tests/features/closure_steps")
+        .await;
+
+    assert_eq!(writer.passed_steps(), 2);
+    assert_eq!(writer.failed_steps(), 0);
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World {
+    url: String,
+}
@@ -0,0 +1,95 @@
+#![cfg(unix)]
+
+use std::{env, process, thread, time::Duration};
+
+use cucumber::{given, runner, then, when, World as _};
+
+#[given("a step")]
+#[then("a step")]
+fn step(_: &mut World) {}
+
+#[when("Ctrl-C is pressed")]
+fn press_ctrl_c(_: &mut World) {
+    send_sigint();
+}
+
+#[when("Ctrl-C is pressed twice")]
+fn press_ctrl_c_twice(_: &mut World) {
+    send_sigint();
+    send_sigint();
+
+    // Never finishes in time, unless the process is exited forcibly.
+    thread::sleep(Duration::from_secs(30));
+}
+
+/// Sends `SIGINT` to the current process and waits for it being handled.
+fn send_sigint() {
+    let stop = runner::StopHandle::ctrl_c();
+    let before = stop.is_requested();
+
+    let status = process::Command::new("kill")
+        .args(["-INT", &process::id().to_string()])
+        .status()
+        .unwrap_or_else(|e| panic!("failed to run `kill`: {e}"));
+    assert!(status.success(), "`kill` failed: {status}");
+
+    if !before {
+        while !stop.is_requested() {
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+}
+
+#[test]
+fn aborts_on_ctrl_c() {
+    let out = output_of("run_interrupted_once");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+
+    assert_eq!(out.status.code(), Some(130), "{stdout}");
+    assert!(stdout.contains("execution stopped"), "{stdout}");
+    assert!(stdout.contains("Execution aborted"), "{stdout}");
+    assert!(stdout.contains("1 scenario (1 skipped)"), "{stdout}");
+}
+
+#[test]
+fn exits_immediately_on_repeated_ctrl_c() {
+    let out = output_of("run_interrupted_twice");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+
+    assert_eq!(out.status.code(), Some(130), "{stdout}");
+    assert!(!stdout.contains("[Summary]"), "{stdout}");
+}
+
+/// Runs the given `#[ignore]`d test of this binary in a separate process, as
+/// the `SIGINT` handler is process-wide.
+fn output_of(test: &str) -> process::Output {
+    let exe = env::current_exe().unwrap_or_else(|e| panic!("{e}"));
+    process::Command::new(exe)
+        .args(["--ignored", "--exact", "--nocapture", test])
+        .output()
+        .unwrap_or_else(|e| panic!("failed to run `{test}`: {e}"))
+}
+
+#[tokio::test]
+#[ignore = "run by `aborts_on_ctrl_c`"]
+async fn run_interrupted_once() {
+    World::cucumber()
+        .max_concurrent_scenarios(1)
+        .handle_ctrl_c()
+        .with_default_cli()
+        .run_and_exit("tests/features/ctrl_c/once")
+        .await;
+}
+
+#[tokio::test]
+#[ignore = "run by `exits_immediately_on_repeated_ctrl_c`"]
+async fn run_interrupted_twice() {
+    World::cucumber()
+        .handle_ctrl_c()
+        .with_default_cli()
+        .run_and_exit("tests/features/ctrl_c/twice")
+        .await;
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
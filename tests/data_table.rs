@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+
+use cucumber::{
+    gherkin, given, step, step::Ext as _, StatsWriter as _, World as _,
+};
+
+#[given("the users:")]
+fn users(_: &mut World, #[step] ctx: &step::Context) {
+    assert_eq!(
+        ctx.step.table_header(),
+        Some(["name".into(), "email".into()].as_slice()),
+    );
+    assert_eq!(
+        ctx.step.table_rows_as_maps(),
+        Some(vec![
+            map(&[("name", "alice"), ("email", "alice@test.com")]),
+            map(&[("name", "bob"), ("email", "")]),
+        ]),
+    );
+}
+
+#[given("the numbers:")]
+fn numbers(_: &mut World, #[step] ctx: &step::Context) {
+    assert_eq!(ctx.step.table_as::<u32>(), Some(Ok(vec![1, 2, 42])));
+}
+
+#[tokio::test]
+async fn exposes_data_tables() {
+    let writer = World::cucumber()
+        .with_default_cli()
+        .run("tests/features/data_table")
+        .await;
+
+    assert_eq!(writer.passed_steps(), 2);
+    assert_eq!(writer.failed_steps(), 0);
+}
+
+#[test]
+fn handles_missing_and_malformed_tables() {
+    let step = |rows: Option<Vec<Vec<&str>>>| gherkin::Step {
+        keyword: "Given".into(),
+        ty: gherkin::StepType::Given,
+        value: "a step".into(),
+        docstring: None,
+        table: rows.map(|rows| gherkin::Table {
+            rows: rows
+                .into_iter()
+                .map(|r| r.into_iter().map(Into::into).collect())
+                .collect(),
+            span: gherkin::Span::default(),
+            position: gherkin::LineCol::default(),
+        }),
+        span: gherkin::Span::default(),
+        position: gherkin::LineCol::default(),
+    };
+
+    assert_eq!(step(None).table_header(), None);
+    assert_eq!(step(None).table_rows_as_maps(), None);
+    assert_eq!(step(None).table_as::<u32>(), None);
+
+    let header_only = step(Some(vec![vec!["a", "b"]]));
+    assert_eq!(header_only.table_rows_as_maps(), Some(vec![]));
+
+    let two_columns = step(Some(vec![vec!["1", "2"]]));
+    assert_eq!(two_columns.table_as::<u32>(), None);
+
+    let invalid = step(Some(vec![vec!["1"], vec!["x"]]));
+    assert!(matches!(invalid.table_as::<u32>(), Some(Err(_))));
+}
+
+fn map(entries: &[(&str, &str)]) -> HashMap<String, String> {
+    entries
+        .iter()
+        .map(|(k, v)| ((*k).into(), (*v).into()))
+        .collect()
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
@@ -0,0 +1,51 @@
+use std::{env, fs, process};
+
+use cucumber::{given, World as _};
+
+#[given("a step")]
+fn step(_: &mut World) {}
+
+#[test]
+fn outputs_diagnostics() {
+    let exe = env::current_exe().unwrap_or_else(|e| panic!("{e}"));
+    let output = process::Command::new(exe)
+        .args(["--ignored", "--exact", "run_with_diagnostics"])
+        .env("CUCUMBER_LOG", "debug")
+        .output()
+        .unwrap_or_else(|e| {
+            panic!("failed to run `run_with_diagnostics`: {e}")
+        });
+    assert!(output.status.success(), "{output:?}");
+
+    let stderr = String::from_utf8_lossy(&output.stderr)
+        .replace(env!("CARGO_MANIFEST_DIR"), ".")
+        .replace('\\', "/");
+    let diagnostics = stderr
+        .lines()
+        .filter(|l| l.starts_with("[cucumber]"))
+        .map(|l| format!("{l}\n"))
+        .collect::<String>();
+
+    assert_eq!(
+        diagnostics,
+        fs::read_to_string(
+            "tests/features/diagnostics/diagnostics.feature.out"
+        )
+        .unwrap_or_else(|e| panic!("{e}")),
+    );
+}
+
+#[tokio::test]
+#[ignore = "run by `outputs_diagnostics`"]
+async fn run_with_diagnostics() {
+    World::cucumber()
+        .max_concurrent_scenarios(1)
+        .with_default_cli()
+        .filter_run("tests/features/diagnostics", |_, _, sc| {
+            !sc.tags.iter().any(|t| t == "skip")
+        })
+        .await;
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
@@ -0,0 +1,60 @@
+use cucumber::{
+    gherkin, given, step, step::Ext as _, StatsWriter as _, World as _,
+};
+
+#[given("the JSON:")]
+fn json(_: &mut World, #[step] ctx: &step::Context) {
+    assert_eq!(
+        ctx.step.docstring_dedented().as_deref(),
+        Some("{\n  \"name\": \"cucumber\",\n  \"tags\": [\"rust\"]\n}"),
+    );
+}
+
+#[given("the SQL:")]
+fn sql(_: &mut World, #[step] ctx: &step::Context) {
+    assert_eq!(
+        ctx.step.docstring_with_margin('|').as_deref(),
+        Some("SELECT *\n  FROM users\n\n  WHERE id = 1"),
+    );
+}
+
+#[tokio::test]
+async fn strips_docstring_indentation() {
+    let writer = World::cucumber()
+        .with_default_cli()
+        .run("tests/features/docstring")
+        .await;
+
+    assert_eq!(writer.passed_steps(), 2);
+    assert_eq!(writer.failed_steps(), 0);
+}
+
+#[test]
+fn dedents_only_common_whitespace() {
+    let step = |doc: &str| gherkin::Step {
+        keyword: "Given".into(),
+        ty: gherkin::StepType::Given,
+        value: "a step".into(),
+        docstring: Some(doc.into()),
+        table: None,
+        span: gherkin::Span::default(),
+        position: gherkin::LineCol::default(),
+    };
+
+    assert_eq!(
+        step("\n    a\n      b\n\n    c\n  \n").docstring_dedented(),
+        Some("a\n  b\n\nc".into()),
+    );
+    assert_eq!(
+        step("\n\ta\n    b\n").docstring_dedented(),
+        Some("\ta\n    b".into()),
+    );
+    assert_eq!(
+        step("\n\t a\n\t  b\n").docstring_dedented(),
+        Some("a\n b".into()),
+    );
+    assert_eq!(step("").docstring_dedented(), Some(String::new()));
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
@@ -0,0 +1,95 @@
+use clap::Parser as _;
+use cucumber::{
+    cli, given, then, when, writer, StatsWriter as _, World as _,
+    WriterExt as _,
+};
+
+#[given("a step")]
+#[then("a step")]
+#[then(regex = r"^a step \d$")]
+fn step(_: &mut World) {}
+
+#[when("a panicking step")]
+fn panicking_step(_: &mut World) {
+    panic!("dry run must not execute steps");
+}
+
+#[tokio::test]
+async fn resolves_steps_without_executing_them() {
+    let writer = World::cucumber()
+        .dry_run()
+        .with_default_cli()
+        .run("tests/features/dry_run")
+        .await;
+
+    assert_eq!(writer.passed_steps(), 0);
+    assert_eq!(writer.skipped_steps(), 9);
+    assert_eq!(writer.failed_steps(), 2);
+    assert!(writer.execution_has_failed());
+}
+
+#[tokio::test]
+async fn counts_steps_as_regular_run() {
+    let writer = World::cucumber()
+        .dry_run()
+        .with_writer(
+            writer::Basic::raw(
+                Vec::new(),
+                writer::Coloring::Never,
+                writer::Verbosity::Default,
+            )
+            .summarized()
+            .normalized(),
+        )
+        .with_default_cli()
+        .run("tests/features/dry_run")
+        .await;
+
+    let output = String::from_utf8(writer.inner_writer().to_vec()).unwrap();
+    assert!(
+        output.contains("Reason: dry run"),
+        "unexpected output:\n{output}",
+    );
+    assert!(
+        output.contains("3 scenarios (3 skipped)\n9 steps (9 skipped)"),
+        "unexpected output:\n{output}",
+    );
+    assert!(!output.contains("passed"), "unexpected output:\n{output}");
+}
+
+#[tokio::test]
+async fn passes_without_undefined_steps() {
+    let writer = World::cucumber()
+        .dry_run()
+        .filter_run("tests/features/dry_run", |_, _, sc| sc.name == "resolved")
+        .await;
+
+    assert_eq!(writer.skipped_steps(), 3);
+    assert_eq!(writer.failed_steps(), 0);
+    assert!(!writer.execution_has_failed());
+}
+
+#[tokio::test]
+async fn dry_run_from_cli() {
+    let cli = cli::Opts::<_, _, _>::try_parse_from(["test", "--dry-run"])
+        .expect("invalid options");
+
+    let writer = World::cucumber()
+        .with_cli(cli)
+        .run("tests/features/dry_run")
+        .await;
+
+    assert_eq!(writer.passed_steps(), 0);
+    assert_eq!(writer.failed_steps(), 2);
+    assert!(writer.execution_has_failed());
+}
+
+#[derive(Debug, cucumber::World)]
+#[world(init = Self::new)]
+struct World;
+
+impl World {
+    fn new() -> Self {
+        panic!("dry run must not construct a `World`");
+    }
+}
@@ -0,0 +1,202 @@
+use std::{env, io, process};
+
+use cucumber::{
+    given, pending, writer, writer::Outcome, StatsWriter as _, World as _,
+    WriterExt as _,
+};
+use futures::FutureExt as _;
+
+#[given("a passing step")]
+fn passing(_: &mut World) {}
+
+#[given("a failing step")]
+fn failing(_: &mut World) {
+    panic!("failed");
+}
+
+#[given("a pending step")]
+fn pending(_: &mut World) {
+    pending!();
+}
+
+#[tokio::test]
+async fn passed() {
+    let writer = World::cucumber()
+        .with_default_cli()
+        .run("tests/features/exit_code/passed")
+        .await;
+
+    assert_eq!(writer.outcome(), Outcome::Passed);
+    assert_eq!(writer.outcome().exit_code(), 0);
+}
+
+#[tokio::test]
+async fn failed_steps() {
+    let writer = World::cucumber()
+        .with_default_cli()
+        .run("tests/features/exit_code/failed")
+        .await;
+
+    assert_eq!(writer.outcome(), Outcome::Failed);
+    assert_eq!(writer.outcome().exit_code(), 1);
+
+    let writer = World::cucumber()
+        .fail_on_skipped()
+        .with_default_cli()
+        .run("tests/features/exit_code/failed")
+        .await;
+
+    assert_eq!(writer.strict_failed_steps(), 1);
+    assert_eq!(writer.outcome(), Outcome::Failed);
+    assert_eq!(writer.outcome().exit_code(), 1);
+}
+
+#[tokio::test]
+async fn skipped_steps() {
+    let writer = World::cucumber()
+        .with_default_cli()
+        .run("tests/features/exit_code/skipped")
+        .await;
+
+    assert_eq!(writer.outcome(), Outcome::Passed);
+
+    let writer = World::cucumber()
+        .fail_on_skipped()
+        .with_default_cli()
+        .run("tests/features/exit_code/skipped")
+        .await;
+
+    assert_eq!(writer.outcome(), Outcome::Undefined);
+    assert_eq!(writer.outcome().exit_code(), 2);
+}
+
+#[tokio::test]
+async fn pending_steps() {
+    let writer = World::cucumber()
+        .fail_on_skipped()
+        .with_default_cli()
+        .run("tests/features/exit_code/pending")
+        .await;
+
+    assert_eq!(writer.outcome(), Outcome::Passed);
+
+    let writer = World::cucumber()
+        .with_writer(
+            writer::Basic::raw(
+                io::sink(),
+                writer::Coloring::Never,
+                writer::Verbosity::Default,
+            )
+            .summarized()
+            .normalized()
+            .fail_on_skipped()
+            .fail_on_pending(),
+        )
+        .with_default_cli()
+        .run("tests/features/exit_code/pending")
+        .await;
+
+    assert_eq!(writer.outcome(), Outcome::Undefined);
+    assert_eq!(writer.outcome().exit_code(), 2);
+}
+
+#[tokio::test]
+async fn undefined_steps_in_dry_run() {
+    let writer = World::cucumber()
+        .dry_run()
+        .with_default_cli()
+        .run("tests/features/exit_code/skipped")
+        .await;
+
+    assert_eq!(writer.outcome(), Outcome::Undefined);
+    assert_eq!(writer.outcome().exit_code(), 2);
+}
+
+#[tokio::test]
+async fn parsing_errors() {
+    let writer = World::cucumber()
+        .with_default_cli()
+        .run("tests/features/exit_code/broken")
+        .await;
+
+    assert_eq!(writer.failed_steps(), 1);
+    assert_eq!(writer.outcome(), Outcome::SetupFailed);
+    assert_eq!(writer.outcome().exit_code(), 3);
+}
+
+#[tokio::test]
+async fn hook_errors() {
+    let writer = World::cucumber()
+        .before(|_, _, _, _| {
+            async {
+                panic!("docker isn't running");
+            }
+            .boxed_local()
+        })
+        .with_default_cli()
+        .run("tests/features/exit_code/passed")
+        .await;
+
+    assert_eq!(writer.outcome(), Outcome::SetupFailed);
+    assert_eq!(writer.outcome().exit_code(), 3);
+}
+
+#[test]
+fn run_and_exit_exits_with_outcome_code() {
+    assert_eq!(exit_code_of("run_and_exit_passed"), Some(0));
+    assert_eq!(exit_code_of("run_and_exit_failed"), Some(1));
+    assert_eq!(exit_code_of("run_and_exit_skipped_strict"), Some(2));
+    assert_eq!(exit_code_of("run_and_exit_broken"), Some(3));
+}
+
+/// Runs the given `#[ignore]`d test of this binary in a separate process,
+/// returning its exit code.
+fn exit_code_of(test: &str) -> Option<i32> {
+    let exe = env::current_exe().unwrap_or_else(|e| panic!("{e}"));
+    process::Command::new(exe)
+        .args(["--ignored", "--exact", test])
+        .output()
+        .unwrap_or_else(|e| panic!("failed to run `{test}`: {e}"))
+        .status
+        .code()
+}
+
+#[tokio::test]
+#[ignore = "run by `run_and_exit_exits_with_outcome_code`"]
+async fn run_and_exit_passed() {
+    World::cucumber()
+        .with_default_cli()
+        .run_and_exit("tests/features/exit_code/passed")
+        .await;
+}
+
+#[tokio::test]
+#[ignore = "run by `run_and_exit_exits_with_outcome_code`"]
+async fn run_and_exit_failed() {
+    World::cucumber()
+        .with_default_cli()
+        .run_and_exit("tests/features/exit_code/failed")
+        .await;
+}
+
+#[tokio::test]
+#[ignore = "run by `run_and_exit_exits_with_outcome_code`"]
+async fn run_and_exit_skipped_strict() {
+    World::cucumber()
+        .fail_on_skipped()
+        .with_default_cli()
+        .run_and_exit("tests/features/exit_code/skipped")
+        .await;
+}
+
+#[tokio::test]
+#[ignore = "run by `run_and_exit_exits_with_outcome_code`"]
+async fn run_and_exit_broken() {
+    World::cucumber()
+        .with_default_cli()
+        .run_and_exit("tests/features/exit_code/broken")
+        .await;
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
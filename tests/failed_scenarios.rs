@@ -0,0 +1,48 @@
+use cucumber::{given, World as _};
+
+#[given("a passing step")]
+fn passing(_: &mut World) {}
+
+#[given("a failing step")]
+fn failing(_: &mut World) {
+    panic!("failed");
+}
+
+#[tokio::test]
+async fn lists_failed_scenarios() {
+    let writer = World::cucumber()
+        .max_concurrent_scenarios(1)
+        .with_default_cli()
+        .run("tests/features/failed_scenarios")
+        .await;
+
+    let failed = writer
+        .failed_scenarios()
+        .map(|(f, r, s)| {
+            let path = f.path.as_ref().and_then(|p| p.file_name()).unwrap();
+            (
+                path.to_string_lossy().into_owned(),
+                r.map(|r| r.name.clone()),
+                s.name.clone(),
+                s.position.line,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        failed,
+        [
+            ("failed.feature".into(), None, "failing".into(), 6),
+            (
+                "failed.feature".into(),
+                Some("rule".into()),
+                "failing in rule".into(),
+                12,
+            ),
+        ],
+    );
+    assert_eq!(writer.scenarios_stats().failed, 2);
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
@@ -0,0 +1,41 @@
+use cucumber::{given, when, writer, World as _, WriterExt as _};
+
+#[given("a step")]
+fn step(_: &mut World) {}
+
+#[when("a failing step")]
+fn failing_step(_: &mut World) {
+    panic!("boom");
+}
+
+#[tokio::test]
+async fn outputs_digest_of_failed_and_undefined_steps() {
+    let writer = World::cucumber()
+        .with_writer(
+            writer::Basic::raw(
+                Vec::new(),
+                writer::Coloring::Never,
+                writer::Verbosity::Default,
+            )
+            .normalized()
+            .summarized(),
+        )
+        .with_default_cli()
+        .run("tests/features/failure_digest")
+        .await;
+    let output = String::from_utf8(writer.inner_writer().to_vec()).unwrap();
+
+    let digest = "\
+        Failures:\n  \
+        1) Scenario \"failing\": When a failing step\n     \
+        at tests/features/failure_digest/digest.feature:4:5 \
+        (defined at tests/failure_digest.rs:6:1)\n\
+        Undefined:\n  \
+        1) Scenario \"undefined\": Then an undefined step\n     \
+        at tests/features/failure_digest/digest.feature:8:5\n\
+        [Summary]";
+    assert!(output.contains(digest), "{output}");
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
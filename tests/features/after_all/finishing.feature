@@ -0,0 +1,7 @@
+Feature: Finishing
+
+  Scenario: first
+    Given a step
+
+  Scenario: second
+    Given a step
@@ -0,0 +1,4 @@
+Feature: Hanging
+
+  Scenario: never finishes
+    Given a hanging step
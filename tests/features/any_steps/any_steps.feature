@@ -0,0 +1,8 @@
+Feature: Any steps
+
+  Scenario: matching steps of any type
+    Given I wait 1 seconds
+    And I wait 5 seconds
+    When I wait 2 seconds
+    Then I wait 3 seconds
+    And the waits are "any 1, literal, any 2, then 3"
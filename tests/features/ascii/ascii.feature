@@ -0,0 +1,10 @@
+Feature: ASCII markers
+
+  Background:
+    Given a passing step
+
+  Scenario: passing
+    Then a passing step
+
+  Scenario: failing
+    Then a failing step
@@ -0,0 +1,9 @@
+Feature: Background failures
+  Background:
+    Given a broken database
+
+  Scenario: first
+    Then nothing happens
+
+  Scenario: second
+    Then nothing happens
@@ -0,0 +1,5 @@
+﻿Feature: BOM
+
+  Scenario: with BOM
+    Given a step
+    Then a failing step
@@ -0,0 +1,7 @@
+Feature: Capture groups
+  Scenario: optional and alternation groups
+    Given a job
+    And a job with 3 retries
+    When the cat is hungry
+    And the dog is fed
+    Then the job runs
@@ -0,0 +1,5 @@
+Feature: Closure steps
+
+  Scenario: configured endpoint
+    Given the "users" endpoint
+    Then the URL is "http://localhost:8080/users"
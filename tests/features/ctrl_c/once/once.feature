@@ -0,0 +1,9 @@
+Feature: Ctrl-C
+
+  Scenario: interrupted
+    Given a step
+    When Ctrl-C is pressed
+    Then a step
+
+  Scenario: not started
+    Given a step
@@ -0,0 +1,6 @@
+Feature: Repeated Ctrl-C
+
+  Scenario: interrupted
+    Given a step
+    When Ctrl-C is pressed twice
+    Then a step
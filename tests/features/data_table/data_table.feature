@@ -0,0 +1,13 @@
+Feature: Data Tables
+
+  Scenario: table with a header and empty cells
+    Given the users:
+      | name  | email           |
+      | alice | alice@test.com  |
+      | bob   |                 |
+
+  Scenario: single-column table without a header
+    Given the numbers:
+      | 1  |
+      | 2  |
+      | 42 |
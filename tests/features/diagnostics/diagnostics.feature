@@ -0,0 +1,8 @@
+Feature: Diagnostics
+
+  Scenario: included
+    Given a step
+
+  @skip
+  Scenario: filtered
+    Given a step
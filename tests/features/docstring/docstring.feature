@@ -0,0 +1,19 @@
+Feature: Doc Strings
+
+  Scenario: dedented JSON
+    Given the JSON:
+      """
+            {
+              "name": "cucumber",
+              "tags": ["rust"]
+            }
+      """
+
+  Scenario: SQL with margin
+    Given the SQL:
+      """
+      |SELECT *
+      |  FROM users
+      |
+      |  WHERE id = 1
+      """
@@ -0,0 +1,17 @@
+Feature: Dry run
+
+  Background:
+    Given a step
+
+  Scenario: resolved
+    When a panicking step
+    Then a step
+
+  Scenario Outline: undefined
+    When an undefined step
+    Then a step <num>
+
+    Examples:
+      | num |
+      | 1   |
+      | 2   |
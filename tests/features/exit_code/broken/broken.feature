@@ -0,0 +1,6 @@
+Feature: Broken
+
+  Scenario: passed
+    Given a passing step
+
+  @tag
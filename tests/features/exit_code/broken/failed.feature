@@ -0,0 +1,4 @@
+Feature: Failed
+
+  Scenario: failed
+    Given a failing step
@@ -0,0 +1,10 @@
+Feature: Failed
+
+  Scenario: passed
+    Given a passing step
+
+  Scenario: failed
+    Given a failing step
+
+  Scenario: undefined
+    Given an undefined step
@@ -0,0 +1,4 @@
+Feature: Passed
+
+  Scenario: passed
+    Given a passing step
@@ -0,0 +1,4 @@
+Feature: Pending
+
+  Scenario: pending
+    Given a pending step
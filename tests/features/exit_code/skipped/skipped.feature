@@ -0,0 +1,4 @@
+Feature: Skipped
+
+  Scenario: skipped
+    Given an undefined step
@@ -0,0 +1,13 @@
+Feature: Failed scenarios
+
+  Scenario: passing
+    Given a passing step
+
+  Scenario: failing
+    Given a passing step
+    And a failing step
+
+  Rule: rule
+
+    Scenario: failing in rule
+      Given a failing step
@@ -0,0 +1,11 @@
+Feature: Failure digest
+  Scenario: failing
+    Given a step
+    When a failing step
+
+  Scenario: undefined
+    Given a step
+    Then an undefined step
+
+  Scenario: passing
+    Given a step
@@ -0,0 +1,8 @@
+Feature: Flaky
+
+  @retry(2)
+  Scenario: flaky
+    Given a step failing once
+
+  Scenario: stable
+    Given a passing step
@@ -0,0 +1,8 @@
+Feature: Flushing
+
+  Scenario: first
+    Given a step
+    And a step
+
+  Scenario: second
+    Given a step
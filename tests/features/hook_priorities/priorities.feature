@@ -0,0 +1,4 @@
+Feature: Hook priorities
+
+  Scenario: prioritized
+    Then before hooks are "cucumber, logging, db, cache"
@@ -0,0 +1,13 @@
+Feature: Hooks
+
+  Scenario: untagged
+    Then before hooks are "db"
+
+  @http
+  Scenario: tagged
+    Then before hooks are "db, http"
+
+  @http
+  Rule: tagged rule
+    Scenario: inherits tags
+      Then before hooks are "db, http"
@@ -0,0 +1,17 @@
+# language: de
+Funktionalität: Deutsch
+
+  Grundlage:
+    Angenommen ein Schritt
+
+  Szenario: erfolgreich
+    Wenn ein Schritt
+    Dann ein Schritt
+    Und ein Schritt
+
+  Szenariogrundriss: fehlschlagend
+    Angenommen ein <art> Schritt
+
+    Beispiele:
+      | art           |
+      | fehlschlagend |
@@ -0,0 +1,22 @@
+Feature: Line endings
+
+  Background:
+    Given a step
+
+  Scenario: plain
+    When a step with a doc string
+      """
+      doc
+      string
+      """
+    Then a failing step
+
+  Scenario Outline: outline
+    When a step with a table
+      | a | b |
+      | 1 | 2 |
+    Then a failing <step>
+
+    Examples:
+      | step |
+      | step |
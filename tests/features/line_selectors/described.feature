@@ -0,0 +1,12 @@
+Feature: Described line selectors
+
+  Scenario Outline: outline <kind>
+    Given a <kind> step
+
+    Examples:
+      Rows are separated from the keyword.
+
+      | kind   |
+      | first  |
+      # Comments are allowed between the rows.
+      | second |
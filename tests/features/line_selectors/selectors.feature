@@ -0,0 +1,19 @@
+Feature: Line selectors
+
+  Scenario: first
+    Given a step
+    And a step
+
+  Scenario Outline: outline
+    Given a <kind> step
+
+    Examples:
+      | kind   |
+      | first  |
+      | second |
+      | third  |
+
+  Rule: rule
+
+    Scenario: ruled
+      Given a step
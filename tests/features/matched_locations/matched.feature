@@ -0,0 +1,7 @@
+Feature: Matched locations
+
+  Background:
+    Given a background step
+
+  Scenario: passing
+    When a step
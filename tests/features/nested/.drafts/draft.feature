@@ -0,0 +1,4 @@
+Feature: Draft
+
+  Scenario: draft
+    Given an unknown step
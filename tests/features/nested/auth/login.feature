@@ -0,0 +1,4 @@
+Feature: Login
+
+  Scenario: login
+    Given a step
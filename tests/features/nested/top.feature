@@ -0,0 +1,4 @@
+Feature: Top
+
+  Scenario: top
+    Given a step
@@ -0,0 +1,4 @@
+Feature: Deep
+
+  Scenario: deep
+    Given a step
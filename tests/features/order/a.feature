@@ -0,0 +1,12 @@
+Feature: A
+
+  Scenario: a1
+    Given a step
+
+  Scenario: a2
+    Given a step
+
+  Rule: rule
+
+    Scenario: a3
+      Given a step
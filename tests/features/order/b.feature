@@ -0,0 +1,7 @@
+Feature: B
+
+  Scenario: b1
+    Given a step
+
+  Scenario: b2
+    Given a step
@@ -0,0 +1,4 @@
+Feature: Output truncation
+
+  Scenario: huge output
+    Given a step dumping 100 lines
@@ -0,0 +1,15 @@
+Feature: Panic details
+
+  Scenario: panicking step
+    Given a panicking step
+
+  Scenario: panicking step in another file
+    Given a panicking step in another file
+
+  Scenario: panicking thread after a caught panic
+    Given a step catching a panic
+    Then a step panicking in a thread
+
+  Scenario: resumed panic after a caught panic
+    Given a step catching a panic
+    Then a step resuming a panic
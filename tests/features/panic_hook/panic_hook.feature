@@ -0,0 +1,8 @@
+Feature: Panic hook
+
+  Scenario: panicking step
+    Given a panicking step
+
+  @hanging
+  Scenario: hanging step
+    Given a hanging step
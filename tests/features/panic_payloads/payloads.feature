@@ -0,0 +1,10 @@
+Feature: Panic payloads
+
+  Scenario: unwrapped error
+    Given an unwrapped I/O error
+
+  Scenario: I/O error payload
+    Given an I/O error payload
+
+  Scenario: boxed error payload
+    Given a boxed error payload
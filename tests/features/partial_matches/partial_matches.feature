@@ -0,0 +1,3 @@
+Feature: Partial matches
+  Scenario: trailing text
+    Given I have 5 cukes and 3 bananas
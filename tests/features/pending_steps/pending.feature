@@ -0,0 +1,9 @@
+Feature: Pending steps
+
+  Scenario: pending step
+    Given a ready step
+    When a pending step
+    Then a ready step
+
+  Scenario: unimplemented step
+    When an unimplemented step
@@ -0,0 +1,12 @@
+Feature: Repeat
+
+  Scenario: stable
+    Given a passing step
+
+  Scenario: flaky
+    Given a step failing every second run
+
+  Rule: rule
+
+    Scenario: ruled
+      Given a passing step
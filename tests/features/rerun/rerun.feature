@@ -0,0 +1,17 @@
+Feature: Rerun
+
+  Scenario: passing
+    Given a step
+
+  Scenario: failing
+    Given a failing step
+
+  Scenario Outline: outline
+    Given a <kind> step
+
+    Examples:
+      Rows are separated from the keyword.
+
+      | kind    |
+      | passing |
+      | failing |
@@ -0,0 +1,25 @@
+@feature
+Feature: Results
+
+  Background:
+    Given 1 step
+
+  Scenario: passed
+    When 2 steps
+      """
+      docstring
+      """
+
+  Scenario: failed
+    When 2 steps
+    Then fail
+
+  @scenario
+  Scenario: skipped
+    When unknown
+
+  @rule
+  Rule: rule
+    Scenario: passed in rule
+      Then 2 steps
+        | cell |
@@ -0,0 +1,11 @@
+Feature: Scratch context
+
+  Scenario: values are shared between steps
+    Given an order 42 is placed
+    Then the order 42 is stored
+
+  Scenario: values are fresh for each scenario
+    Then no order is stored
+
+  Scenario: missing value fails the step
+    Then the order 42 is stored
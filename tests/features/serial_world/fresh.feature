@@ -0,0 +1,8 @@
+Feature: Fresh
+
+  Scenario: first
+    Given the world is fresh
+    When it's incremented
+
+  Scenario: second
+    Given the world is fresh
@@ -0,0 +1,15 @@
+@serial_world
+Feature: Shared
+
+  Scenario: first
+    Given the world is fresh
+    When it's incremented
+
+  Scenario: second
+    Then it's incremented once
+
+  Scenario: failing
+    When it panics
+
+  Scenario: poisoned
+    Then it's incremented once
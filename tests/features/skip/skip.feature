@@ -0,0 +1,9 @@
+Feature: Skipping at runtime
+
+  Scenario: skipped scenario
+    Given a ready step
+    When a step requiring DOCKER_HOST
+    Then a ready step
+
+  Scenario: executed scenario
+    Given a ready step
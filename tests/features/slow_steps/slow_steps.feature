@@ -0,0 +1,4 @@
+Feature: Slow steps
+  Scenario: slow and fast steps
+    Given a slow step
+    Then a fast step
@@ -0,0 +1,16 @@
+Feature: Snippets
+
+  Scenario: quoted string and number
+    Given the user "alice" has 3 items
+
+  Scenario: same step with other values
+    Given the user "bob" has 7 items
+
+  Scenario: literal
+    When the user clicks login
+
+  Scenario: float
+    Then the total is 4.5
+
+  Scenario: defined
+    Given a defined step
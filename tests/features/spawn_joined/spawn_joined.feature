@@ -0,0 +1,13 @@
+Feature: Joined threads
+
+  Scenario: spawned thread finishes
+    When a joined thread increments the counter
+    Then the counter is incremented
+
+  Scenario: spawned thread waits for the executor
+    When a joined thread waits for a task on the executor
+    Then the counter is incremented
+
+  Scenario: spawned thread panics
+    When a joined thread panics
+    Then the counter is incremented
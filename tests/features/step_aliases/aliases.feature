@@ -0,0 +1,18 @@
+Feature: Step aliases
+
+  Scenario: macro aliases
+    Given a user
+    When I sign in
+    And I log in
+    Then the user signed in 2 times
+
+  Scenario: overlapping aliases
+    Given a user
+    When I authenticate
+    Then the user signed in 1 times
+
+  Scenario: builder aliases
+    Given a user
+    When I log out
+    And I sign out
+    Then the user signed out 2 times
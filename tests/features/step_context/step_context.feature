@@ -0,0 +1,18 @@
+@feature
+Feature: Step context
+
+  Background:
+    Given feature is "Step context"
+
+  @readonly
+  Scenario: tagged
+    Then scenario is "tagged"
+    And tags are "feature,readonly"
+
+  @rule
+  Rule: tagged rule
+
+    @scenario
+    Scenario: in rule
+      Then scenario is "in rule"
+      And tags are "feature,rule,scenario"
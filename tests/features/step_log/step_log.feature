@@ -0,0 +1,5 @@
+Feature: Step logs
+
+  Scenario: long-running step
+    Given a long-running step
+    Then a step
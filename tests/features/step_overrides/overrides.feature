@@ -0,0 +1,3 @@
+Feature: Overrides
+  Scenario: overrides
+    Given a user exists
@@ -0,0 +1,7 @@
+Feature: Step providers
+
+  Scenario: provided and local steps
+    Given a client for "https://example.com"
+    When the client requests "/health"
+    Then a single request is made
+    And the last request is "https://example.com/health"
@@ -0,0 +1,8 @@
+Feature: Step timeout
+
+  Scenario: hanging step
+    Given a hanging step
+    Then a step
+
+  Scenario: quick step
+    Given a step
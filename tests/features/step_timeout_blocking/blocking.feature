@@ -0,0 +1,5 @@
+Feature: Blocking step timeout
+
+  Scenario: blocking step
+    Given a blocking step
+    Then a step
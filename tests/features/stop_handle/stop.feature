@@ -0,0 +1,9 @@
+Feature: Stop handle
+
+  Scenario: stopped
+    Given a step
+    When a stop is requested
+    Then a step
+
+  Scenario: not started
+    Given a step
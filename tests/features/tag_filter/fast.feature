@@ -0,0 +1,12 @@
+Feature: Fast
+
+  @smoke
+  Scenario: smoke
+    Given a step
+
+  @smoke @wip
+  Scenario: wip smoke
+    Given a step
+
+  Scenario: untagged
+    Given an undefined step
@@ -0,0 +1,6 @@
+@slow
+Feature: Slow
+
+  @smoke
+  Scenario: slow smoke
+    Given a step
@@ -0,0 +1,6 @@
+Feature: Unused steps
+
+  Scenario: using some steps
+    Given a used step
+    When I sign in
+    Then 3 apples are left
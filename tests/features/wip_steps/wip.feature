@@ -0,0 +1,14 @@
+Feature: Work-in-progress steps
+
+  Scenario: wip step is pending
+    Given a ready step
+    When a wip step
+    Given a ready step
+
+  Scenario: undefined step is skipped
+    Given a ready step
+    When an undefined step
+
+  Scenario: no wip steps
+    Given a ready step
+    Given a ready step
@@ -0,0 +1,18 @@
+Feature: World lifecycle
+
+  @hooked
+  Scenario: hooked
+    Then log is "collection, trait"
+
+  Scenario: lazy
+    Then log is "trait"
+
+  Scenario: failing before
+    Then log is "trait"
+
+  @hooked
+  Scenario: failing before hooked
+    Then log is "collection, trait"
+
+  Scenario: failing after
+    Then log is "trait"
@@ -0,0 +1,18 @@
+Feature: World pool
+
+  Scenario: first
+    Given the world is fresh
+    When the world is used
+
+  Scenario: failing
+    Given the world is fresh
+    When the world is used
+    Then the step fails
+
+  Scenario: third
+    Given the world is fresh
+    When the world is used
+
+  Scenario: fourth
+    Given the world is fresh
+    When the world is used
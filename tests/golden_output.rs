@@ -0,0 +1,68 @@
+use std::fs;
+
+use cucumber::{given, then, when, writer, World as _, WriterExt as _};
+
+#[given(regex = r"^(\S+) is hungry$")]
+fn is_hungry(_: &mut World, _who: String) {}
+
+#[when(expr = "she eats {int} cucumbers")]
+fn eats(w: &mut World, n: usize) {
+    w.eaten += n;
+}
+
+#[then("she is full")]
+fn is_full(w: &mut World) {
+    assert_eq!(w.eaten, 3);
+}
+
+#[given("a step")]
+fn step(_: &mut World) {}
+
+#[when("a step with a doc string")]
+fn doc_string(_: &mut World) {}
+
+#[when("a step with a table")]
+fn table(_: &mut World) {}
+
+#[then("a failing step")]
+fn failing(_: &mut World) {
+    panic!("failed");
+}
+
+async fn golden_output(path: &str) -> String {
+    let mut basic = writer::Basic::raw(
+        Vec::new(),
+        writer::Coloring::Always,
+        writer::Verbosity::Default,
+    );
+    basic.apply_cli(writer::basic::Cli {
+        golden: true,
+        ..writer::basic::Cli::default()
+    });
+
+    let writer = World::cucumber()
+        .with_writer(basic.normalized())
+        .with_default_cli()
+        .run(path)
+        .await;
+    String::from_utf8(writer.inner_writer().to_vec()).unwrap()
+}
+
+#[tokio::test]
+async fn snapshots_example_features() {
+    for (features, snapshot) in [
+        ("tests/features/readme", "tests/golden/readme.stdout"),
+        (
+            "tests/features/line_endings/lf.feature",
+            "tests/golden/lf.stdout",
+        ),
+    ] {
+        let expected = fs::read_to_string(snapshot).unwrap();
+        assert_eq!(golden_output(features).await, expected, "{snapshot}");
+    }
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World {
+    eaten: usize,
+}
@@ -0,0 +1,169 @@
+use std::{
+    future,
+    sync::{Mutex, PoisonError},
+};
+
+use cucumber::{
+    step, then, writer, StatsWriter as _, World as _, WriterExt as _,
+};
+use futures::FutureExt as _;
+
+static AFTER_HOOKS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+static PRIORITIZED_AFTER_HOOKS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+#[then(expr = "before hooks are {string}")]
+fn before_hooks_are(world: &mut World, expected: String) {
+    assert_eq!(world.0.join(", "), expected);
+}
+
+fn db_steps() -> step::Collection<World> {
+    step::Collection::new()
+        .before(None, |_, _, _, w: &mut World| {
+            w.0.push("db");
+            future::ready(()).boxed_local()
+        })
+        .after(None, |_, _, _, _, w| {
+            if let Some(w) = w {
+                w.0.push("db");
+            }
+            future::ready(()).boxed_local()
+        })
+}
+
+fn http_steps() -> step::Collection<World> {
+    step::Collection::new()
+        .before(Some("@http".parse().unwrap()), |_, _, _, w: &mut World| {
+            w.0.push("http");
+            future::ready(()).boxed_local()
+        })
+        .after(Some("@http".parse().unwrap()), |_, _, _, _, w| {
+            if let Some(w) = w {
+                w.0.push("http");
+            }
+            future::ready(()).boxed_local()
+        })
+}
+
+fn logging_steps() -> step::Collection<World> {
+    step::Collection::new()
+        .before_with_priority(10, None, |_, _, _, w: &mut World| {
+            w.0.push("logging");
+            future::ready(()).boxed_local()
+        })
+        .after_with_priority(10, None, |_, _, _, _, w| {
+            if let Some(w) = w {
+                w.0.push("logging");
+            }
+            future::ready(()).boxed_local()
+        })
+}
+
+fn cache_steps() -> step::Collection<World> {
+    step::Collection::new()
+        .before_with_priority(-1, None, |_, _, _, w: &mut World| {
+            w.0.push("cache");
+            future::ready(()).boxed_local()
+        })
+        .after_with_priority(-1, None, |_, _, _, _, w| {
+            if let Some(w) = w {
+                w.0.push("cache");
+            }
+            future::ready(()).boxed_local()
+        })
+}
+
+#[tokio::test]
+async fn merges_hooks_of_collections() {
+    let writer = World::cucumber()
+        .steps(World::collection().append(db_steps()).append(http_steps()))
+        .after(|_, _, sc, _, w| {
+            let log = w.map(|w| w.0.join(", ")).unwrap_or_default();
+            AFTER_HOOKS
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .push(format!("{}: {log}", sc.name));
+            future::ready(()).boxed_local()
+        })
+        .with_writer(writer::Basic::stdout().summarized())
+        .max_concurrent_scenarios(1)
+        .with_default_cli()
+        .run("tests/features/hooks")
+        .await;
+
+    assert_eq!(writer.passed_steps(), 3);
+    assert_eq!(writer.failed_steps(), 0);
+    assert_eq!(writer.hook_errors(), 0);
+
+    let mut after_hooks = AFTER_HOOKS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone();
+    after_hooks.sort();
+    assert_eq!(
+        after_hooks,
+        [
+            "inherits tags: db, http, http, db",
+            "tagged: db, http, http, db",
+            "untagged: db, db",
+        ],
+    );
+}
+
+#[tokio::test]
+async fn runs_collection_after_hooks_only() {
+    static RUN: Mutex<usize> = Mutex::new(0);
+
+    let writer = World::cucumber()
+        .steps(World::collection().after(None, |_, _, _, _, _| {
+            *RUN.lock().unwrap_or_else(PoisonError::into_inner) += 1;
+            future::ready(()).boxed_local()
+        }))
+        .with_writer(writer::Basic::stdout().summarized())
+        .with_default_cli()
+        .run("tests/features/hooks")
+        .await;
+
+    assert_eq!(writer.hook_errors(), 0);
+    assert_eq!(*RUN.lock().unwrap_or_else(PoisonError::into_inner), 3);
+}
+
+#[tokio::test]
+async fn orders_hooks_by_priority() {
+    let writer = World::cucumber()
+        .steps(
+            World::collection()
+                .append(cache_steps())
+                .append(db_steps())
+                .append(logging_steps()),
+        )
+        .before(|_, _, _, w| {
+            w.0.push("cucumber");
+            future::ready(()).boxed_local()
+        })
+        .after(|_, _, _, _, w| {
+            let log = w.map(|w| w.0.join(", ")).unwrap_or_default();
+            PRIORITIZED_AFTER_HOOKS
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .push(log);
+            future::ready(()).boxed_local()
+        })
+        .with_writer(writer::Basic::stdout().summarized())
+        .with_default_cli()
+        .run("tests/features/hook_priorities")
+        .await;
+
+    assert_eq!(writer.passed_steps(), 1);
+    assert_eq!(writer.failed_steps(), 0);
+    assert_eq!(writer.hook_errors(), 0);
+    assert_eq!(
+        *PRIORITIZED_AFTER_HOOKS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner),
+        ["cucumber, logging, db, cache, cache, db, logging"],
+    );
+}
+
+#[derive(Clone, Debug, Default, cucumber::World)]
+struct World(Vec<&'static str>);
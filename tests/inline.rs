@@ -0,0 +1,63 @@
+use cucumber::{
+    given, parser::basic::Inline, writer, writer::Stats as _, World as _,
+    WriterExt as _,
+};
+
+#[given("a step")]
+fn step(_: &mut World) {}
+
+#[given("a failing step")]
+fn failing(_: &mut World) {
+    panic!("failed");
+}
+
+#[tokio::test]
+async fn runs_inline_features() {
+    let writer = World::cucumber()
+        .with_writer(
+            writer::Basic::raw(
+                Vec::new(),
+                writer::Coloring::Never,
+                writer::Verbosity::Default,
+            )
+            .summarized()
+            .normalized(),
+        )
+        .with_default_cli()
+        .run(
+            Inline::new()
+                .feature(
+                    "embedded/first.feature",
+                    "Feature: First\n\n  \
+                     Scenario: passing\n    \
+                     Given a step\n\n  \
+                     Scenario Outline: failing\n    \
+                     Given a <kind> step\n\n    \
+                     Examples:\n      \
+                     | kind    |\n      \
+                     | failing |\n",
+                )
+                .feature(
+                    "embedded/broken.feature",
+                    "Feature: Broken\n\n  @tag\n",
+                ),
+        )
+        .await;
+
+    assert_eq!(writer.scenarios_stats().passed, 1);
+    assert_eq!(writer.scenarios_stats().failed, 1);
+    assert_eq!(writer.parsing_errors(), 1);
+
+    let output = String::from_utf8(writer.inner_writer().to_vec()).unwrap();
+    assert!(
+        output.contains("embedded/first.feature:7:5"),
+        "unexpected output:\n{output}",
+    );
+    assert!(
+        output.contains("embedded/broken.feature"),
+        "unexpected output:\n{output}",
+    );
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
@@ -1,4 +1,8 @@
-use std::{fs, io::Read as _};
+use std::{
+    fs,
+    io::Read as _,
+    sync::atomic::{AtomicBool, Ordering},
+};
 
 use cucumber::{given, then, when, writer, World as _};
 use futures::FutureExt as _;
@@ -21,6 +25,17 @@ fn step(world: &mut World) {
     assert!(world.0 < 4, "Too much!");
 }
 
+#[given("a step failing once")]
+fn flaky_step(_: &mut World) {
+    /// Indicator whether the step has failed already.
+    static FAILED: AtomicBool = AtomicBool::new(false);
+
+    assert!(FAILED.swap(true, Ordering::SeqCst), "First run!");
+}
+
+#[given("a passing step")]
+fn passing_step(_: &mut World) {}
+
 #[tokio::test]
 async fn test() {
     let mut file = NamedTempFile::new().unwrap();
@@ -83,5 +98,26 @@ async fn test() {
     );
 }
 
+#[tokio::test]
+async fn marks_flaky_scenarios() {
+    let mut file = NamedTempFile::new().unwrap();
+    drop(
+        World::cucumber()
+            .with_writer(writer::Json::new(file.reopen().unwrap()))
+            .with_default_cli()
+            .run("tests/features/flaky")
+            .await,
+    );
+
+    let mut buffer = String::new();
+    file.read_to_string(&mut buffer).unwrap();
+
+    let flaky = buffer.find(r#""name":"flaky""#).unwrap();
+    let stable = buffer.find(r#""name":"stable""#).unwrap();
+    let marker = buffer.find(r#""flaky":true"#).unwrap();
+    assert_eq!(buffer.matches(r#""flaky":true"#).count(), 1, "{buffer}");
+    assert!(flaky < marker && marker < stable, "{buffer}");
+}
+
 #[derive(Clone, Copy, cucumber::World, Debug, Default)]
 struct World(usize);
@@ -1,4 +1,8 @@
-use std::{fs, io::Read as _};
+use std::{
+    fs,
+    io::Read as _,
+    sync::atomic::{AtomicBool, Ordering},
+};
 
 use cucumber::{given, then, when, writer, World as _};
 use futures::FutureExt as _;
@@ -20,6 +24,17 @@ fn step(world: &mut World) {
     tracing::info!("step");
 }
 
+#[given("a step failing once")]
+fn flaky_step(_: &mut World) {
+    /// Indicator whether the step has failed already.
+    static FAILED: AtomicBool = AtomicBool::new(false);
+
+    assert!(FAILED.swap(true, Ordering::SeqCst), "First run!");
+}
+
+#[given("a passing step")]
+fn passing_step(_: &mut World) {}
+
 #[tokio::test]
 async fn output() {
     let mut file = NamedTempFile::new().unwrap();
@@ -70,5 +85,29 @@ async fn output() {
     );
 }
 
+#[tokio::test]
+async fn marks_flaky_scenarios() {
+    let mut file = NamedTempFile::new().unwrap();
+    drop(
+        World::cucumber()
+            .with_writer(writer::JUnit::new(file.reopen().unwrap(), 0))
+            .with_default_cli()
+            .run("tests/features/flaky")
+            .await,
+    );
+
+    let mut buffer = String::new();
+    file.read_to_string(&mut buffer).unwrap();
+
+    assert_eq!(buffer.matches("<system-err>").count(), 1, "{buffer}",);
+    assert!(
+        buffer.contains(
+            "<system-err><![CDATA[Flaky: passed on retry attempt 1/2]]>\
+             </system-err>",
+        ),
+        "{buffer}",
+    );
+}
+
 #[derive(Clone, Copy, cucumber::World, Debug, Default)]
 struct World(usize);
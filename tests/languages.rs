@@ -0,0 +1,53 @@
+use cucumber::{
+    given, then, when, writer, writer::Stats as _, World as _, WriterExt as _,
+};
+
+#[given("ein Schritt")]
+#[when("ein Schritt")]
+#[then("ein Schritt")]
+fn step(_: &mut World) {}
+
+#[given("ein fehlschlagend Schritt")]
+fn failing(_: &mut World) {
+    panic!("fehlgeschlagen");
+}
+
+#[tokio::test]
+async fn renders_localized_keywords() {
+    let writer = World::cucumber()
+        .with_writer(
+            writer::Basic::raw(
+                Vec::new(),
+                writer::Coloring::Never,
+                writer::Verbosity::Default,
+            )
+            .summarized()
+            .normalized(),
+        )
+        .with_default_cli()
+        .run("tests/features/languages/de.feature")
+        .await;
+
+    assert_eq!(writer.scenarios_stats().passed, 1);
+    assert_eq!(writer.scenarios_stats().failed, 1);
+    assert_eq!(writer.steps_stats().passed, 5);
+    assert_eq!(writer.steps_stats().failed, 1);
+    assert_eq!(writer.parsing_errors(), 0);
+
+    let output = String::from_utf8(writer.inner_writer().to_vec()).unwrap();
+    for expected in [
+        "Funktionalität: Deutsch",
+        "Szenario: erfolgreich",
+        "Szenariogrundriss: fehlschlagend",
+        "✔  Wenn ein Schritt",
+        "✔  Dann ein Schritt",
+        "✔  Und ein Schritt",
+        "✘  Angenommen ein fehlschlagend Schritt",
+        "de.feature:13:5",
+    ] {
+        assert!(output.contains(expected), "unexpected output:\n{output}");
+    }
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
@@ -0,0 +1,56 @@
+use cucumber::{given, then, when, writer, World as _, WriterExt as _};
+
+#[given("a step")]
+fn step(_: &mut World) {}
+
+#[when("a step with a doc string")]
+fn doc_string(_: &mut World, step: &cucumber::gherkin::Step) {
+    assert_eq!(step.docstring.as_deref(), Some("\ndoc\nstring\n"));
+}
+
+#[when("a step with a table")]
+fn table(_: &mut World, step: &cucumber::gherkin::Step) {
+    let rows = &step.table.as_ref().unwrap().rows;
+    assert_eq!(rows, &[vec!["a", "b"], vec!["1", "2"]]);
+}
+
+#[then("a failing step")]
+fn failing(_: &mut World) {
+    panic!("failed");
+}
+
+async fn output(file: &str) -> String {
+    let writer = World::cucumber()
+        .with_writer(
+            writer::Basic::raw(
+                Vec::new(),
+                writer::Coloring::Never,
+                writer::Verbosity::Default,
+            )
+            .normalized(),
+        )
+        .with_default_cli()
+        .max_concurrent_scenarios(1)
+        .run(format!("tests/features/line_endings/{file}"))
+        .await;
+    String::from_utf8(writer.inner_writer().to_vec())
+        .unwrap()
+        .replace(file, "<file>")
+}
+
+#[tokio::test]
+async fn reports_same_positions_regardless_of_line_endings() {
+    let crlf =
+        std::fs::read_to_string("tests/features/line_endings/crlf.feature")
+            .unwrap();
+    assert!(crlf.contains("\r\n"), "fixture has no CRLF line endings");
+
+    let lf = output("lf.feature").await;
+    assert!(lf.contains("<file>:12:5"), "{lf}");
+    assert!(lf.contains("<file>:18:5"), "{lf}");
+    assert!(lf.contains("Example: tests/features/line_endings/<file>:22"));
+    assert_eq!(output("crlf.feature").await, lf);
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
@@ -0,0 +1,96 @@
+use std::cell::RefCell;
+
+use cucumber::{given, writer::out::Styles, World as _};
+use futures::FutureExt as _;
+
+thread_local! {
+    // Each test runs on its own thread, along with its `Scenario`s.
+    static SCENARIOS: RefCell<Vec<String>> = RefCell::default();
+}
+
+#[given(regex = r"^a(?: \w+)? step$")]
+fn step(_: &mut World) {}
+
+async fn run_scenarios(input: &str) -> Vec<String> {
+    SCENARIOS.with(|s| s.borrow_mut().clear());
+    _ = World::cucumber()
+        .max_concurrent_scenarios(1)
+        .before(|_, _, sc, _| {
+            SCENARIOS.with(|s| s.borrow_mut().push(sc.name.clone()));
+            async {}.boxed_local()
+        })
+        .with_default_cli()
+        .run(input)
+        .await;
+    SCENARIOS.with(|s| s.borrow().clone())
+}
+
+#[tokio::test]
+async fn selects_scenarios_by_lines() {
+    const FILE: &str = "tests/features/line_selectors/selectors.feature";
+
+    assert_eq!(run_scenarios(&format!("{FILE}:3")).await, ["first"]);
+    assert_eq!(run_scenarios(&format!("{FILE}:5")).await, ["first"]);
+    assert_eq!(
+        run_scenarios(&format!("{FILE}:8")).await,
+        ["outline", "outline", "outline"],
+    );
+    assert_eq!(run_scenarios(&format!("{FILE}:13")).await, ["outline"]);
+    assert_eq!(
+        run_scenarios(&format!("{FILE}:19:12")).await,
+        ["outline", "ruled"],
+    );
+    assert!(run_scenarios(&format!("{FILE}:1")).await.is_empty());
+}
+
+#[tokio::test]
+async fn selects_examples_rows_separated_from_keyword() {
+    const FILE: &str = "tests/features/line_selectors/described.feature";
+
+    assert_eq!(
+        run_scenarios(&format!("{FILE}:12")).await,
+        ["outline second"],
+    );
+    assert_eq!(
+        run_scenarios(&format!("{FILE}:10:12")).await,
+        ["outline first", "outline second"],
+    );
+    assert_eq!(
+        run_scenarios(&format!("{FILE}:7")).await,
+        ["outline first", "outline second"],
+    );
+}
+
+#[tokio::test]
+async fn counts_not_selected_scenarios_as_filtered_out() {
+    const FILE: &str = "tests/features/line_selectors/selectors.feature";
+
+    for (lines, (passed, filtered)) in
+        [("3", (1, 4)), ("13", (1, 4)), ("8:19", (4, 1))]
+    {
+        let writer = World::cucumber()
+            .with_default_cli()
+            .run(format!("{FILE}:{lines}"))
+            .await;
+
+        assert_eq!(
+            writer.scenarios_stats().passed,
+            passed,
+            "Wrong number of passed `Scenario`s with `:{lines}`",
+        );
+        assert_eq!(
+            writer.filtered_scenarios(),
+            filtered,
+            "Wrong number of filtered out `Scenario`s with `:{lines}`",
+        );
+        assert!(
+            Styles::new()
+                .summary(&writer)
+                .contains(&format!(", {filtered} filtered out\n")),
+            "No filtered out `Scenario`s in summary with `:{lines}`",
+        );
+    }
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
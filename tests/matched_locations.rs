@@ -0,0 +1,43 @@
+use cucumber::{given, when, writer, World as _, WriterExt as _};
+
+#[given("a background step")]
+fn background(_: &mut World) {}
+
+#[when("a step")]
+fn step(_: &mut World) {}
+
+async fn run(verbosity: writer::Verbosity) -> String {
+    let writer = World::cucumber()
+        .with_writer(
+            writer::Basic::raw(Vec::new(), writer::Coloring::Never, verbosity)
+                .summarized()
+                .normalized(),
+        )
+        .with_default_cli()
+        .run("tests/features/matched_locations")
+        .await;
+    String::from_utf8(writer.inner_writer().to_vec()).unwrap()
+}
+
+#[tokio::test]
+async fn outputs_matched_definitions_when_verbose() {
+    let output = run(writer::Verbosity::ShowWorld).await;
+
+    assert_eq!(
+        output
+            .matches("Matched: tests/matched_locations.rs:")
+            .count(),
+        2,
+        "unexpected output:\n{output}",
+    );
+}
+
+#[tokio::test]
+async fn omits_matched_definitions_by_default() {
+    let output = run(writer::Verbosity::Default).await;
+
+    assert!(!output.contains("Matched:"), "unexpected output:\n{output}");
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
@@ -0,0 +1,33 @@
+use std::sync::Mutex;
+
+use cucumber::{given, parser, StatsWriter as _, World as _};
+use futures::FutureExt as _;
+
+static FEATURES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+#[given("a step")]
+fn step(_: &mut World) {}
+
+#[tokio::test]
+async fn runs_each_feature_once() {
+    let writer = World::cucumber()
+        .max_concurrent_scenarios(1)
+        .before(|feature, _, _, _| {
+            FEATURES.lock().unwrap().push(feature.name.clone());
+            async {}.boxed_local()
+        })
+        .with_default_cli()
+        .run(parser::basic::Paths::from([
+            "tests/features/nested/auth",
+            "tests/features/nested",
+            "tests/features/nested/top.feature",
+        ]))
+        .await;
+
+    assert_eq!(writer.passed_steps(), 3);
+    assert!(!writer.execution_has_failed());
+    assert_eq!(*FEATURES.lock().unwrap(), ["Login", "Top", "Deep"]);
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
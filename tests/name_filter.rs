@@ -0,0 +1,22 @@
+use std::env;
+
+use cucumber::{cli, given, World as _};
+
+#[derive(Clone, Copy, cucumber::World, Debug, Default)]
+struct World;
+
+#[given("a step")]
+fn step(_: &mut World) {}
+
+#[tokio::test]
+async fn filters_by_name_from_env_var() {
+    env::set_var(cli::NAME_ENV_VAR, "^(wip )?smoke$");
+
+    let writer = World::cucumber()
+        .with_default_cli()
+        .run("tests/features/tag_filter")
+        .await;
+
+    assert_eq!(writer.scenarios_stats().passed, 2);
+    assert_eq!(writer.filtered_scenarios(), 2);
+}
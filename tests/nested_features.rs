@@ -0,0 +1,30 @@
+use std::sync::Mutex;
+
+use cucumber::{given, StatsWriter as _, World as _};
+use futures::FutureExt as _;
+
+static FEATURES: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+#[given("a step")]
+fn step(_: &mut World) {}
+
+#[tokio::test]
+async fn discovers_features_recursively_in_sorted_order() {
+    let writer = World::cucumber()
+        .max_concurrent_scenarios(1)
+        .before(|feature, _, _, _| {
+            FEATURES.lock().unwrap().push(feature.name.clone());
+            async {}.boxed_local()
+        })
+        .with_default_cli()
+        .run("tests/features/nested")
+        .await;
+
+    assert_eq!(writer.passed_steps(), 3);
+    assert_eq!(writer.skipped_steps(), 0);
+    assert!(!writer.execution_has_failed());
+    assert_eq!(*FEATURES.lock().unwrap(), ["Login", "Top", "Deep"]);
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
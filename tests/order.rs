@@ -0,0 +1,99 @@
+use std::sync::{Arc, Mutex};
+
+use clap::Parser as _;
+use cucumber::{
+    cli, given, parser,
+    runner::{self, basic::Order},
+    writer, World as _, WriterExt as _,
+};
+use futures::FutureExt as _;
+
+#[given("a step")]
+fn step(_: &mut World) {}
+
+async fn run_order(order: Order) -> Vec<String> {
+    let started = Arc::new(Mutex::new(Vec::new()));
+    let rec = Arc::clone(&started);
+    World::cucumber()
+        .max_concurrent_scenarios(1)
+        .order(order)
+        .before(move |_, _, sc, _| {
+            rec.lock().unwrap().push(sc.name.clone());
+            async {}.boxed_local()
+        })
+        .with_default_cli()
+        .run("tests/features/order")
+        .await;
+    let order = started.lock().unwrap().clone();
+    order
+}
+
+#[tokio::test]
+async fn runs_in_defined_order_by_default() {
+    assert_eq!(
+        run_order(Order::Defined).await,
+        ["a1", "a2", "a3", "b1", "b2"],
+    );
+}
+
+#[tokio::test]
+async fn shuffles_reproducibly_with_seed() {
+    let first = run_order(Order::Random(Some(42))).await;
+    assert_eq!(first, run_order(Order::Random(Some(42))).await);
+    assert_ne!(first, ["a1", "a2", "a3", "b1", "b2"]);
+
+    let mut sorted = first.clone();
+    sorted.sort();
+    assert_eq!(sorted, ["a1", "a2", "a3", "b1", "b2"]);
+}
+
+#[tokio::test]
+async fn outputs_seed_in_summary() {
+    let writer = World::cucumber()
+        .order(Order::Random(Some(42)))
+        .with_writer(
+            writer::Basic::raw(
+                Vec::new(),
+                writer::Coloring::Never,
+                writer::Verbosity::Default,
+            )
+            .summarized()
+            .normalized(),
+        )
+        .with_default_cli()
+        .run("tests/features/order")
+        .await;
+
+    assert_eq!(writer.inner_writer().seed(), Some(42));
+
+    let output =
+        String::from_utf8(writer.inner_writer().inner_writer().to_vec())
+            .unwrap();
+    assert!(
+        output.ends_with(
+            "5 steps (5 passed)\n\
+             Random order seed: 42 (reproduce with `--order random:42`)\n",
+        ),
+        "unexpected output:\n{output}",
+    );
+}
+
+type Opts =
+    cli::Opts<parser::basic::Cli, runner::basic::Cli, writer::basic::Cli>;
+
+#[test]
+fn parses_cli_option() {
+    for (arg, expected) in [
+        ("defined", Order::Defined),
+        ("random", Order::Random(None)),
+        ("random:7", Order::Random(Some(7))),
+    ] {
+        let opts = Opts::try_parse_from(["test", "--order", arg])
+            .expect("failed to parse CLI");
+        assert_eq!(opts.runner.order, Some(expected));
+    }
+    assert!(Opts::try_parse_from(["test", "--order", "random:seed",]).is_err());
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
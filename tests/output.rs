@@ -10,7 +10,7 @@ use regex::Regex;
 #[derive(cucumber::World, Debug, Default)]
 struct World(usize);
 
-#[given(regex = r"foo is (\d+)")]
+#[given(regex = r"foo is (\d+)(?: ambiguous)?")]
 #[when(regex = r"foo is (\d+)")]
 #[then(regex = r"foo is (\d+)")]
 fn step(w: &mut World, num: usize) {
@@ -141,7 +141,32 @@ impl<World: 'static + Debug> Writer<World> for DebugWriter {
 
                 format!("{ev:?}").into()
             }
-            Ok(ev) => format!("{ev:?}").into(),
+            Ok(ev) => {
+                // Aggregated results are omitted, as contain durations.
+                let result = match &ev {
+                    Cucumber::Feature(_, Feature::Finished(res)) => {
+                        Some(format!("{res:?}"))
+                    }
+                    Cucumber::Feature(
+                        _,
+                        Feature::Scenario(_, sc)
+                        | Feature::Rule(_, Rule::Scenario(_, sc)),
+                    ) => match &sc.event {
+                        Scenario::Finished(res) => Some(format!("{res:?}")),
+                        _ => None,
+                    },
+                    _ => None,
+                };
+                let ev = format!("{ev:?}");
+                result
+                    .map_or_else(
+                        || ev.clone(),
+                        |res| {
+                            ev.replace(&format!("Finished({res})"), "Finished")
+                        },
+                    )
+                    .into()
+            }
         };
 
         let without_span = SPAN_OR_PATH_RE.replace_all(ev.as_ref(), "");
@@ -155,7 +180,8 @@ impl<World: 'static + Debug> Writer<World> for DebugWriter {
 static SPAN_OR_PATH_RE: &Lazy<Regex> = regex!(
     "( span: Span \\{ start: (\\d+), end: (\\d+) },\
      |, col: (\\d+)\
-     | path: (None|(Some\\()?\"[^\"]*\")\\)?,?)"
+     | path: (None|(Some\\()?\"[^\"]*\")\\)?,?\
+     | file: \"[^\"]*\",)"
 );
 
 #[cfg(test)]
@@ -0,0 +1,57 @@
+use std::io;
+
+use cucumber::{given, writer, World as _, WriterExt as _};
+
+#[given("a step")]
+fn step(_: &mut World) {}
+
+#[derive(Debug, Default)]
+struct Output {
+    buf: Vec<u8>,
+    flushes: usize,
+}
+
+impl io::Write for Output {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buf.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.flushes += 1;
+        Ok(())
+    }
+}
+
+async fn flushes(flush: writer::basic::Flushing) -> usize {
+    let mut basic = writer::Basic::raw(
+        Output::default(),
+        writer::Coloring::Never,
+        writer::Verbosity::Default,
+    );
+    basic.apply_cli(writer::basic::Cli {
+        flush,
+        ..writer::basic::Cli::default()
+    });
+
+    let writer = World::cucumber()
+        .with_writer(basic.normalized())
+        .with_default_cli()
+        .run("tests/features/flush")
+        .await;
+    writer.inner_writer().flushes
+}
+
+#[tokio::test]
+async fn flushes_after_each_event() {
+    // Feature and 2 Scenarios started, 3 Steps started and passed, 2 Scenarios
+    // finished.
+    assert!(flushes(writer::basic::Flushing::Always).await >= 11);
+}
+
+#[tokio::test]
+async fn never_flushes_if_disabled() {
+    assert_eq!(flushes(writer::basic::Flushing::Never).await, 0);
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
@@ -0,0 +1,55 @@
+use std::fs;
+
+use cucumber::{given, writer, World as _, WriterExt as _};
+
+#[given(expr = "a step dumping {int} lines")]
+fn dumps(_: &mut World, n: usize) {
+    let lines = (1..=n).map(|i| format!("line {i}")).collect::<Vec<_>>();
+    panic!("{}", lines.join("\n"));
+}
+
+#[tokio::test]
+async fn truncates_captured_output() {
+    let mut basic = writer::Basic::raw(
+        Vec::new(),
+        writer::Coloring::Never,
+        writer::Verbosity::Default,
+    );
+    basic.apply_cli(writer::basic::Cli {
+        max_output_lines: Some(4),
+        ..writer::basic::Cli::default()
+    });
+
+    let writer = World::cucumber()
+        .with_writer(basic.normalized())
+        .with_default_cli()
+        .run("tests/features/output_truncation")
+        .await;
+    let output = String::from_utf8(writer.inner_writer().to_vec()).unwrap();
+
+    assert!(output.contains("line 1\n"), "{output}");
+    assert!(output.contains("line 2\n"), "{output}");
+    assert!(!output.contains("line 3\n"), "{output}");
+    assert!(!output.contains("line 98\n"), "{output}");
+    assert!(output.contains("line 99\n"), "{output}");
+    assert!(output.contains("line 100"), "{output}");
+
+    let (_, rest) = output
+        .split_once("… 96 lines omitted, full output: ")
+        .unwrap_or_else(|| panic!("no omitted lines marker: {output}"));
+    let (path, _) = rest.split_once(" …").unwrap();
+    let full = fs::read_to_string(path).unwrap();
+    assert_eq!(full.lines().count(), 100);
+    assert!(full.contains("line 50\n"));
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt as _;
+
+        let mode = fs::metadata(path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600, "file is accessible by others");
+    }
+    fs::remove_file(path).unwrap();
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
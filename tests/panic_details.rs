@@ -0,0 +1,111 @@
+#[path = "panic_details/other.rs"]
+mod other;
+
+use std::{
+    panic,
+    sync::atomic::{AtomicU32, Ordering},
+};
+
+use async_trait::async_trait;
+use cucumber::{
+    cli, event, given, parser, step, then, Event, World as _, Writer,
+    WriterExt as _,
+};
+
+static PANIC_LINE: AtomicU32 = AtomicU32::new(0);
+
+#[given("a panicking step")]
+fn panicking(_: &mut World) {
+    PANIC_LINE.store(line!() + 1, Ordering::SeqCst);
+    panic!("boom");
+}
+
+#[given("a step catching a panic")]
+fn catching(_: &mut World) {
+    panic::catch_unwind(|| panic!("caught")).unwrap_err();
+}
+
+#[then("a step panicking in a thread")]
+fn panicking_thread(_: &mut World, #[step] ctx: &step::Context) {
+    ctx.spawn_joined(|| panic!("in thread"));
+}
+
+#[then("a step resuming a panic")]
+fn resuming(_: &mut World) {
+    panic::resume_unwind(Box::new("resumed"));
+}
+
+#[derive(Default)]
+struct PanicsWriter(Vec<event::PanicDetails>);
+
+#[async_trait(?Send)]
+impl<W: 'static> Writer<W> for PanicsWriter {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<W>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{Cucumber, Feature, Scenario, Step, StepError};
+
+        if let Ok(Cucumber::Feature(_, Feature::Scenario(_, ev))) =
+            ev.map(Event::into_inner)
+        {
+            if let Scenario::Step(_, Step::Failed(.., StepError::Panic(d))) =
+                ev.event
+            {
+                self.0.push(d);
+            }
+        }
+    }
+}
+
+#[tokio::test]
+async fn reports_panic_location() {
+    let writer = World::cucumber()
+        .with_writer(PanicsWriter::default().normalized())
+        .with_default_cli()
+        .run("tests/features/panic_details")
+        .await;
+
+    let panics = &writer.inner_writer().0;
+    assert_eq!(panics.len(), 4);
+    assert_eq!(panics[0].payload.downcast_ref::<&str>(), Some(&"boom"));
+    assert_eq!(
+        panics[0].location,
+        Some(event::PanicLocation {
+            file: file!().to_owned(),
+            line: PANIC_LINE.load(Ordering::SeqCst),
+            column: 5,
+        }),
+    );
+    assert_eq!(panics[1].payload.downcast_ref::<&str>(), Some(&"other"));
+    assert_eq!(
+        panics[1].location,
+        Some(event::PanicLocation {
+            file: "tests/panic_details/other.rs".to_owned(),
+            line: other::PANIC_LINE.load(Ordering::SeqCst),
+            column: 5,
+        }),
+    );
+}
+
+#[tokio::test]
+async fn never_reports_stale_panic_location() {
+    let writer = World::cucumber()
+        .with_writer(PanicsWriter::default().normalized())
+        .with_default_cli()
+        .run("tests/features/panic_details")
+        .await;
+
+    let panics = &writer.inner_writer().0;
+    assert_eq!(panics.len(), 4);
+    assert_eq!(panics[2].payload.downcast_ref::<&str>(), Some(&"in thread"));
+    assert_eq!(panics[2].location, None);
+    assert_eq!(panics[3].payload.downcast_ref::<&str>(), Some(&"resumed"));
+    assert_eq!(panics[3].location, None);
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
@@ -0,0 +1,13 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use cucumber::given;
+
+use super::World;
+
+pub(super) static PANIC_LINE: AtomicU32 = AtomicU32::new(0);
+
+#[given("a panicking step in another file")]
+fn panicking(_: &mut World) {
+    PANIC_LINE.store(line!() + 1, Ordering::SeqCst);
+    panic!("other");
+}
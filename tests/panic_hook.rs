@@ -0,0 +1,58 @@
+use std::{
+    future, panic,
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+
+use cucumber::{given, StatsWriter as _, World as _};
+use tokio::time;
+
+static SENTINEL_CALLED: AtomicBool = AtomicBool::new(false);
+
+#[given("a panicking step")]
+fn panicking(_: &mut World) {
+    panic!("boom");
+}
+
+#[given("a hanging step")]
+async fn hanging(_: &mut World) {
+    future::pending::<()>().await;
+}
+
+/// Asserts the sentinel panic hook is the installed one.
+fn assert_sentinel_hook() {
+    SENTINEL_CALLED.store(false, Ordering::SeqCst);
+    panic::catch_unwind(|| panic!("sentinel")).unwrap_err();
+    assert!(
+        SENTINEL_CALLED.load(Ordering::SeqCst),
+        "sentinel hook is lost"
+    );
+}
+
+#[tokio::test]
+async fn restores_original_panic_hook() {
+    panic::set_hook(Box::new(|_| {
+        SENTINEL_CALLED.store(true, Ordering::SeqCst)
+    }));
+
+    let writer = World::cucumber()
+        .filter_run("tests/features/panic_hook", |_, _, sc| {
+            !sc.tags.iter().any(|t| t == "hanging")
+        })
+        .await;
+    assert!(writer.execution_has_failed());
+    assert_sentinel_hook();
+
+    time::timeout(
+        Duration::from_millis(100),
+        World::cucumber().run("tests/features/panic_hook"),
+    )
+    .await
+    .unwrap_err();
+    assert_sentinel_hook();
+
+    drop(panic::take_hook());
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
@@ -0,0 +1,48 @@
+use std::{error::Error, io, panic};
+
+use cucumber::{given, writer, World as _, WriterExt as _};
+
+#[given("an unwrapped I/O error")]
+fn unwrapped(_: &mut World) {
+    write().unwrap();
+}
+
+fn write() -> io::Result<()> {
+    Err(io::Error::new(io::ErrorKind::Other, "disk is full"))
+}
+
+#[given("an I/O error payload")]
+fn io_error(_: &mut World) {
+    panic::panic_any(io::Error::new(io::ErrorKind::Other, "no route"));
+}
+
+#[given("a boxed error payload")]
+fn boxed_error(_: &mut World) {
+    let err: Box<dyn Error + Send + Sync> = "connection reset".into();
+    panic::panic_any(err);
+}
+
+#[tokio::test]
+async fn outputs_non_string_payloads() {
+    let writer = World::cucumber()
+        .with_writer(
+            writer::Basic::raw(
+                Vec::new(),
+                writer::Coloring::Never,
+                writer::Verbosity::Default,
+            )
+            .normalized(),
+        )
+        .with_default_cli()
+        .run("tests/features/panic_payloads")
+        .await;
+    let output = String::from_utf8(writer.inner_writer().to_vec()).unwrap();
+
+    assert!(output.contains("disk is full"), "{output}");
+    assert!(output.contains("no route"), "{output}");
+    assert!(output.contains("connection reset"), "{output}");
+    assert!(!output.contains("Could not resolve"), "{output}");
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
@@ -0,0 +1,136 @@
+use cucumber::{
+    gherkin::{self, GherkinEnv},
+    given,
+    step::{self, Resolution},
+    writer, StatsWriter as _, World as _, WriterExt as _,
+};
+use futures::FutureExt as _;
+use regex::RegexBuilder;
+
+#[given(regex = r"I have (\d+) cukes")]
+fn cukes(w: &mut World, count: usize) {
+    w.0 = count;
+}
+
+#[given(regex = r"^my name is (.+?)")]
+fn name(_: &mut World, _name: String) {}
+
+// language=Gherkin
+const FEATURE: &str = r"
+Feature: Partial matches
+  Scenario: partial matches
+    Given I have 5 cukes
+    And I have 5 cukes and 3 bananas
+    And my name is Bob
+";
+
+#[test]
+fn matches_whole_step_text_by_default() {
+    let feature = gherkin::Feature::parse(FEATURE, GherkinEnv::default())
+        .expect("failed to parse feature");
+    let steps = &feature.scenarios[0].steps;
+    let collection = World::collection();
+
+    assert!(matches!(
+        collection.resolve(&steps[0]),
+        Resolution::Regex(_, _, m) if m[1].1 == "5",
+    ));
+    assert!(matches!(
+        collection.resolve(&steps[1]),
+        Resolution::Undefined(p) if p.len() == 1,
+    ));
+    assert!(matches!(
+        collection.resolve(&steps[2]),
+        Resolution::Regex(_, _, m) if m[0].1 == "my name is Bob"
+            && m[1].1 == "Bob",
+    ));
+
+    let collection = collection.partial_matches(true);
+    assert!(matches!(
+        collection.resolve(&steps[1]),
+        Resolution::Regex(_, _, m) if m[0].1 == "I have 5 cukes",
+    ));
+}
+
+#[test]
+fn matches_whole_step_text_of_directly_registered_regexes() {
+    let feature = gherkin::Feature::parse(FEATURE, GherkinEnv::default())
+        .unwrap_or_else(|e| panic!("failed to parse feature: {e}"));
+    let step = &feature.scenarios[0].steps[2];
+    let collection = |re| {
+        step::Collection::<World>::new().given(
+            None,
+            RegexBuilder::new(re)
+                .build()
+                .unwrap_or_else(|e| panic!("invalid `Regex`: {e}")),
+            |_, _| async {}.boxed_local(),
+        )
+    };
+
+    assert!(matches!(
+        collection(r"^my name is (.+?)").resolve(step),
+        Resolution::Regex(_, _, m) if m[1].1 == "Bob",
+    ));
+    assert!(matches!(
+        collection(r"my name is (B|Bob)").resolve(step),
+        Resolution::Regex(_, _, m) if m[1].1 == "Bob",
+    ));
+    assert!(matches!(
+        collection(r"my name is (B|Bo)").resolve(step),
+        Resolution::Undefined(p) if p.len() == 1,
+    ));
+}
+
+#[tokio::test]
+async fn skips_partially_matched_steps_unless_allowed() {
+    let writer = World::cucumber()
+        .with_default_cli()
+        .run("tests/features/partial_matches")
+        .await;
+    assert_eq!(writer.skipped_steps(), 1);
+    assert_eq!(writer.passed_steps(), 0);
+
+    let writer = World::cucumber()
+        .partial_step_matches(true)
+        .with_default_cli()
+        .run("tests/features/partial_matches")
+        .await;
+    assert_eq!(writer.skipped_steps(), 0);
+    assert_eq!(writer.passed_steps(), 1);
+}
+
+#[tokio::test]
+async fn outputs_partial_matches_of_undefined_steps() {
+    for dry_run in [false, true] {
+        let cucumber = World::cucumber().with_writer(
+            writer::Basic::raw(
+                Vec::new(),
+                writer::Coloring::Never,
+                writer::Verbosity::Default,
+            )
+            .normalized(),
+        );
+        let cucumber = if dry_run {
+            cucumber.dry_run()
+        } else {
+            cucumber
+        };
+        let writer = cucumber
+            .with_default_cli()
+            .run("tests/features/partial_matches")
+            .await;
+
+        let output = String::from_utf8(writer.inner_writer().to_vec()).unwrap();
+        assert!(
+            output.contains(
+                "Step \"Given I have 5 cukes and 3 bananas\" is undefined, \
+                 as it only partially matches: `I have (\\d+) cukes` --> \
+                 tests/partial_matches.rs:",
+            ),
+            "unexpected output:\n{output}",
+        );
+    }
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World(usize);
@@ -0,0 +1,58 @@
+use std::io;
+
+use cucumber::{
+    given, pending, then, when, writer, StatsWriter as _, World as _,
+    WriterExt as _,
+};
+
+#[given("a ready step")]
+#[then("a ready step")]
+fn ready(_: &mut World) {}
+
+#[when("a pending step")]
+fn pending(_: &mut World) {
+    pending!();
+}
+
+#[when("an unimplemented step")]
+fn unimplemented(_: &mut World) {
+    todo!();
+}
+
+#[tokio::test]
+async fn reports_pending_steps_by_payload_type() {
+    let writer = World::cucumber()
+        .with_default_cli()
+        .run("tests/features/pending_steps")
+        .await;
+
+    assert_eq!(writer.passed_steps(), 1);
+    assert_eq!(writer.pending_steps(), 1);
+    assert_eq!(writer.skipped_steps(), 1);
+    assert_eq!(writer.failed_steps(), 1);
+}
+
+#[tokio::test]
+async fn fails_on_pending_steps_if_configured() {
+    let writer = World::cucumber()
+        .with_writer(
+            writer::Basic::raw(
+                io::sink(),
+                writer::Coloring::Never,
+                writer::Verbosity::Default,
+            )
+            .summarized()
+            .normalized()
+            .fail_on_skipped()
+            .fail_on_pending(),
+        )
+        .with_default_cli()
+        .run("tests/features/pending_steps")
+        .await;
+
+    assert_eq!(writer.pending_steps(), 0);
+    assert_eq!(writer.failed_steps(), 2);
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
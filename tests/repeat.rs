@@ -0,0 +1,62 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use clap::Parser as _;
+use cucumber::{
+    cli, given, parser, runner, writer, World as _, WriterExt as _,
+};
+
+static RUNS: AtomicUsize = AtomicUsize::new(0);
+
+#[given("a passing step")]
+fn passing(_: &mut World) {}
+
+#[given("a step failing every second run")]
+fn flaky(_: &mut World) {
+    assert!(RUNS.fetch_add(1, Ordering::SeqCst) % 2 == 0, "flaked");
+}
+
+#[tokio::test]
+async fn repeats_all_scenarios() {
+    let writer = World::cucumber()
+        .repeat(3)
+        .with_writer(
+            writer::Basic::raw(
+                Vec::new(),
+                writer::Coloring::Never,
+                writer::Verbosity::Default,
+            )
+            .summarized()
+            .normalized(),
+        )
+        .with_default_cli()
+        .run("tests/features/repeat")
+        .await;
+
+    let stats = writer.scenarios_stats();
+    assert_eq!(stats.passed, 8);
+    assert_eq!(stats.failed, 1);
+
+    let output = String::from_utf8(writer.inner_writer().to_vec()).unwrap();
+    assert_eq!(output.matches("Feature: Repeat").count(), 3);
+    assert!(
+        output.contains(
+            "Failed in repeated runs:\n  \
+             1) Scenario \"flaky\": failed 1 of 3 runs\n     \
+             at tests/features/repeat/repeat.feature:6",
+        ),
+        "unexpected output:\n{output}",
+    );
+}
+
+type Opts =
+    cli::Opts<parser::basic::Cli, runner::basic::Cli, writer::basic::Cli>;
+
+#[test]
+fn parses_cli_option() {
+    let opts = Opts::try_parse_from(["test", "--repeat", "5"])
+        .expect("failed to parse CLI");
+    assert_eq!(opts.runner.repeat, Some(5));
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
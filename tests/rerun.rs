@@ -0,0 +1,91 @@
+use std::fs;
+
+use cucumber::{given, StatsWriter as _, World as _};
+
+#[given(regex = "^a(?: passing)? step$")]
+fn step(_: &mut World) {}
+
+#[given("a failing step")]
+fn failing_step(_: &mut World) {
+    panic!("boom");
+}
+
+#[tokio::test]
+async fn reruns_only_failed_scenarios() {
+    let dir = tempfile::tempdir().unwrap();
+    let rerun = dir.path().join("rerun.txt");
+
+    let writer = World::cucumber()
+        .rerun_file(&rerun)
+        .with_default_cli()
+        .run("tests/features/rerun")
+        .await;
+
+    assert_eq!(writer.failed_steps(), 2);
+    assert_eq!(
+        fs::read_to_string(&rerun).unwrap(),
+        "tests/features/rerun/rerun.feature:6\n\
+         tests/features/rerun/rerun.feature:17\n",
+    );
+
+    let writer = World::cucumber()
+        .with_default_cli()
+        .run(format!("@{}", rerun.display()))
+        .await;
+
+    assert_eq!(writer.passed_steps(), 0);
+    assert_eq!(writer.failed_steps(), 2);
+    assert_eq!(writer.filtered_scenarios(), 2);
+}
+
+#[tokio::test]
+async fn writes_empty_rerun_file_without_failures() {
+    let dir = tempfile::tempdir().unwrap();
+    let input = dir.path().join("input.txt");
+    let rerun = dir.path().join("rerun.txt");
+    fs::write(&input, "tests/features/rerun/rerun.feature:3 \n").unwrap();
+    fs::write(&rerun, "tests/features/rerun/rerun.feature:6\n").unwrap();
+
+    let writer = World::cucumber()
+        .rerun_file(&rerun)
+        .with_default_cli()
+        .run(format!("@{}", input.display()))
+        .await;
+
+    assert_eq!(writer.passed_steps(), 1);
+    assert_eq!(fs::read_to_string(&rerun).unwrap(), "");
+}
+
+#[tokio::test]
+async fn reruns_paths_with_spaces() {
+    let dir = tempfile::tempdir().unwrap();
+    let features = dir.path().join("with spaces");
+    fs::create_dir(&features).unwrap();
+    let feature = features.join("rerun feature.feature");
+    fs::copy("tests/features/rerun/rerun.feature", &feature).unwrap();
+    let rerun = dir.path().join("rerun.txt");
+
+    let writer = World::cucumber()
+        .rerun_file(&rerun)
+        .with_default_cli()
+        .run(&features)
+        .await;
+
+    assert_eq!(writer.failed_steps(), 2);
+    assert_eq!(
+        fs::read_to_string(&rerun).unwrap(),
+        format!("{0}:6\n{0}:17\n", feature.display()),
+    );
+
+    let writer = World::cucumber()
+        .with_default_cli()
+        .run(format!("@{}", rerun.display()))
+        .await;
+
+    assert_eq!(writer.passed_steps(), 0);
+    assert_eq!(writer.failed_steps(), 2);
+    assert_eq!(writer.parsing_errors(), 0);
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
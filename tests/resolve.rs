@@ -0,0 +1,204 @@
+use cucumber::{
+    gherkin::{self, GherkinEnv},
+    given,
+    step::{self, Resolution},
+    then, when, World as _,
+};
+
+#[given("a literal step")]
+fn literal(_: &mut World) {}
+
+#[given(expr = "{int} cucumbers")]
+fn cucumbers(_: &mut World, _count: usize) {}
+
+#[when(regex = r"^ambiguous .+$")]
+fn ambiguous_any(_: &mut World) {}
+
+#[when(regex = r"^ambiguous (\w+)$")]
+fn ambiguous_word(_: &mut World, _word: String) {}
+
+#[then("a precise step")]
+fn precise(_: &mut World) {}
+
+#[then(regex = r"^a (\w+) step$")]
+fn any_step(_: &mut World, _kind: String) {}
+
+// language=Gherkin
+const FEATURE: &str = r"
+Feature: Resolution
+  Scenario: resolution
+    Given a literal step
+    And 5 cucumbers
+    When ambiguous step
+    Then undefined step
+    And a precise step
+";
+
+#[test]
+fn resolves_steps_without_running_them() {
+    let feature = gherkin::Feature::parse(FEATURE, GherkinEnv::default())
+        .expect("failed to parse feature");
+    let steps = World::collection();
+
+    let resolved = feature.scenarios[0]
+        .steps
+        .iter()
+        .map(|s| steps.resolve(s))
+        .collect::<Vec<_>>();
+
+    assert!(
+        matches!(&resolved[0], Resolution::Literal(_, Some(loc)) if loc.line == 8),
+        "{:?}",
+        resolved[0],
+    );
+    assert!(
+        matches!(
+            &resolved[1],
+            Resolution::Regex(_, Some(loc), m) if loc.line == 11 && m[1].1 == "5",
+        ),
+        "{:?}",
+        resolved[1],
+    );
+    assert!(
+        matches!(
+            &resolved[2],
+            Resolution::Ambiguous(e) if e.possible_matches.len() == 2,
+        ),
+        "{:?}",
+        resolved[2],
+    );
+    assert!(
+        matches!(resolved[3], Resolution::Undefined(_)),
+        "{:?}",
+        resolved[3]
+    );
+    assert!(
+        matches!(
+            &resolved[4],
+            Resolution::Literal(re, _) if re.as_str() == "^a precise step$",
+        ),
+        "{:?}",
+        resolved[4],
+    );
+}
+
+#[test]
+fn reports_ambiguous_matches_in_registration_order() {
+    let feature = gherkin::Feature::parse(FEATURE, GherkinEnv::default())
+        .expect("failed to parse feature");
+    let undefined = &feature.scenarios[0].steps[3];
+
+    let patterns = [r"^undefined (\w+)$", r"^(\w+) step$", r"^undefined .+$"];
+    let steps = patterns.iter().fold(step::Collection::new(), |steps, re| {
+        steps.then(None, regex::Regex::new(re).unwrap(), |_: &mut World, _| {
+            Box::pin(async {})
+        })
+    });
+
+    for _ in 0..3 {
+        let Resolution::Ambiguous(e) = steps.clone().resolve(undefined) else {
+            panic!("expected ambiguous resolution");
+        };
+        let matched = e
+            .possible_matches
+            .iter()
+            .map(|(re, _)| re.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(matched, patterns);
+    }
+}
+
+#[test]
+fn finds_steps_resolved_to() {
+    let feature = gherkin::Feature::parse(FEATURE, GherkinEnv::default())
+        .expect("failed to parse feature");
+    let steps = World::collection();
+
+    for step in &feature.scenarios[0].steps {
+        match (steps.resolve(step), steps.find(step)) {
+            (Resolution::Literal(_, loc), Ok(Some((_, _, l, ctx)))) => {
+                assert_eq!(loc, l);
+                assert_eq!(ctx.matches.len(), 1);
+            }
+            (Resolution::Regex(_, loc, m), Ok(Some((_, _, l, ctx)))) => {
+                assert_eq!(loc, l);
+                assert_eq!(m, ctx.matches);
+            }
+            (Resolution::Ambiguous(e), Err(err)) => {
+                assert_eq!(e.possible_matches, err.possible_matches);
+            }
+            (Resolution::Undefined(_), Ok(None)) => {}
+            (resolved, found) => panic!(
+                "`find()` doesn't agree with `resolve()`: {resolved:?} and {:?}",
+                found.map(|f| f.map(|(_, _, loc, _)| loc)),
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
+
+#[test]
+fn resolves_steps_added_after_resolution() {
+    let feature = gherkin::Feature::parse(FEATURE, GherkinEnv::default())
+        .expect("failed to parse feature");
+    let undefined = &feature.scenarios[0].steps[3];
+
+    let steps = World::collection();
+    assert!(matches!(steps.resolve(undefined), Resolution::Undefined(_)));
+
+    let steps = steps.then(
+        None,
+        regex::Regex::new("^undefined step$").unwrap(),
+        |_, _| Box::pin(async {}),
+    );
+    assert!(matches!(steps.resolve(undefined), Resolution::Literal(..)));
+
+    let steps = steps.append(step::Collection::new().then(
+        None,
+        regex::Regex::new(r"^(\w+) step$").unwrap(),
+        |_, _| Box::pin(async {}),
+    ));
+    assert!(matches!(
+        steps.resolve(undefined),
+        Resolution::Literal(re, _) if re.as_str() == "^undefined step$",
+    ));
+}
+
+#[test]
+fn resolves_steps_with_regex_builder_flags() {
+    // language=Gherkin
+    let feature = gherkin::Feature::parse(
+        "Feature: Flags\n  \
+           Scenario: flags\n    \
+             Then a loud step\n    \
+             And A LOUD STEP\n",
+        GherkinEnv::default(),
+    )
+    .unwrap_or_else(|e| panic!("failed to parse feature: {e}"));
+    let (lower, upper) = (
+        &feature.scenarios[0].steps[0],
+        &feature.scenarios[0].steps[1],
+    );
+
+    let steps = World::collection().then(
+        None,
+        regex::RegexBuilder::new("^a loud step$")
+            .case_insensitive(true)
+            .build()
+            .unwrap_or_else(|e| panic!("invalid regex: {e}")),
+        |_, _| Box::pin(async {}),
+    );
+
+    for step in [lower, upper] {
+        assert!(
+            matches!(
+                steps.resolve(step),
+                Resolution::Literal(ref re, _) if re.as_str() == "^a loud step$",
+            ),
+            "{:?}",
+            steps.resolve(step),
+        );
+    }
+}
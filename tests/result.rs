@@ -1,4 +1,9 @@
-use cucumber::{given, then, when, StatsWriter as _, World};
+use async_trait::async_trait;
+use cucumber::{
+    cli, event, given, parser,
+    result::{FeatureResult, ScenarioResult, Status},
+    then, when, Event, StatsWriter as _, World, Writer, WriterExt as _,
+};
 
 #[given("ok")]
 #[when("ok")]
@@ -14,8 +19,148 @@ fn error(_: &mut W) -> Result<(), &'static str> {
     Err("error")
 }
 
-#[derive(Clone, Copy, Debug, Default, World)]
-struct W;
+#[given(regex = r"\d+ steps?")]
+#[when(regex = r"\d+ steps?")]
+#[then(regex = r"\d+ steps?")]
+fn step(_: &mut W) {}
+
+#[then("fail")]
+fn fail(_: &mut W) {
+    panic!("failed");
+}
+
+#[derive(Default)]
+struct ResultsWriter {
+    scenarios: Vec<ScenarioResult>,
+    features: Vec<FeatureResult>,
+}
+
+#[async_trait(?Send)]
+impl<T: 'static> Writer<T> for ResultsWriter {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<T>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{Cucumber, Feature, Rule, Scenario};
+
+        match ev.as_deref() {
+            Ok(Cucumber::Feature(
+                _,
+                Feature::Scenario(_, sc)
+                | Feature::Rule(_, Rule::Scenario(_, sc)),
+            )) => {
+                if let Scenario::Finished(res) = &sc.event {
+                    self.scenarios.push(res.as_ref().clone());
+                }
+            }
+            Ok(Cucumber::Feature(_, Feature::Finished(res))) => {
+                self.features.push(res.as_ref().clone());
+            }
+            _ => {}
+        }
+    }
+}
+
+#[tokio::test]
+async fn aggregates_results() {
+    let writer = W::cucumber()
+        .with_writer(ResultsWriter::default().normalized())
+        .with_default_cli()
+        .run("tests/features/results")
+        .await;
+
+    let writer = writer.inner_writer();
+
+    assert_eq!(writer.features.len(), 1);
+    let feature = &writer.features[0];
+    assert_eq!(feature.name, "Results");
+    assert_eq!(feature.scenarios.len(), 4);
+    assert_eq!(feature.passed(), 2);
+    assert_eq!(feature.failed(), 1);
+    assert_eq!(feature.skipped(), 1);
+
+    let mut scenarios = writer.scenarios.clone();
+    scenarios.sort_by_key(|sc| sc.line);
+    let summary = scenarios
+        .iter()
+        .map(|sc| {
+            format!(
+                "{}{} ({}) [{}]: {}",
+                sc.rule
+                    .as_ref()
+                    .map(|r| format!("{r}: "))
+                    .unwrap_or_default(),
+                sc.name,
+                sc.status(),
+                sc.tags.join(", "),
+                sc.steps
+                    .iter()
+                    .map(|st| format!(
+                        "{}{} ({})",
+                        st.keyword, st.value, st.status
+                    ))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        summary,
+        [
+            "passed (passed) [feature]: \
+             Given 1 step (passed), When 2 steps (passed)",
+            "failed (failed) [feature]: \
+             Given 1 step (passed), When 2 steps (passed), Then fail (failed)",
+            "skipped (skipped) [feature, scenario]: \
+             Given 1 step (passed), When unknown (skipped)",
+            "rule: passed in rule (passed) [feature, rule]: \
+             Given 1 step (passed), Then 2 steps (passed)",
+        ],
+    );
+    assert!(scenarios[1].steps[2]
+        .error
+        .as_deref()
+        .is_some_and(|e| e.contains("failed")));
+
+    assert_eq!(scenarios[0].keyword, "Scenario");
+    assert_eq!((scenarios[0].line, scenarios[0].col), (7, 3));
+    let step = &scenarios[0].steps[1];
+    assert_eq!((step.line, step.col), (8, 5));
+    assert_eq!(step.docstring.as_deref(), Some("\ndocstring\n"));
+    assert!(scenarios[0].steps[0].is_background);
+    let table = scenarios[3].steps[1].table.as_ref();
+    assert_eq!(
+        table.map(|t| t.rows.clone()),
+        Some(vec![vec!["cell".into()]])
+    );
+}
+
+#[tokio::test]
+async fn fails_undefined_steps_in_results_on_skipped() {
+    let writer = W::cucumber()
+        .with_writer(ResultsWriter::default().normalized())
+        .fail_on_skipped_with(|_, _, sc| sc.name == "skipped")
+        .with_default_cli()
+        .run("tests/features/results")
+        .await;
+
+    let writer = writer.inner_writer();
+
+    let skipped = writer
+        .scenarios
+        .iter()
+        .find(|sc| sc.name == "skipped")
+        .unwrap();
+    assert_eq!(skipped.status(), Status::Failed);
+    assert_eq!(skipped.steps[1].status, Status::Failed);
+
+    assert_eq!(writer.features.len(), 1);
+    assert_eq!(writer.features[0].failed(), 2);
+    assert_eq!(writer.features[0].skipped(), 0);
+}
 
 #[tokio::test]
 async fn fails() {
@@ -31,3 +176,6 @@ async fn fails() {
     assert_eq!(writer.parsing_errors(), 0);
     assert_eq!(writer.hook_errors(), 0);
 }
+
+#[derive(Clone, Copy, Debug, Default, World)]
+struct W;
@@ -55,6 +55,13 @@ async fn correctly() {
             "Wrong `Stats` for `Step`s on `{retries:?}` retries and \
              `{retry_filter:?}` tags",
         );
+        // Each `Scenario` fails initially, so any passed one is flaky.
+        assert_eq!(
+            writer.flaky_scenarios(),
+            p_sc,
+            "Wrong number of flaky `Scenario`s on `{retries:?}` retries and \
+             `{retry_filter:?}` tags",
+        );
 
         SCENARIO_RUNS.lock().await.clear();
     }
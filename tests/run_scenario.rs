@@ -0,0 +1,90 @@
+use cucumber::{
+    gherkin, given,
+    result::{ScenarioResult, Status},
+    then, writer, World as _, WriterExt as _,
+};
+
+#[given("a step")]
+fn step(_: &mut World) {}
+
+#[then(expr = "it {word}")]
+fn check(_: &mut World, outcome: String) {
+    assert_eq!(outcome, "passes", "failed");
+}
+
+fn feature() -> gherkin::Feature {
+    gherkin::Feature::parse(
+        "Feature: Generated\n\n  \
+         Scenario: single\n    \
+         Given a step\n    \
+         Then it passes\n\n  \
+         Rule: rule\n\n    \
+         Scenario Outline: outlined\n      \
+         Given a step\n      \
+         Then it <outcome>\n\n      \
+         Examples:\n        \
+         | outcome |\n        \
+         | passes  |\n        \
+         | fails   |\n",
+        gherkin::GherkinEnv::default(),
+    )
+    .unwrap()
+}
+
+async fn run(
+    feature: &gherkin::Feature,
+    rule: Option<&gherkin::Rule>,
+    scenario: &gherkin::Scenario,
+) -> Vec<ScenarioResult> {
+    // No input is parsed, so its type is arbitrary.
+    World::cucumber::<&str>()
+        .with_writer(
+            writer::Basic::raw(
+                Vec::new(),
+                writer::Coloring::Never,
+                writer::Verbosity::Default,
+            )
+            .normalized(),
+        )
+        .with_default_cli()
+        .run_scenario(feature, rule, scenario)
+        .await
+}
+
+#[tokio::test]
+async fn returns_step_results() {
+    let feature = feature();
+    let results = run(&feature, None, &feature.scenarios[0]).await;
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].name, "single");
+    assert_eq!(results[0].status(), Status::Passed);
+    assert_eq!(
+        results[0]
+            .steps
+            .iter()
+            .map(|s| (s.value.as_str(), s.status))
+            .collect::<Vec<_>>(),
+        [("a step", Status::Passed), ("it passes", Status::Passed)],
+    );
+}
+
+#[tokio::test]
+async fn expands_examples_of_rule_scenario() {
+    let feature = feature();
+    let rule = &feature.rules[0];
+    let results = run(&feature, Some(rule), &rule.scenarios[0]).await;
+
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|r| r.rule.as_deref() == Some("rule")));
+
+    let mut statuses = results
+        .iter()
+        .map(ScenarioResult::status)
+        .collect::<Vec<_>>();
+    statuses.sort_by_key(|s| *s == Status::Failed);
+    assert_eq!(statuses, [Status::Passed, Status::Failed]);
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
@@ -0,0 +1,43 @@
+use cucumber::{given, step, then, StatsWriter as _, World as _};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct OrderId(u64);
+
+#[given(expr = "an order {int} is placed")]
+fn place_order(_: &mut World, #[step] ctx: &step::Context, id: u64) {
+    assert_eq!(ctx.insert(OrderId(id)), None);
+}
+
+#[then(expr = "the order {int} is stored")]
+fn order_stored(
+    _: &mut World,
+    #[step] ctx: &step::Context,
+    id: u64,
+) -> Result<(), step::MissingScratchValueError> {
+    assert_eq!(ctx.get::<OrderId>()?, OrderId(id));
+    Ok(())
+}
+
+#[then("no order is stored")]
+fn no_order(_: &mut World, #[step] ctx: &step::Context) {
+    let err = ctx.get::<OrderId>().unwrap_err();
+    assert!(
+        err.to_string().contains("scratch_context::OrderId"),
+        "{err}"
+    );
+    assert!(ctx.remove::<OrderId>().is_err());
+}
+
+#[tokio::test]
+async fn shares_values_within_scenario_only() {
+    let writer = World::cucumber()
+        .with_default_cli()
+        .run("tests/features/scratch_context")
+        .await;
+
+    assert_eq!(writer.passed_steps(), 3);
+    assert_eq!(writer.failed_steps(), 1);
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
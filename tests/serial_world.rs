@@ -0,0 +1,81 @@
+use cucumber::{given, then, when, writer, World as _, WriterExt as _};
+
+#[given("the world is fresh")]
+fn fresh(w: &mut World) {
+    assert_eq!(w.counter, 0, "world is reused");
+}
+
+#[when("it's incremented")]
+fn increment(w: &mut World) {
+    w.counter += 1;
+}
+
+#[then("it's incremented once")]
+fn incremented(w: &mut World) {
+    assert_eq!(w.counter, 1, "world is not shared");
+}
+
+#[when("it panics")]
+fn panics(_: &mut World) {
+    panic!("corrupted");
+}
+
+#[tokio::test]
+async fn shares_world_between_tagged_feature_scenarios() {
+    let writer = World::cucumber()
+        .with_writer(
+            writer::Basic::raw(
+                Vec::new(),
+                writer::Coloring::Never,
+                writer::Verbosity::Default,
+            )
+            .summarized()
+            .normalized(),
+        )
+        .with_default_cli()
+        .run("tests/features/serial_world")
+        .await;
+
+    let stats = writer.scenarios_stats();
+    assert_eq!(stats.passed, 4);
+    assert_eq!(stats.failed, 2);
+
+    let output = String::from_utf8(writer.inner_writer().to_vec()).unwrap();
+    assert!(
+        output.contains("World is poisoned by a previously failed Scenario"),
+        "unexpected output:\n{output}",
+    );
+}
+
+#[tokio::test]
+async fn shares_world_between_all_feature_scenarios() {
+    let writer = World::cucumber()
+        .world_per_feature()
+        .with_writer(
+            writer::Basic::raw(
+                Vec::new(),
+                writer::Coloring::Never,
+                writer::Verbosity::Default,
+            )
+            .summarized()
+            .normalized(),
+        )
+        .with_default_cli()
+        .run("tests/features/serial_world/fresh.feature")
+        .await;
+
+    let stats = writer.scenarios_stats();
+    assert_eq!(stats.passed, 1);
+    assert_eq!(stats.failed, 1);
+
+    let output = String::from_utf8(writer.inner_writer().to_vec()).unwrap();
+    assert!(
+        output.contains("world is reused"),
+        "unexpected output:\n{output}"
+    );
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World {
+    counter: usize,
+}
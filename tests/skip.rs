@@ -0,0 +1,46 @@
+use cucumber::{
+    given, skip, then, when, writer, StatsWriter as _, World as _,
+    WriterExt as _,
+};
+
+#[given("a ready step")]
+#[then("a ready step")]
+fn ready(_: &mut World) {}
+
+#[when(regex = r"^a step requiring (\S+)$")]
+fn requiring(_: &mut World, var: String) {
+    skip!("requires {var}");
+}
+
+#[tokio::test]
+async fn skips_scenario_with_reason() {
+    let writer = World::cucumber()
+        .with_writer(
+            writer::Basic::raw(
+                Vec::new(),
+                writer::Coloring::Never,
+                writer::Verbosity::Default,
+            )
+            .summarized()
+            .normalized(),
+        )
+        .with_default_cli()
+        .run("tests/features/skip")
+        .await;
+
+    assert_eq!(writer.passed_steps(), 2);
+    assert_eq!(writer.skipped_steps(), 1);
+    assert_eq!(writer.failed_steps(), 0);
+    assert_eq!(writer.scenarios_stats().passed, 1);
+    assert_eq!(writer.scenarios_stats().skipped, 1);
+    assert!(!writer.execution_has_failed());
+
+    let output =
+        String::from_utf8(writer.inner_writer().inner_writer().to_vec())
+            .unwrap();
+    assert!(output.contains("Reason: requires DOCKER_HOST"), "{output}");
+    assert!(!output.contains("undefined"), "{output}");
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
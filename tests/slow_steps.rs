@@ -0,0 +1,45 @@
+use std::time::Duration;
+
+use cucumber::{given, then, writer, World as _, WriterExt as _};
+use regex::Regex;
+
+#[given("a slow step")]
+async fn slow(_: &mut World) {
+    tokio::time::sleep(Duration::from_millis(100)).await;
+}
+
+#[then("a fast step")]
+fn fast(_: &mut World) {}
+
+#[tokio::test]
+async fn outputs_durations_of_slow_steps_only() {
+    let mut basic = writer::Basic::raw(
+        Vec::new(),
+        writer::Coloring::Never,
+        writer::Verbosity::Default,
+    );
+    basic.apply_cli(writer::basic::Cli {
+        slow_steps: Some(Duration::from_millis(50)),
+        ..writer::basic::Cli::default()
+    });
+
+    let writer = World::cucumber()
+        .with_writer(basic.normalized())
+        .with_default_cli()
+        .run("tests/features/slow_steps")
+        .await;
+    let output = String::from_utf8(writer.inner_writer().to_vec()).unwrap();
+
+    let slow = Regex::new(r"✔  Given a slow step \(\d+\.\d+ms\)\n").unwrap();
+    assert!(slow.is_match(&output), "{output}");
+    assert!(output.contains("✔  Then a fast step\n"), "{output}");
+    assert!(
+        Regex::new(r"Run took \d+\.\d+m?s")
+            .unwrap()
+            .is_match(&output),
+        "{output}",
+    );
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
@@ -0,0 +1,46 @@
+use cucumber::{given, writer, World as _, WriterExt as _};
+
+#[given("a defined step")]
+fn defined(_: &mut World) {}
+
+#[tokio::test]
+async fn outputs_snippets_for_undefined_steps() {
+    let writer = World::cucumber()
+        .with_writer(
+            writer::Basic::raw(
+                Vec::new(),
+                writer::Coloring::Never,
+                writer::Verbosity::Default,
+            )
+            .normalized()
+            .summarized(),
+        )
+        .with_default_cli()
+        .run("tests/features/snippets")
+        .await;
+    let output = String::from_utf8(writer.inner_writer().to_vec()).unwrap();
+    let (_, snippets) = output
+        .split_once("undefined steps with these snippets:\n\n")
+        .unwrap_or_else(|| panic!("no snippets: {output}"));
+
+    assert_eq!(
+        snippets.trim_end(),
+        r##"#[given(regex = r#"^the user "([^"]*)" has (-?\d+) items$"#)]
+async fn the_user_has_items(world: &mut World, arg0: String, arg1: i64) {
+    todo!()
+}
+
+#[when(regex = r#"^the user clicks login$"#)]
+async fn the_user_clicks_login(world: &mut World) {
+    todo!()
+}
+
+#[then(regex = r#"^the total is (-?\d+\.\d+)$"#)]
+async fn the_total_is(world: &mut World, arg0: f64) {
+    todo!()
+}"##,
+    );
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
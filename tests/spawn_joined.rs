@@ -0,0 +1,61 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc, Arc,
+    },
+    time::Duration,
+};
+
+use cucumber::{
+    step, then, when, writer, StatsWriter as _, World as _, WriterExt as _,
+};
+
+#[when("a joined thread increments the counter")]
+fn increments(world: &mut World, #[step] ctx: &step::Context) {
+    let counter = Arc::clone(&world.0);
+    ctx.spawn_joined(move || {
+        _ = counter.fetch_add(1, Ordering::SeqCst);
+    });
+}
+
+#[when("a joined thread waits for a task on the executor")]
+fn waits(world: &mut World, #[step] ctx: &step::Context) {
+    let counter = Arc::clone(&world.0);
+    let (tx, rx) = mpsc::channel();
+    ctx.spawn_joined(move || {
+        rx.recv_timeout(Duration::from_secs(5))
+            .unwrap_or_else(|e| panic!("executor is blocked: {e}"));
+        _ = counter.fetch_add(1, Ordering::SeqCst);
+    });
+    // Runs on the same single-threaded executor, so only if it isn't blocked
+    // by joining the spawned thread.
+    drop(tokio::spawn(async move {
+        tx.send(())
+            .unwrap_or_else(|e| panic!("failed to send: {e}"));
+    }));
+}
+
+#[when("a joined thread panics")]
+fn panics(_: &mut World, #[step] ctx: &step::Context) {
+    ctx.spawn_joined(|| panic!("spawned thread panicked"));
+}
+
+#[then("the counter is incremented")]
+fn is_incremented(world: &mut World) {
+    assert_eq!(world.0.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn fails_step_on_joined_thread_panic() {
+    let writer = World::cucumber()
+        .with_writer(writer::Basic::stdout().summarized())
+        .with_default_cli()
+        .run("tests/features/spawn_joined")
+        .await;
+
+    assert_eq!(writer.passed_steps(), 4);
+    assert_eq!(writer.failed_steps(), 1);
+}
+
+#[derive(Clone, Debug, Default, cucumber::World)]
+struct World(Arc<AtomicUsize>);
@@ -0,0 +1,55 @@
+use cucumber::{given, step, then, when, StatsWriter as _, World as _};
+use futures::FutureExt as _;
+use regex::Regex;
+
+#[given("a user")]
+fn user(_: &mut World) {}
+
+#[when("I sign in", "I log in", regex = r"^I (sign in|authenticate)$")]
+fn sign_in(world: &mut World) {
+    world.sign_ins += 1;
+}
+
+#[then(expr = "the user signed in {int} times")]
+fn signed_in(world: &mut World, times: usize) {
+    assert_eq!(world.sign_ins, times);
+}
+
+#[then(expr = "the user signed out {int} times")]
+fn signed_out(world: &mut World, times: usize) {
+    assert_eq!(world.sign_outs, times);
+}
+
+#[tokio::test]
+async fn aliases_share_definition() {
+    let loc = step::Location {
+        path: file!(),
+        line: line!(),
+        column: column!(),
+    };
+    let writer = World::cucumber()
+        .steps(World::collection().when_aliases(
+            Some(loc),
+            [
+                Regex::new("^I log out$").unwrap(),
+                Regex::new("^I sign out$").unwrap(),
+            ],
+            |w, _| {
+                async move {
+                    w.sign_outs += 1;
+                }
+                .boxed_local()
+            },
+        ))
+        .run("tests/features/step_aliases")
+        .await;
+
+    assert_eq!(writer.passed_steps(), 11);
+    assert!(!writer.execution_has_failed());
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World {
+    sign_ins: usize,
+    sign_outs: usize,
+}
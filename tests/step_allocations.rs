@@ -0,0 +1,86 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    future,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use cucumber::{
+    gherkin::{self, GherkinEnv},
+    step,
+};
+use futures::{future::LocalBoxFuture, FutureExt as _};
+use regex::Regex;
+
+/// [`GlobalAlloc`] counting allocations made by the current thread while
+/// [`COUNTING`] is enabled.
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    static COUNTING: Cell<bool> = const { Cell::new(false) };
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if COUNTING.with(Cell::get) {
+            _ = ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        }
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+/// Counts allocations made by the given function.
+fn count_allocations<T>(f: impl FnOnce() -> T) -> usize {
+    ALLOCATIONS.store(0, Ordering::Relaxed);
+    COUNTING.with(|c| c.set(true));
+    drop(f());
+    COUNTING.with(|c| c.set(false));
+    ALLOCATIONS.load(Ordering::Relaxed)
+}
+
+fn noop(_: &mut (), _: step::Context) -> LocalBoxFuture<'_, ()> {
+    future::ready(()).boxed_local()
+}
+
+// language=Gherkin
+const FEATURE: &str = r"
+Feature: Allocations
+  Scenario: allocations
+    Given an undefined step
+    Given step number 500 with 42
+";
+
+#[test]
+fn step_matching_allocations_do_not_depend_on_definitions_count() {
+    const DEFINITIONS: usize = 1000;
+
+    let collection = (0..DEFINITIONS).fold(step::Collection::new(), |c, i| {
+        let re = Regex::new(&format!(r"^step number {i} with (\d+)$"));
+        c.given(None, re.unwrap(), noop)
+    });
+    let feature = gherkin::Feature::parse(FEATURE, GherkinEnv::default())
+        .expect("failed to parse feature");
+    let (undefined, defined) = (
+        &feature.scenarios[0].steps[0],
+        &feature.scenarios[0].steps[1],
+    );
+
+    // Warm up any lazily initialized statics and `Regex` caches.
+    drop(collection.find(undefined));
+    drop(collection.find(defined));
+
+    let allocs = count_allocations(|| collection.find(undefined));
+    assert!(allocs < 10, "{allocs} allocations for an undefined step");
+
+    let allocs = count_allocations(|| collection.find(defined));
+    assert!(allocs < 20, "{allocs} allocations for a defined step");
+}
@@ -0,0 +1,37 @@
+use cucumber::{given, step, then, StatsWriter as _, World as _};
+
+#[given(expr = "feature is {string}")]
+fn feature_is(_: &mut World, #[step] ctx: &step::Context, name: String) {
+    assert_eq!(ctx.feature().map(|f| f.name.as_str()), Some(name.as_str()));
+}
+
+#[then(expr = "scenario is {string}")]
+fn scenario_is(_: &mut World, #[step] ctx: &step::Context, name: String) {
+    assert_eq!(ctx.scenario().map(|s| s.name.as_str()), Some(name.as_str()));
+    if ctx.has_tag("rule") {
+        assert_eq!(ctx.rule().map(|r| r.name.as_str()), Some("tagged rule"));
+    } else {
+        assert!(ctx.rule().is_none());
+    }
+}
+
+#[then(expr = "tags are {string}")]
+fn tags_are(_: &mut World, #[step] ctx: &step::Context, expected: String) {
+    assert_eq!(ctx.tags().collect::<Vec<_>>().join(","), expected);
+    assert!(ctx.has_tag("feature"));
+    assert!(!ctx.has_tag("@feature"));
+}
+
+#[tokio::test]
+async fn exposes_feature_rule_and_scenario() {
+    let writer = World::cucumber()
+        .with_default_cli()
+        .run("tests/features/step_context")
+        .await;
+
+    assert_eq!(writer.passed_steps(), 6);
+    assert_eq!(writer.failed_steps(), 0);
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
@@ -0,0 +1,91 @@
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use cucumber::{
+    cli, event, given, parser, step, then, Event, World as _, Writer,
+    WriterExt as _,
+};
+use tokio::time;
+
+static LOG_RECEIVED: AtomicBool = AtomicBool::new(false);
+
+#[given("a long-running step")]
+async fn long_running(_: &mut World, #[step] ctx: &step::Context) {
+    ctx.log("started");
+
+    // The step only finishes once its log is seen by the `Writer`, so this
+    // passes only if logs are forwarded while the step is still running.
+    for _ in 0..500 {
+        if LOG_RECEIVED.load(Ordering::SeqCst) {
+            let thread_ctx = ctx.clone();
+            ctx.spawn_joined(move || thread_ctx.log("from thread"));
+            return;
+        }
+        time::sleep(Duration::from_millis(10)).await;
+    }
+    panic!("log hasn't been received while the step is running");
+}
+
+#[then("a step")]
+fn step(_: &mut World) {}
+
+#[derive(Default)]
+struct LogsWriter(Vec<String>);
+
+#[async_trait(?Send)]
+impl<W: 'static> Writer<W> for LogsWriter {
+    type Cli = cli::Empty;
+
+    async fn handle_event(
+        &mut self,
+        ev: parser::Result<Event<event::Cucumber<W>>>,
+        _: &Self::Cli,
+    ) {
+        use event::{Cucumber, Feature, Scenario, Step};
+
+        if let Ok(Cucumber::Feature(_, Feature::Scenario(_, ev))) =
+            ev.map(Event::into_inner)
+        {
+            match ev.event {
+                Scenario::Log(msg) => {
+                    LOG_RECEIVED.store(true, Ordering::SeqCst);
+                    self.0.push(format!("log: {}", msg.trim_end()));
+                }
+                Scenario::Step(st, Step::Started) => {
+                    self.0.push(format!("started: {}", st.value));
+                }
+                Scenario::Step(st, Step::Passed(..)) => {
+                    self.0.push(format!("passed: {}", st.value));
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+#[tokio::test]
+async fn forwards_logs_while_step_is_running() {
+    let writer = World::cucumber()
+        .with_writer(LogsWriter::default().normalized())
+        .with_default_cli()
+        .run("tests/features/step_log")
+        .await;
+
+    assert_eq!(
+        writer.inner_writer().0,
+        [
+            "started: a long-running step",
+            "log: started",
+            "log: from thread",
+            "passed: a long-running step",
+            "started: a step",
+            "passed: a step",
+        ],
+    );
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
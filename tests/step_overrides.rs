@@ -0,0 +1,154 @@
+use cucumber::{
+    gherkin::{self, GherkinEnv},
+    given,
+    step::{self, Resolution},
+    writer, World as _, WriterExt as _,
+};
+use futures::{future::LocalBoxFuture, FutureExt as _};
+use regex::Regex;
+
+#[given("a user exists")]
+fn user_exists(_: &mut World) {}
+
+// language=Gherkin
+const FEATURE: &str = r"
+Feature: Overrides
+  Scenario: overrides
+    Given a user exists
+";
+
+const LOCATION: step::Location = step::Location {
+    path: "steps/users.rs",
+    line: 42,
+    column: 1,
+};
+
+fn noop(_: &mut World, _: step::Context) -> LocalBoxFuture<'_, ()> {
+    async {}.boxed_local()
+}
+
+fn library() -> step::Collection<World> {
+    step::Collection::new().given(
+        Some(LOCATION),
+        Regex::new("^a user exists$").unwrap(),
+        noop,
+    )
+}
+
+fn resolve(steps: &step::Collection<World>) -> Resolution {
+    let feature = gherkin::Feature::parse(FEATURE, GherkinEnv::default())
+        .expect("failed to parse feature");
+    steps.resolve(&feature.scenarios[0].steps[0])
+}
+
+#[test]
+fn keeps_duplicates_by_default() {
+    let steps = World::collection().append(library());
+
+    assert!(
+        matches!(
+            resolve(&steps),
+            Resolution::Ambiguous(e) if e.possible_matches.len() == 2,
+        ),
+        "{:?}",
+        resolve(&steps),
+    );
+}
+
+#[test]
+fn overrides_duplicates_when_allowed() {
+    let steps = World::collection().allow_overrides(true).append(library());
+
+    assert!(
+        matches!(
+            resolve(&steps),
+            Resolution::Literal(_, Some(loc)) if loc == LOCATION,
+        ),
+        "{:?}",
+        resolve(&steps),
+    );
+}
+
+#[test]
+fn reinserting_same_definition_is_not_duplicate() {
+    let steps = library().append(library());
+
+    assert!(
+        matches!(resolve(&steps), Resolution::Literal(_, Some(_))),
+        "{:?}",
+        resolve(&steps),
+    );
+}
+
+#[test]
+fn collects_duplicates() {
+    let steps = World::collection().append(library());
+
+    let dups = steps.duplicates();
+    assert_eq!(dups.len(), 1, "{dups:?}");
+    assert_eq!(dups[0].ty, Some(gherkin::StepType::Given));
+    assert_eq!(dups[0].regex.as_str(), "^a user exists$");
+    assert_eq!(dups[0].locations.len(), 2, "{dups:?}");
+    assert_eq!(dups[0].locations[1], Some(LOCATION));
+    assert!(World::collection()
+        .allow_overrides(true)
+        .append(library())
+        .duplicates()
+        .is_empty(),);
+}
+
+#[tokio::test]
+async fn warns_about_duplicates_in_output() {
+    let writer = World::cucumber()
+        .steps(World::collection().append(library()))
+        .with_writer(
+            writer::Basic::raw(
+                Vec::new(),
+                writer::Coloring::Never,
+                writer::Verbosity::Default,
+            )
+            .summarized()
+            .normalized(),
+        )
+        .with_default_cli()
+        .run("tests/features/step_overrides")
+        .await;
+
+    let output = String::from_utf8(writer.inner_writer().to_vec()).unwrap();
+    assert!(
+        output.contains(
+            "Warning: Given step `^a user exists$` is defined more than once, \
+             use `step::Collection::allow_overrides()` if this is intended:\n  \
+             --> tests/step_overrides.rs:10:1\n  \
+             --> steps/users.rs:42:1\n",
+        ),
+        "unexpected output:\n{output}",
+    );
+}
+
+#[tokio::test]
+async fn does_not_warn_when_overrides_allowed() {
+    let writer = World::cucumber()
+        .steps(World::collection().allow_overrides(true).append(library()))
+        .with_writer(
+            writer::Basic::raw(
+                Vec::new(),
+                writer::Coloring::Never,
+                writer::Verbosity::Default,
+            )
+            .summarized()
+            .normalized(),
+        )
+        .with_default_cli()
+        .run("tests/features/step_overrides")
+        .await;
+
+    let output = String::from_utf8(writer.inner_writer().to_vec()).unwrap();
+    assert!(
+        !output.contains("defined more than once"),
+        "unexpected output:\n{output}",
+    );
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
@@ -0,0 +1,117 @@
+use cucumber::{step, StatsWriter as _, World as _};
+use futures::{future::LocalBoxFuture, FutureExt as _};
+use regex::Regex;
+
+/// Layout of a separate crate publishing reusable `Step`s.
+mod http_steps {
+    use cucumber::step;
+    use futures::{future::LocalBoxFuture, FutureExt as _};
+    use regex::Regex;
+
+    /// Capability the `World` of a downstream suite has to implement.
+    pub trait HasHttpClient {
+        fn http_client(&mut self) -> &mut Client;
+    }
+
+    #[derive(Debug, Default)]
+    pub struct Client {
+        pub base_url: String,
+        pub requests: Vec<String>,
+    }
+
+    /// Provider of the `Step`s.
+    pub struct Steps;
+
+    impl<W: HasHttpClient + 'static> step::Provider<W> for Steps {
+        fn register(&self, steps: step::Collection<W>) -> step::Collection<W> {
+            steps
+                .given(
+                    None,
+                    Regex::new(r#"^a client for "(\S+)"$"#).unwrap(),
+                    base_url::<W>,
+                )
+                .when(
+                    None,
+                    Regex::new(r#"^the client requests "(\S+)"$"#).unwrap(),
+                    request::<W>,
+                )
+        }
+    }
+
+    fn base_url<W: HasHttpClient>(
+        world: &mut W,
+        ctx: step::Context,
+    ) -> LocalBoxFuture<'_, ()> {
+        async move {
+            world.http_client().base_url = ctx.matches[1].1.clone();
+        }
+        .boxed_local()
+    }
+
+    fn request<W: HasHttpClient>(
+        world: &mut W,
+        ctx: step::Context,
+    ) -> LocalBoxFuture<'_, ()> {
+        async move {
+            let client = world.http_client();
+            let url = format!("{}{}", client.base_url, ctx.matches[1].1);
+            client.requests.push(url);
+        }
+        .boxed_local()
+    }
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World {
+    client: http_steps::Client,
+}
+
+impl http_steps::HasHttpClient for World {
+    fn http_client(&mut self) -> &mut http_steps::Client {
+        &mut self.client
+    }
+}
+
+fn local_steps() -> step::Collection<World> {
+    fn single_request(
+        world: &mut World,
+        _: step::Context,
+    ) -> LocalBoxFuture<'_, ()> {
+        async move { assert_eq!(world.client.requests.len(), 1) }.boxed_local()
+    }
+
+    fn last_request(
+        world: &mut World,
+        ctx: step::Context,
+    ) -> LocalBoxFuture<'_, ()> {
+        async move {
+            assert_eq!(world.client.requests.last(), Some(&ctx.matches[1].1));
+        }
+        .boxed_local()
+    }
+
+    step::Collection::new()
+        .then(
+            None,
+            Regex::new(r"^a single request is made$").unwrap(),
+            single_request,
+        )
+        .then(
+            None,
+            Regex::new(r#"^the last request is "(\S+)"$"#).unwrap(),
+            last_request,
+        )
+}
+
+#[tokio::test]
+async fn registers_provided_and_local_steps() {
+    let writer = World::cucumber()
+        .provide(&http_steps::Steps)
+        .provide(&local_steps())
+        .with_default_cli()
+        .run("tests/features/step_provider")
+        .await;
+
+    assert_eq!(writer.passed_steps(), 4);
+    assert!(!writer.execution_has_failed());
+}
@@ -0,0 +1,75 @@
+use std::{future, thread, time::Duration};
+
+use cucumber::{
+    given, then, writer, StatsWriter as _, World as _, WriterExt as _,
+};
+
+#[given("a step")]
+#[then("a step")]
+fn step(_: &mut World) {}
+
+#[given("a hanging step")]
+async fn hanging_step(_: &mut World) {
+    future::pending::<()>().await;
+}
+
+#[given("a blocking step")]
+fn blocking_step(_: &mut World) {
+    thread::sleep(Duration::from_millis(300));
+}
+
+#[tokio::test]
+async fn fails_hanging_steps() {
+    let writer = World::cucumber()
+        .step_timeout(Duration::from_millis(100))
+        .with_default_cli()
+        .run("tests/features/step_timeout")
+        .await;
+
+    assert_eq!(writer.passed_steps(), 1);
+    assert_eq!(writer.failed_steps(), 1);
+}
+
+#[tokio::test]
+async fn fails_blocking_steps_once_they_return() {
+    let writer = World::cucumber()
+        .step_timeout(Duration::from_millis(100))
+        .with_default_cli()
+        .run("tests/features/step_timeout_blocking")
+        .await;
+
+    assert_eq!(writer.passed_steps(), 0);
+    assert_eq!(writer.failed_steps(), 1);
+    assert_eq!(writer.skipped_steps(), 0);
+}
+
+#[tokio::test]
+async fn outputs_timed_out_steps_distinctly() {
+    let writer = World::cucumber()
+        .step_timeout(Duration::from_millis(100))
+        .with_writer(
+            writer::Basic::raw(
+                Vec::new(),
+                writer::Coloring::Never,
+                writer::Verbosity::Default,
+            )
+            .summarized()
+            .normalized(),
+        )
+        .with_default_cli()
+        .run("tests/features/step_timeout")
+        .await;
+
+    let output = String::from_utf8(writer.inner_writer().to_vec()).unwrap();
+    assert!(
+        output.contains("   ⏱  Given a hanging step\n      Step failed:",),
+        "unexpected output:\n{output}",
+    );
+    assert!(
+        output.contains("Step timed out after 100ms"),
+        "unexpected output:\n{output}",
+    );
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
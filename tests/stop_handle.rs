@@ -0,0 +1,72 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use cucumber::{
+    given, runner, then, when, writer, writer::Outcome, StatsWriter as _,
+    World as _, WriterExt as _,
+};
+use futures::FutureExt as _;
+use once_cell::sync::Lazy;
+
+static STOP: Lazy<runner::StopHandle> = Lazy::new(runner::StopHandle::default);
+
+static AFTER_HOOKS: AtomicUsize = AtomicUsize::new(0);
+
+#[given("a step")]
+#[then("a step")]
+fn step(_: &mut World) {}
+
+#[when("a stop is requested")]
+fn request_stop(_: &mut World) {
+    assert_eq!(STOP.request(), 0, "stop is requested twice");
+}
+
+#[tokio::test]
+async fn finishes_started_scenarios_only() {
+    let writer = World::cucumber()
+        .max_concurrent_scenarios(1)
+        .stop_handle(STOP.clone())
+        .after(|_, _, _, _, _| {
+            async {
+                _ = AFTER_HOOKS.fetch_add(1, Ordering::SeqCst);
+            }
+            .boxed_local()
+        })
+        .with_writer(
+            writer::Basic::raw(
+                Vec::new(),
+                writer::Coloring::Never,
+                writer::Verbosity::Default,
+            )
+            .summarized()
+            .normalized()
+            .fail_on_skipped(),
+        )
+        .with_default_cli()
+        .run("tests/features/stop_handle")
+        .await;
+
+    assert!(STOP.is_requested());
+    assert_eq!(writer.passed_steps(), 2);
+    assert_eq!(writer.skipped_steps(), 1);
+    assert_eq!(
+        writer.failed_steps(),
+        0,
+        "stopped step is failed as skipped"
+    );
+    assert_eq!(AFTER_HOOKS.load(Ordering::SeqCst), 1);
+    assert!(writer.execution_aborted());
+    assert_eq!(writer.outcome(), Outcome::Aborted);
+    assert_eq!(writer.outcome().exit_code(), 130);
+
+    let output = String::from_utf8(
+        writer.inner_writer().inner_writer().inner_writer().to_vec(),
+    )
+    .unwrap_or_else(|e| panic!("invalid UTF-8 output: {e}"));
+    assert!(output.contains("execution stopped"), "{output}");
+    assert!(output.contains("Execution aborted"), "{output}");
+    assert!(!output.contains("Undefined"), "{output}");
+    assert!(!output.contains("snippets"), "{output}");
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
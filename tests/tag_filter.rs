@@ -0,0 +1,68 @@
+use clap::Parser as _;
+use cucumber::{
+    cli, given, writer::out::Styles, writer::summarize::Stats, World as _,
+};
+
+#[derive(Clone, Copy, cucumber::World, Debug, Default)]
+struct World;
+
+#[given("a step")]
+fn step(_: &mut World) {}
+
+#[tokio::test]
+async fn counts_filtered_out_scenarios_separately() {
+    for (tags, (passed, skipped, filtered)) in
+        [("@smoke and not @wip", (2, 0, 2)), ("not @slow", (2, 1, 1))]
+    {
+        let cli =
+            cli::Opts::<_, _, _>::try_parse_from(["test", "--tags", tags])
+                .expect("Invalid command line");
+
+        let writer = World::cucumber()
+            .with_cli(cli)
+            .run("tests/features/tag_filter")
+            .await;
+
+        assert_eq!(
+            *writer.scenarios_stats(),
+            Stats {
+                passed,
+                skipped,
+                failed: 0,
+                retried: 0,
+            },
+            "Wrong `Stats` for `Scenario`s with `{tags}`",
+        );
+        assert_eq!(
+            writer.filtered_scenarios(),
+            filtered,
+            "Wrong number of filtered out `Scenario`s with `{tags}`",
+        );
+        assert!(
+            Styles::new()
+                .summary(&writer)
+                .contains(&format!(", {filtered} filtered out\n")),
+            "No filtered out `Scenario`s in summary with `{tags}`",
+        );
+    }
+}
+
+#[tokio::test]
+async fn composes_name_and_tags_filters() {
+    let cli = cli::Opts::<_, _, _>::try_parse_from([
+        "test",
+        "--name",
+        "smoke",
+        "--tags",
+        "not @wip",
+    ])
+    .expect("Invalid command line");
+
+    let writer = World::cucumber()
+        .with_cli(cli)
+        .run("tests/features/tag_filter")
+        .await;
+
+    assert_eq!(writer.scenarios_stats().passed, 2);
+    assert_eq!(writer.filtered_scenarios(), 2);
+}
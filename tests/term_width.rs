@@ -0,0 +1,33 @@
+use std::env;
+
+use cucumber::writer::out::{Styles, MIN_TERM_WIDTH, TERM_WIDTH_ENV_VAR};
+
+#[test]
+fn overrides_term_width() {
+    env::set_var(TERM_WIDTH_ENV_VAR, "50");
+    let mut styles = Styles::new();
+    assert_eq!(styles.term_width, Some(50));
+    assert_eq!(styles.lines_count("a".repeat(120)), 3);
+
+    styles.override_term_width(120);
+    assert_eq!(styles.term_width, Some(120));
+    assert_eq!(styles.lines_count("a".repeat(120)), 1);
+
+    styles.override_term_width(10);
+    assert_eq!(styles.term_width, Some(MIN_TERM_WIDTH));
+
+    env::set_var(TERM_WIDTH_ENV_VAR, "5");
+    assert_eq!(Styles::new().term_width, Some(MIN_TERM_WIDTH));
+}
+
+#[test]
+fn counts_lines_of_any_term_width() {
+    let mut styles = Styles::new();
+    styles.term_width = Some(0);
+    assert_eq!(styles.lines_count("a".repeat(120)), 1);
+
+    styles.override_term_width(20);
+    let path = format!("{}/long.feature:1:1", "dir/".repeat(100));
+    assert_eq!(styles.lines_count(&path), 11);
+    assert_eq!(styles.lines_count(format!("✘  {}", "a".repeat(37))), 1);
+}
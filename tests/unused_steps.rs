@@ -0,0 +1,112 @@
+use clap::Parser as _;
+use cucumber::{
+    cli, given, parser, runner, then, when, writer, World as _, WriterExt as _,
+};
+
+#[given("a used step")]
+fn used(_: &mut World) {}
+
+#[given("an unused step")]
+fn unused(_: &mut World) {}
+
+#[when("I sign in", "I log in")]
+fn sign_in(_: &mut World) {}
+
+#[then(regex = r"^(\d+) apples are left$")]
+fn apples(_: &mut World) {}
+
+#[then(regex = r"^(\d+) pears are left$")]
+fn pears(_: &mut World) {}
+
+#[tokio::test]
+async fn reports_unused_steps() {
+    let writer = World::cucumber()
+        .report_unused_steps()
+        .with_writer(
+            writer::Basic::raw(
+                Vec::new(),
+                writer::Coloring::Never,
+                writer::Verbosity::Default,
+            )
+            .summarized()
+            .normalized(),
+        )
+        .with_default_cli()
+        .run("tests/features/unused_steps")
+        .await;
+
+    let output = String::from_utf8(writer.inner_writer().to_vec()).unwrap();
+    assert!(
+        output.contains(
+            "2 unused step definitions:\n  \
+             Given `^an unused step$` --> tests/unused_steps.rs:",
+        ),
+        "unexpected output:\n{output}",
+    );
+    assert!(
+        output.contains("  Then `^(\\d+) pears are left$` --> "),
+        "unexpected output:\n{output}",
+    );
+    assert!(!output.contains("I log in"), "unexpected output:\n{output}");
+}
+
+#[tokio::test]
+async fn reports_unused_steps_on_dry_run() {
+    let writer = World::cucumber()
+        .dry_run()
+        .report_unused_steps()
+        .with_writer(
+            writer::Basic::raw(
+                Vec::new(),
+                writer::Coloring::Never,
+                writer::Verbosity::Default,
+            )
+            .summarized()
+            .normalized(),
+        )
+        .with_default_cli()
+        .run("tests/features/unused_steps")
+        .await;
+
+    let output = String::from_utf8(writer.inner_writer().to_vec()).unwrap();
+    assert!(
+        output.contains("2 unused step definitions:"),
+        "unexpected output:\n{output}",
+    );
+}
+
+#[tokio::test]
+async fn does_not_report_by_default() {
+    let writer = World::cucumber()
+        .with_writer(
+            writer::Basic::raw(
+                Vec::new(),
+                writer::Coloring::Never,
+                writer::Verbosity::Default,
+            )
+            .summarized()
+            .normalized(),
+        )
+        .with_default_cli()
+        .run("tests/features/unused_steps")
+        .await;
+
+    let output = String::from_utf8(writer.inner_writer().to_vec()).unwrap();
+    assert!(
+        !output.contains("unused step definition"),
+        "unexpected output:\n{output}",
+    );
+}
+
+type Opts =
+    cli::Opts<parser::basic::Cli, runner::basic::Cli, writer::basic::Cli>;
+
+#[test]
+fn parses_cli_option() {
+    let opts = Opts::try_parse_from(["test", "--report-unused-steps"])
+        .expect("failed to parse CLI");
+    assert!(opts.runner.report_unused_steps);
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World;
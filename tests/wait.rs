@@ -1,6 +1,8 @@
-use std::{panic::AssertUnwindSafe, time::Duration};
+use std::time::Duration;
 
-use cucumber::{cli, given, then, when, writer, Parameter, World as _};
+use cucumber::{
+    cli, given, then, when, writer, Parameter, StatsWriter as _, World as _,
+};
 use derive_more::{Deref, FromStr};
 use futures::FutureExt as _;
 use tokio::time;
@@ -20,7 +22,7 @@ struct CustomCli {
 async fn main() {
     let cli = cli::Opts::<_, _, _, CustomCli>::parsed();
 
-    let res = World::cucumber()
+    let writer = World::cucumber()
         .before(move |_, _, _, w| {
             async move {
                 w.0 = 0;
@@ -32,15 +34,11 @@ async fn main() {
         .with_writer(writer::Libtest::or_basic())
         .fail_on_skipped()
         .with_cli(cli)
-        .run_and_exit("tests/features/wait");
+        .run("tests/features/wait")
+        .await;
 
-    let err = AssertUnwindSafe(res)
-        .catch_unwind()
-        .await
-        .expect_err("should err");
-    let err = err.downcast_ref::<String>().unwrap();
-
-    assert_eq!(err, "4 steps failed, 1 parsing error");
+    assert_eq!(writer.failed_steps(), 4);
+    assert_eq!(writer.parsing_errors(), 1);
 }
 
 #[given(regex = r"(\d+) secs?")]
@@ -0,0 +1,115 @@
+use std::io;
+
+use cucumber::{
+    given, step, when, writer, StatsWriter as _, World as _, WriterExt as _,
+};
+use futures::FutureExt as _;
+use regex::Regex;
+
+#[given("a ready step")]
+fn ready(world: &mut World) {
+    world.executed += 1;
+}
+
+#[when("a wip step", wip)]
+fn wip(_: &mut World) {
+    panic!("work-in-progress step must not be executed");
+}
+
+#[tokio::test]
+async fn reports_wip_steps_as_pending() {
+    let writer = World::cucumber()
+        .with_default_cli()
+        .run("tests/features/wip_steps")
+        .await;
+
+    assert_eq!(writer.passed_steps(), 4);
+    assert_eq!(writer.pending_steps(), 1);
+    assert_eq!(writer.skipped_steps(), 2);
+    assert_eq!(writer.failed_steps(), 0);
+}
+
+#[tokio::test]
+async fn fails_on_pending_steps_only_if_configured() {
+    let writer = World::cucumber()
+        .with_writer(
+            writer::Basic::raw(
+                io::sink(),
+                writer::Coloring::Never,
+                writer::Verbosity::Default,
+            )
+            .summarized()
+            .normalized()
+            .fail_on_skipped(),
+        )
+        .with_default_cli()
+        .run("tests/features/wip_steps")
+        .await;
+
+    assert_eq!(writer.pending_steps(), 1);
+    assert_eq!(writer.failed_steps(), 1);
+
+    let writer = World::cucumber()
+        .with_writer(
+            writer::Basic::raw(
+                io::sink(),
+                writer::Coloring::Never,
+                writer::Verbosity::Default,
+            )
+            .summarized()
+            .normalized()
+            .fail_on_skipped()
+            .fail_on_pending(),
+        )
+        .with_default_cli()
+        .run("tests/features/wip_steps")
+        .await;
+
+    assert_eq!(writer.pending_steps(), 0);
+    assert_eq!(writer.failed_steps(), 2);
+}
+
+#[tokio::test]
+async fn unmarking_wip_step_reenables_it() {
+    let loc = step::Location {
+        path: file!(),
+        line: line!(),
+        column: column!(),
+    };
+    let re = Regex::new("^a wip step$").unwrap();
+    let steps = step::Collection::new()
+        .given(
+            None,
+            Regex::new("^a ready step$").unwrap(),
+            |w: &mut World, _| {
+                async move {
+                    w.executed += 1;
+                }
+                .boxed_local()
+            },
+        )
+        .when_wip(Some(loc), re.clone(), |_, _| {
+            async move { panic!("must not be executed") }.boxed_local()
+        })
+        .when(Some(loc), re, |w: &mut World, _| {
+            async move {
+                w.executed += 1;
+            }
+            .boxed_local()
+        });
+
+    let writer = World::cucumber()
+        .steps(steps)
+        .with_default_cli()
+        .run("tests/features/wip_steps")
+        .await;
+
+    assert_eq!(writer.passed_steps(), 6);
+    assert_eq!(writer.pending_steps(), 0);
+    assert_eq!(writer.skipped_steps(), 1);
+}
+
+#[derive(Debug, Default, cucumber::World)]
+struct World {
+    executed: usize,
+}
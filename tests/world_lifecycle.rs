@@ -0,0 +1,106 @@
+use std::{
+    convert::Infallible,
+    future,
+    sync::{Mutex, PoisonError},
+};
+
+use async_trait::async_trait;
+use cucumber::{
+    event, gherkin, step, writer, StatsWriter as _, WriterExt as _,
+};
+use futures::{future::LocalBoxFuture, FutureExt as _};
+use regex::Regex;
+
+static AFTER_HOOKS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+fn log_is(world: &mut World, ctx: step::Context) -> LocalBoxFuture<'_, ()> {
+    assert_eq!(format!("\"{}\"", world.0.join(", ")), ctx.matches[1].1);
+    future::ready(()).boxed_local()
+}
+
+fn steps() -> step::Collection<World> {
+    let tag = || Some("@hooked".parse().unwrap());
+    step::Collection::new()
+        .then(None, Regex::new(r#"^log is (".*")$"#).unwrap(), log_is)
+        .before(tag(), |_, _, _, w: &mut World| {
+            w.0.push("collection");
+            future::ready(()).boxed_local()
+        })
+        .after(tag(), |_, _, _, _, w| {
+            if let Some(w) = w {
+                w.0.push("collection");
+            }
+            future::ready(()).boxed_local()
+        })
+}
+
+#[tokio::test]
+async fn runs_world_lifecycle_methods_innermost() {
+    let writer = cucumber::Cucumber::<World, _, _, _, _, _>::new()
+        .steps(steps())
+        .after(|_, _, sc, _, w| {
+            let log = w.map(|w| w.0.join(", ")).unwrap_or_default();
+            AFTER_HOOKS
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .push(format!("{}: {log}", sc.name));
+            future::ready(()).boxed_local()
+        })
+        .with_writer(writer::Basic::stdout().summarized())
+        .max_concurrent_scenarios(1)
+        .with_default_cli()
+        .run("tests/features/world_lifecycle")
+        .await;
+
+    assert_eq!(writer.passed_steps(), 3);
+    assert_eq!(writer.failed_steps(), 1);
+    assert_eq!(writer.hook_errors(), 2);
+
+    let mut after_hooks = AFTER_HOOKS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone();
+    after_hooks.sort();
+    assert_eq!(
+        after_hooks,
+        [
+            "failing before hooked: collection, trait, trait, collection",
+            "failing before: trait, trait",
+            "hooked: collection, trait, trait, collection",
+            "lazy: trait, trait",
+        ],
+    );
+}
+
+#[derive(Debug, Default)]
+struct World(Vec<&'static str>);
+
+#[async_trait(?Send)]
+impl cucumber::World for World {
+    type Error = Infallible;
+
+    async fn new() -> Result<Self, Self::Error> {
+        Ok(Self::default())
+    }
+
+    async fn before_scenario(
+        &mut self,
+        _: &gherkin::Feature,
+        _: Option<&gherkin::Rule>,
+        scenario: &gherkin::Scenario,
+    ) {
+        self.0.push("trait");
+        assert!(!scenario.name.starts_with("failing before"), "before");
+    }
+
+    async fn after_scenario(
+        &mut self,
+        _: &gherkin::Feature,
+        _: Option<&gherkin::Rule>,
+        scenario: &gherkin::Scenario,
+        _: &event::ScenarioFinished,
+    ) {
+        self.0.push("trait");
+        assert_ne!(scenario.name, "failing after", "after");
+    }
+}
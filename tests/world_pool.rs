@@ -0,0 +1,107 @@
+use std::{
+    convert::Infallible,
+    future,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use async_trait::async_trait;
+use cucumber::{runner::basic::PoolOptions, step, StatsWriter as _};
+use futures::{future::LocalBoxFuture, FutureExt as _};
+use regex::Regex;
+
+static CONSTRUCTED: AtomicUsize = AtomicUsize::new(0);
+static RESET: AtomicUsize = AtomicUsize::new(0);
+
+fn steps() -> step::Collection<World> {
+    step::Collection::new()
+        .given(
+            None,
+            Regex::new("^the world is fresh$").unwrap(),
+            |w: &mut World, _| {
+                assert!(!w.used, "`World` is not reset");
+                future::ready(()).boxed_local()
+            },
+        )
+        .when(
+            None,
+            Regex::new("^the world is used$").unwrap(),
+            |w: &mut World, _| {
+                w.used = true;
+                future::ready(()).boxed_local()
+            },
+        )
+        .then(None, Regex::new("^the step fails$").unwrap(), fails)
+}
+
+fn fails(_: &mut World, _: step::Context) -> LocalBoxFuture<'_, ()> {
+    panic!("failed")
+}
+
+async fn run(pool: Option<PoolOptions>) -> (usize, usize) {
+    CONSTRUCTED.store(0, Ordering::SeqCst);
+    RESET.store(0, Ordering::SeqCst);
+
+    let writer = cucumber::Cucumber::<World, _, _, _, _, _>::new()
+        .steps(steps())
+        .max_concurrent_scenarios(1)
+        .pool_worlds(pool)
+        .with_default_cli()
+        .run("tests/features/world_pool")
+        .await;
+
+    assert_eq!(writer.passed_steps(), 8);
+    assert_eq!(writer.failed_steps(), 1);
+
+    (
+        CONSTRUCTED.load(Ordering::SeqCst),
+        RESET.load(Ordering::SeqCst),
+    )
+}
+
+#[tokio::test]
+async fn pools_worlds() {
+    assert_eq!(run(None).await, (4, 0), "without pool");
+    assert_eq!(
+        run(Some(PoolOptions::default())).await,
+        (2, 2),
+        "discarding failed",
+    );
+    assert_eq!(
+        run(Some(PoolOptions {
+            discard_failed: false,
+            ..PoolOptions::default()
+        }))
+        .await,
+        (1, 3),
+        "reusing failed",
+    );
+    assert_eq!(
+        run(Some(PoolOptions {
+            size: Some(0),
+            ..PoolOptions::default()
+        }))
+        .await,
+        (4, 0),
+        "zero-sized pool",
+    );
+}
+
+#[derive(Debug)]
+struct World {
+    used: bool,
+}
+
+#[async_trait(?Send)]
+impl cucumber::World for World {
+    type Error = Infallible;
+
+    async fn new() -> Result<Self, Self::Error> {
+        _ = CONSTRUCTED.fetch_add(1, Ordering::SeqCst);
+        Ok(Self { used: false })
+    }
+
+    async fn reset(&mut self) {
+        _ = RESET.fetch_add(1, Ordering::SeqCst);
+        self.used = false;
+    }
+}